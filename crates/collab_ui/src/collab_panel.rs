@@ -10,7 +10,6 @@ use client::{ChannelId, Client, Contact, User, UserStore};
 use collections::{HashMap, HashSet};
 use contact_finder::ContactFinder;
 use db::kvp::KEY_VALUE_STORE;
-use i18n;
 use editor::{Editor, EditorElement, EditorStyle};
 use fuzzy::{StringMatch, StringMatchCandidate, match_strings};
 use gpui::{
@@ -20,6 +19,7 @@ use gpui::{
     Render, SharedString, Styled, Subscription, Task, TextStyle, WeakEntity, Window, actions,
     anchored, canvas, deferred, div, fill, list, point, prelude::*, px,
 };
+use i18n;
 use menu::{Cancel, Confirm, SecondaryConfirm, SelectNext, SelectPrevious};
 use project::{Fs, Project};
 use rpc::{
@@ -1132,8 +1132,9 @@ impl CollabPanel {
                             .child(Indicator::dot().color(Color::Info))
                     })),
             )
-                        .child(Label::new("notes"))
-                    .tooltip(Tooltip::text(i18n::t_static("tooltip.open_channel_notes")))    }
+            .child(Label::new("notes"))
+            .tooltip(Tooltip::text(i18n::t_static("tooltip.open_channel_notes")))
+    }
 
     fn has_subchannels(&self, ix: usize) -> bool {
         self.entries.get(ix).is_some_and(|entry| {
@@ -2956,7 +2957,9 @@ impl CollabPanel {
                                 .on_click(cx.listener(move |this, _, window, cx| {
                                     this.open_channel_notes(channel_id, window, cx)
                                 }))
-                                .tooltip(Tooltip::text(i18n::t_static("tooltip.open_channel_notes"))),
+                                .tooltip(Tooltip::text(i18n::t_static(
+                                    "tooltip.open_channel_notes",
+                                ))),
                         )
                         .visible_on_hover(""),
                 ),
@@ -3133,9 +3136,9 @@ impl Panel for CollabPanel {
             .then_some(ui::IconName::UserGroup)
     }
 
-    fn icon_tooltip(&self, _window: &Window, _cx: &App) -> Option<&'static str> {
-    Some(i18n::t_static("panel.collab_panel"))
-}
+    fn icon_tooltip(&self, _window: &Window, _cx: &App) -> Option<SharedString> {
+        Some(i18n::t("panel.collab_panel").into())
+    }
 
     fn toggle_action(&self) -> Box<dyn gpui::Action> {
         Box::new(ToggleFocus)
@@ -1,27 +1,228 @@
+mod custom_panel_settings;
+mod panel_state;
+mod source;
+mod translation_preview;
+
 use anyhow::Result;
+use db::kvp::KEY_VALUE_STORE;
+use editor::{Editor, EditorEvent};
+use fs::Fs;
 use gpui::{
-    actions, div, prelude::*, App, AsyncWindowContext, Context, EventEmitter, Entity, Focusable,
-    FocusHandle, IntoElement, Render, WeakEntity, Window,
+    AnyElement, App, AsyncWindowContext, ClickEvent, ClipboardItem, Context, Entity,
+    EntityInputHandler, EventEmitter, FocusHandle, Focusable, IntoElement, Render, ScrollHandle,
+    ScrollStrategy, Task, UniformListScrollHandle, WeakEntity, Window, actions, div, prelude::*,
+    uniform_list,
+};
+use panel_state::CustomPanelState;
+use release_channel::ReleaseChannel;
+use settings::{Settings, SettingsStore};
+use std::{
+    cell::RefCell,
+    collections::HashSet,
+    ops::Range,
+    rc::Rc,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
 };
-use i18n::t;
-use ui::{prelude::*, IconName};
+use ui::{ContextMenu, IconName, PopoverMenu, SpinnerLabel, Tooltip, WithScrollbar, prelude::*};
 use workspace::{
-    dock::{DockPosition, Panel, PanelEvent},
     Workspace,
+    dock::{DockPosition, Panel, PanelEvent},
 };
 
-actions!(custom_panel, [ToggleFocus]);
+pub use custom_panel_settings::CustomPanelSettings;
+pub use source::{CustomPanelSource, DefaultSource, PanelItem, PanelTooltip};
+pub use translation_preview::{
+    LogTranslationDiff, OpenTranslationPreview, TranslationPreviewPanel,
+};
+
+actions!(
+    custom_panel,
+    [
+        ToggleFocus,
+        /// 按固定步长加宽面板
+        IncreaseWidth,
+        /// 按固定步长收窄面板
+        DecreaseWidth,
+        /// 在条目文本旁显示对应的翻译键，仅在 Dev 渠道可用
+        ToggleTranslationKeys,
+        /// 让数据源立即重新获取数据
+        Refresh,
+        /// 置顶或取消置顶选中的条目，置顶的条目排在列表最前面
+        TogglePin,
+        /// 把选中范围扩展到下一个条目
+        ExtendSelectionNext,
+        /// 把选中范围扩展到上一个条目
+        ExtendSelectionPrevious,
+        /// 按显示顺序复制所有选中条目的文本，每行一个
+        Copy,
+        /// 让数据源移除所有选中的条目
+        Remove,
+        /// 为当前的筛选文本命名并保存到设置中
+        SaveFilter,
+    ]
+);
 
 const CUSTOM_PANEL_KEY: &str = "CustomPanel";
 
-/// 自定义面板结构体，显示简单文本
+/// 默认面板的 id，它的持久化键沿用加入 id 之前的格式，已保存的状态可以继续读取
+pub const DEFAULT_PANEL_ID: &str = "default";
+
+// 设置中的默认宽度无效时使用的宽度
+const DEFAULT_WIDTH: Pixels = px(280.);
+// 键盘调整宽度时每次变化的像素数
+const WIDTH_STEP: Pixels = px(20.);
+// 键盘调整宽度时允许的最小和最大宽度
+const MIN_WIDTH: Pixels = px(160.);
+const MAX_WIDTH: Pixels = px(800.);
+// 宽度低于该值时切换为紧凑布局
+const NARROW_WIDTH: Pixels = px(200.);
+// 停靠在底部时的默认高度
+const DEFAULT_HEIGHT: Pixels = px(240.);
+// 订阅的事件触发重新渲染时的合并窗口，窗口内的多次事件只渲染一次
+const NOTIFY_DEBOUNCE: Duration = Duration::from_millis(50);
+// 拖动调整尺寸时状态变化频繁，停止变化这么久之后才写入数据库
+const SERIALIZATION_DEBOUNCE: Duration = Duration::from_millis(200);
+
+// 是否已经提示过 i18n 未初始化
+static WARNED_I18N_UNINITIALIZED: AtomicBool = AtomicBool::new(false);
+// 是否已经提示过设置中的图标名称无法识别
+static WARNED_INVALID_ICON: AtomicBool = AtomicBool::new(false);
+// 设置中的图标名称无法识别时使用的图标
+const FALLBACK_ICON: IconName = IconName::Star;
+
+/// `initialized` 为 false（i18n 尚未初始化）时记录一次警告，返回本次调用是否记录了警告
+///
+/// 面板仍然可以正常创建，只是文字会显示为原始键，这通常说明 `i18n::init`
+/// 在初始化顺序中排在了面板之后。
+fn warn_once_if_i18n_uninitialized(initialized: bool, warned: &AtomicBool) -> bool {
+    if initialized || warned.swap(true, Ordering::Relaxed) {
+        return false;
+    }
+    log::warn!("CustomPanel created before i18n::init, its labels will show raw translation keys");
+    true
+}
+
+/// 将设置中的图标名称解析为 [`IconName`]，无法识别时使用 [`FALLBACK_ICON`]
+///
+/// 设置中的拼写错误不应导致状态栏图标空白，只在第一次遇到时记录一次警告。
+fn resolve_icon(name: &str, warned: &AtomicBool) -> IconName {
+    name.parse::<IconName>().unwrap_or_else(|_| {
+        if !warned.swap(true, Ordering::Relaxed) {
+            log::warn!("unknown custom panel icon {name:?}, falling back to {FALLBACK_ICON:?}");
+        }
+        FALLBACK_ICON
+    })
+}
+
+/// 搜索框的占位提示
+///
+/// 中日韩文字通常通过输入法输入，提示用户筛选在确认候选词后才会生效。
+fn search_placeholder(language: i18n::Language) -> SharedString {
+    if language.uses_ime() {
+        i18n::t_shared("custom_panel.search_placeholder_ime")
+    } else {
+        i18n::t_shared("custom_panel.search_placeholder")
+    }
+}
+
+/// 自定义面板发出的事件
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CustomPanelEvent {
+    /// 第 `index` 个条目被激活
+    ItemActivated(usize),
+}
+
+/// 渲染条目时用到的布局参数，每次渲染计算一次，传给每一行
+#[derive(Clone, Copy)]
+struct RowStyle {
+    // 是否横向排列
+    horizontal: bool,
+    // 是否在条目旁显示翻译键
+    show_translation_keys: bool,
+    // 条目文字的行高，纵向列表中每行都使用这个高度
+    line_height: Pixels,
+}
+
+/// 某个筛选文本下显示的行
+struct FilterCache {
+    // 得到这个结果时的筛选文本
+    query: String,
+    // 每行显示的条目在数据源中的索引，置顶的条目在前
+    indices: Rc<[usize]>,
+}
+
+/// 活动编辑器中打开的文件，显示在条目上方
+#[derive(Debug, Clone, PartialEq)]
+struct ActiveFile {
+    // 编辑器的标题，通常是文件名，没有文件的缓冲区取内容的开头
+    name: SharedString,
+    // 文件的语言，多缓冲区或尚未识别出语言时为 None
+    language: Option<SharedString>,
+}
+
+impl ActiveFile {
+    fn from_editor(editor: &Editor, cx: &App) -> Self {
+        let buffer = editor.buffer().read(cx);
+        Self {
+            name: buffer.title(cx).to_string().into(),
+            language: buffer
+                .as_singleton()
+                .and_then(|buffer| buffer.read(cx).language().map(|language| language.name().0)),
+        }
+    }
+}
+
+/// 自定义面板结构体，显示数据源提供的条目
 pub struct CustomPanel {
+    // 面板 id，同一个 workspace 中的多个面板各自用它区分持久化的状态
+    id: SharedString,
     // 焦点句柄，用于管理面板的键盘焦点
     focus_handle: FocusHandle,
-    // Workspace 的弱引用，避免循环引用（下划线前缀表示未使用）
-    _workspace: WeakEntity<Workspace>,
+    // 数据源，决定面板显示的内容和激活行为
+    source: Box<dyn CustomPanelSource>,
+    // Workspace 的弱引用，避免循环引用，用于计算持久化状态的键
+    workspace: WeakEntity<Workspace>,
+    // 当前选中的条目索引，None 表示没有选中，多选时是最后点击或移动到的条目
+    selected_index: Option<usize>,
+    // 所有选中的条目索引，批量操作作用于这些条目
+    selection: HashSet<usize>,
+    // 范围选择的起点，Shift+点击或 Shift+方向键从这里选到目标条目
+    selection_anchor: Option<usize>,
+    // 纵向列表的滚动句柄，用于把选中项滚动到可见区域
+    scroll_handle: UniformListScrollHandle,
+    // 停靠在底部时横向排列的条目的滚动句柄
+    horizontal_scroll_handle: ScrollHandle,
+    // 搜索框，按条目文本筛选
+    search_editor: Entity<Editor>,
+    // 已提交的筛选文本，输入法组字期间保持不变
+    query: String,
+    // 保存筛选时输入名称的编辑框，None 表示没有在保存
+    filter_name_editor: Option<Entity<Editor>>,
+    // 上一次的筛选结果，筛选文本不变时重复使用，数据源的数据或置顶的条目变化时清空
+    filter_cache: RefCell<Option<FilterCache>>,
+    // 置顶的条目标识（见 PanelItem::id），会持久化
+    pinned: HashSet<SharedString>,
     // 面板宽度，None 表示使用默认宽度
     width: Option<Pixels>,
+    // 停靠在底部时的面板高度，None 表示使用默认高度
+    height: Option<Pixels>,
+    // 通过键盘调整宽度后显示的新宽度，供读屏工具播报
+    width_announcement: Option<SharedString>,
+    // 是否在条目旁显示翻译键，不会持久化
+    show_translation_keys: bool,
+    // 正在进行的状态保存任务
+    pending_serialization: Task<Option<()>>,
+    // 正在进行的刷新任务，None 表示没有在刷新
+    refresh_task: Option<Task<()>>,
+    // 等待中的合并渲染任务，面板释放时随之取消
+    pending_notify: Option<Task<()>>,
+    // 是否显示活动文件，只有通过 new 创建并跟随 workspace 的面板才显示
+    tracks_active_file: bool,
+    // 活动编辑器中的文件，None 表示没有活动的编辑器
+    active_file: Option<ActiveFile>,
+    // 活动编辑器的事件订阅，切换活动项时替换
+    active_editor_subscription: Option<gpui::Subscription>,
     // 订阅列表，用于存储事件订阅（下划线前缀表示未使用）
     _subscriptions: Vec<gpui::Subscription>,
 }
@@ -32,27 +233,881 @@ impl CustomPanel {
     pub fn new(
         // 可变引用 workspace，用于获取工作区信息
         workspace: &mut Workspace,
-        // 可变引用 window，用于创建窗口相关的组件（未使用）
-        _window: &mut Window,
+        // 可变引用 window，用于创建搜索框
+        window: &mut Window,
         // Context，用于创建实体和访问应用状态
         cx: &mut Context<Workspace>,
     ) -> Entity<Self> {
-        // 从上下文中获取焦点句柄
-        let focus_handle = cx.focus_handle();
         // 获取 workspace 的弱引用，避免循环引用
         let workspace_handle = workspace.weak_handle();
+        let workspace_entity = cx.entity();
+        let active_editor = workspace.active_item_as::<Editor>(cx);
+
+        // 创建新的 CustomPanel 实体，使用默认数据源
+        cx.new(|cx| {
+            let mut panel =
+                Self::with_source(workspace_handle, Box::new(DefaultSource), window, cx);
+            // 跟随 workspace 的活动项显示当前文件
+            panel.tracks_active_file = true;
+            panel.set_active_editor(active_editor, cx);
+            let subscription = cx.subscribe_in(
+                &workspace_entity,
+                window,
+                |panel, workspace, event: &workspace::Event, _, cx| {
+                    if let workspace::Event::ActiveItemChanged = event {
+                        let active_editor = workspace.read(cx).active_item_as::<Editor>(cx);
+                        panel.set_active_editor(active_editor, cx);
+                    }
+                },
+            );
+            panel._subscriptions.push(subscription);
+            panel
+        })
+    }
 
-        // 创建新的 CustomPanel 实体
-        cx.new(|_| CustomPanel {
-            // 设置焦点句柄
-            focus_handle,
+    /// 使用指定的数据源创建默认面板
+    pub fn with_source(
+        workspace: WeakEntity<Workspace>,
+        source: Box<dyn CustomPanelSource>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        Self::with_id(DEFAULT_PANEL_ID, workspace, source, window, cx)
+    }
+
+    /// 使用指定的 id 和数据源创建面板
+    ///
+    /// 同一个 workspace 中的每个面板需要不同的 id，否则它们保存的状态会互相覆盖。
+    pub fn with_id(
+        id: impl Into<SharedString>,
+        workspace: WeakEntity<Workspace>,
+        source: Box<dyn CustomPanelSource>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        warn_once_if_i18n_uninitialized(i18n::is_initialized(), &WARNED_I18N_UNINITIALIZED);
+        let search_editor = cx.new(|cx| {
+            let mut editor = Editor::single_line(window, cx);
+            editor.set_placeholder_text(&search_placeholder(i18n::get_language()), window, cx);
+            editor
+        });
+        let search_subscription =
+            cx.subscribe_in(&search_editor, window, Self::on_search_editor_event);
+        // 切换语言后用新语言重新渲染
+        let this = cx.weak_entity();
+        let language_subscription = i18n::subscribe(cx, move |_, cx| {
+            this.update(cx, |this, cx| {
+                // 条目文本随语言变化，旧的筛选结果不再适用
+                this.invalidate_filter_cache();
+                cx.notify();
+            })
+            .ok();
+        });
+        // 设置变化后重新渲染，默认宽度等设置立即生效
+        let settings_subscription = cx.observe_global::<SettingsStore>(|this, cx| {
+            this.invalidate_filter_cache();
+            cx.notify();
+        });
+        CustomPanel {
+            // 区分持久化状态的 id
+            id: id.into(),
+            // 从上下文中获取焦点句柄
+            focus_handle: cx.focus_handle(),
+            // 设置数据源
+            source,
             // 设置 workspace 的弱引用
-            _workspace: workspace_handle,
+            workspace,
+            // 初始时没有选中任何条目
+            selected_index: None,
+            selection: HashSet::default(),
+            selection_anchor: None,
+            scroll_handle: UniformListScrollHandle::new(),
+            horizontal_scroll_handle: ScrollHandle::new(),
+            search_editor,
+            // 初始时不筛选
+            query: String::new(),
+            filter_name_editor: None,
+            filter_cache: RefCell::new(None),
+            // 初始时没有置顶的条目
+            pinned: HashSet::default(),
             // 初始化宽度为 None（使用默认值）
             width: None,
-            // 初始化订阅列表为空
-            _subscriptions: Vec::new(),
-        })
+            height: None,
+            // 尚未通过键盘调整过宽度
+            width_announcement: None,
+            // 默认不显示翻译键
+            show_translation_keys: false,
+            // 尚未保存过状态
+            pending_serialization: Task::ready(None),
+            // 初始时没有在刷新
+            refresh_task: None,
+            // 没有等待中的渲染
+            pending_notify: None,
+            // 只有 new 创建的面板才跟随活动文件
+            tracks_active_file: false,
+            active_file: None,
+            active_editor_subscription: None,
+            // 初始只订阅搜索框、语言切换和设置变化
+            _subscriptions: vec![
+                search_subscription,
+                language_subscription,
+                settings_subscription,
+            ],
+        }
+    }
+
+    /// 替换面板的数据源并重新渲染
+    pub fn set_source(&mut self, source: Box<dyn CustomPanelSource>, cx: &mut Context<Self>) {
+        self.source = source;
+        // 旧的选中项和刷新任务对新数据源没有意义
+        self.clear_selection();
+        self.refresh_task = None;
+        self.invalidate_filter_cache();
+        cx.notify();
+    }
+
+    /// 切换显示的活动编辑器，None 表示没有活动的编辑器
+    ///
+    /// 编辑器的标题或语言变化时（例如保存为新文件名、识别出语言）同步更新。
+    fn set_active_editor(&mut self, editor: Option<Entity<Editor>>, cx: &mut Context<Self>) {
+        self.active_file = editor
+            .as_ref()
+            .map(|editor| ActiveFile::from_editor(editor.read(cx), cx));
+        self.active_editor_subscription = editor.map(|editor| {
+            cx.subscribe(&editor, |this, editor, event: &EditorEvent, cx| {
+                if matches!(
+                    event,
+                    EditorEvent::TitleChanged | EditorEvent::Reparsed(_) | EditorEvent::Saved
+                ) {
+                    this.active_file = Some(ActiveFile::from_editor(editor.read(cx), cx));
+                    cx.notify();
+                }
+            })
+        });
+        cx.notify();
+    }
+
+    /// 让数据源重新获取数据，完成后重新渲染
+    ///
+    /// 正在进行的刷新会被取消，以最新一次请求为准。
+    pub fn refresh(&mut self, cx: &mut Context<Self>) {
+        let refresh = self.source.refresh(cx);
+        // 替换旧任务时旧任务被丢弃，也就取消了
+        self.refresh_task = Some(cx.spawn(async move |this, cx| {
+            refresh.await;
+            this.update(cx, |this, cx| {
+                this.refresh_task = None;
+                this.invalidate_filter_cache();
+                cx.notify();
+            })
+            .ok();
+        }));
+        cx.notify();
+    }
+
+    /// 订阅 `entity` 的事件，事件发生时合并重新渲染
+    ///
+    /// 适合工作区、项目这类可能在短时间内连续发出大量事件的实体。
+    pub fn subscribe_debounced<T, E>(&mut self, entity: &Entity<T>, cx: &mut Context<Self>)
+    where
+        T: EventEmitter<E>,
+        E: 'static,
+    {
+        let subscription = cx.subscribe(entity, |this, _, _: &E, cx| this.notify_debounced(cx));
+        self._subscriptions.push(subscription);
+    }
+
+    /// 在 [`NOTIFY_DEBOUNCE`] 之后重新渲染，期间的多次调用合并为一次
+    pub fn notify_debounced(&mut self, cx: &mut Context<Self>) {
+        if self.pending_notify.is_some() {
+            return;
+        }
+        self.pending_notify = Some(cx.spawn(async move |this, cx| {
+            cx.background_executor().timer(NOTIFY_DEBOUNCE).await;
+            this.update(cx, |this, cx| {
+                this.pending_notify = None;
+                this.invalidate_filter_cache();
+                cx.notify();
+            })
+            .ok();
+        }));
+    }
+
+    /// 是否正在刷新
+    pub fn is_refreshing(&self) -> bool {
+        self.refresh_task.is_some()
+    }
+
+    /// 当前生效的筛选文本
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    // 搜索框内容变化时更新筛选文本
+    //
+    // 输入法组字期间，未确认的拼音或假名也会写入搜索框，如果按它们筛选，
+    // 列表会随每次按键闪烁并显示错误的结果，所以只在没有组字文本时才更新。
+    fn on_search_editor_event(
+        &mut self,
+        search_editor: &Entity<Editor>,
+        event: &EditorEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if !matches!(event, EditorEvent::BufferEdited) {
+            return;
+        }
+        let composing = search_editor
+            .update(cx, |editor, cx| editor.marked_text_range(window, cx))
+            .is_some();
+        if composing {
+            return;
+        }
+        let query = search_editor.read(cx).text(cx);
+        if query != self.query {
+            self.query = query;
+            // 旧的选中项可能已被筛掉
+            self.clear_selection();
+            cx.notify();
+        }
+    }
+
+    /// 把搜索框的内容替换为名为 `name` 的已保存筛选，返回是否找到该筛选
+    pub fn apply_saved_filter(
+        &mut self,
+        name: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        let Some(query) = CustomPanelSettings::get_global(cx)
+            .saved_filters
+            .get(name)
+            .cloned()
+        else {
+            return false;
+        };
+        // 搜索框内容变化后通过 on_search_editor_event 更新筛选文本
+        self.search_editor
+            .update(cx, |editor, cx| editor.set_text(query, window, cx));
+        true
+    }
+
+    // 处理 SaveFilter 动作，显示输入名称的编辑框，默认以筛选文本作为名称
+    fn save_filter(&mut self, _: &SaveFilter, window: &mut Window, cx: &mut Context<Self>) {
+        if self.query.is_empty() {
+            return;
+        }
+        let query = self.query.clone();
+        let editor = cx.new(|cx| {
+            let mut editor = Editor::single_line(window, cx);
+            editor.set_placeholder_text(
+                &i18n::t_shared("custom_panel.filter_name_placeholder"),
+                window,
+                cx,
+            );
+            editor.set_text(query, window, cx);
+            editor.select_all(&editor::actions::SelectAll, window, cx);
+            editor
+        });
+        window.focus(&editor.focus_handle(cx), cx);
+        self.filter_name_editor = Some(editor);
+        cx.notify();
+    }
+
+    // 在名称编辑框中按回车，以输入的名称保存当前筛选
+    //
+    // 同名的筛选会被覆盖。设置文件写入后，设置变化会使新筛选出现在菜单中。
+    fn confirm_save_filter(
+        &mut self,
+        _: &menu::Confirm,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(editor) = self.filter_name_editor.as_ref() else {
+            return;
+        };
+        let name = editor.read(cx).text(cx).trim().to_string();
+        if name.is_empty() {
+            return;
+        }
+        let query = self.query.clone();
+        settings::update_settings_file(<dyn Fs>::global(cx), cx, move |settings, _| {
+            settings
+                .custom_panel
+                .get_or_insert_default()
+                .saved_filters
+                .get_or_insert_default()
+                .insert(name, query);
+        });
+        self.close_filter_name_editor(window, cx);
+    }
+
+    // 在名称编辑框中按 Esc，放弃保存
+    fn cancel_save_filter(
+        &mut self,
+        _: &menu::Cancel,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.close_filter_name_editor(window, cx);
+    }
+
+    /// 关闭名称编辑框，焦点回到面板
+    fn close_filter_name_editor(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.filter_name_editor = None;
+        window.focus(&self.focus_handle, cx);
+        cx.notify();
+    }
+
+    /// 已保存筛选的下拉菜单，点击名称应用筛选，最后一项保存当前筛选
+    fn render_saved_filters_menu(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let panel = cx.weak_entity();
+        let focus_handle = self.focus_handle.clone();
+        let has_query = !self.query.is_empty();
+        PopoverMenu::new("custom-panel-saved-filters")
+            .trigger_with_tooltip(
+                IconButton::new("custom-panel-saved-filters-button", IconName::ListFilter)
+                    .icon_size(IconSize::Small),
+                Tooltip::text(i18n::t_shared("custom_panel.saved_filters")),
+            )
+            .menu(move |window, cx| {
+                let names = CustomPanelSettings::get_global(cx)
+                    .saved_filters
+                    .keys()
+                    .cloned()
+                    .collect::<Vec<_>>();
+                let panel = panel.clone();
+                Some(ContextMenu::build(window, cx, |mut menu, _, _| {
+                    menu = menu
+                        .context(focus_handle.clone())
+                        .header(i18n::t_shared("custom_panel.saved_filters"));
+                    for name in names {
+                        let panel = panel.clone();
+                        menu = menu.entry(name.clone(), None, move |window, cx| {
+                            panel
+                                .update(cx, |panel, cx| {
+                                    panel.apply_saved_filter(&name, window, cx);
+                                })
+                                .ok();
+                        });
+                    }
+                    menu.separator().action_disabled_when(
+                        !has_query,
+                        i18n::t_shared("custom_panel.save_filter"),
+                        Box::new(SaveFilter),
+                    )
+                }))
+            })
+    }
+
+    /// 条目是否在当前筛选下显示，筛选时隐藏分隔线
+    fn is_visible(&self, item: &PanelItem) -> bool {
+        if self.query.is_empty() {
+            return true;
+        }
+        match item {
+            PanelItem::Entry { label, .. } => {
+                label.to_lowercase().contains(&self.query.to_lowercase())
+            }
+            PanelItem::Separator => false,
+        }
+    }
+
+    /// 每行显示的条目在数据源中的索引，没有筛选也没有置顶时返回 None，行号即为索引
+    ///
+    /// 置顶的条目按索引顺序排在最前面，其余条目保持数据源中的顺序。
+    /// 筛选和查找置顶的条目需要按索引逐个检查条目，结果按筛选文本缓存，渲染和键盘导航不会重复筛选。
+    fn filtered_indices(&self, cx: &App) -> Option<Rc<[usize]>> {
+        if self.query.is_empty() && self.pinned.is_empty() {
+            return None;
+        }
+        if let Some(cache) = self.filter_cache.borrow().as_ref() {
+            if cache.query == self.query {
+                return Some(cache.indices.clone());
+            }
+        }
+        let mut pinned = Vec::new();
+        let mut unpinned = Vec::new();
+        for index in 0..self.source.item_count(cx) {
+            let Some(item) = self.source.item(index, cx) else {
+                continue;
+            };
+            if !self.is_visible(&item) {
+                continue;
+            }
+            if self.is_item_pinned(&item) {
+                pinned.push(index);
+            } else {
+                unpinned.push(index);
+            }
+        }
+        let indices = pinned.into_iter().chain(unpinned).collect::<Rc<[usize]>>();
+        self.filter_cache.replace(Some(FilterCache {
+            query: self.query.clone(),
+            indices: indices.clone(),
+        }));
+        Some(indices)
+    }
+
+    /// 清空缓存的筛选结果，数据源的数据或置顶的条目可能变化时调用
+    ///
+    /// 数据源通过 [`refresh`](Self::refresh) 或 [`subscribe_debounced`](Self::subscribe_debounced)
+    /// 订阅的事件通知面板数据变化，其他情况下面板会一直使用已有的筛选结果。
+    fn invalidate_filter_cache(&self) {
+        self.filter_cache.take();
+    }
+
+    /// 显示的行数
+    fn row_count(&self, rows: Option<&[usize]>, cx: &App) -> usize {
+        rows.map_or_else(|| self.source.item_count(cx), |rows| rows.len())
+    }
+
+    /// 第 `index` 个条目是否可以通过键盘选中
+    fn is_selectable(&self, index: usize, cx: &App) -> bool {
+        self.source
+            .item(index, cx)
+            .is_some_and(|item| item.is_selectable())
+    }
+
+    /// 第 `index` 个条目是否置顶
+    pub fn is_pinned(&self, index: usize, cx: &App) -> bool {
+        self.source
+            .item(index, cx)
+            .is_some_and(|item| self.is_item_pinned(&item))
+    }
+
+    fn is_item_pinned(&self, item: &PanelItem) -> bool {
+        item.id().is_some_and(|id| self.pinned.contains(&id))
+    }
+
+    /// 置顶或取消置顶第 `index` 个条目，并保存置顶状态
+    pub fn toggle_pin(&mut self, index: usize, cx: &mut Context<Self>) {
+        let Some(id) = self.source.item(index, cx).and_then(|item| item.id()) else {
+            return;
+        };
+        if !self.pinned.remove(&id) {
+            self.pinned.insert(id);
+        }
+        self.invalidate_filter_cache();
+        self.scroll_to_selected(cx);
+        self.serialize(cx);
+        cx.notify();
+    }
+
+    // 处理 TogglePin 动作，作用于所有选中的条目
+    //
+    // 选中的条目都已置顶时全部取消置顶，否则全部置顶。
+    fn handle_toggle_pin(&mut self, _: &TogglePin, _: &mut Window, cx: &mut Context<Self>) {
+        let selected = self
+            .selection
+            .iter()
+            .filter_map(|&index| self.source.item(index, cx)?.id())
+            .collect::<HashSet<_>>();
+        if selected.is_empty() {
+            return;
+        }
+        if selected.is_subset(&self.pinned) {
+            self.pinned.retain(|id| !selected.contains(id));
+        } else {
+            self.pinned.extend(selected);
+        }
+        self.invalidate_filter_cache();
+        self.scroll_to_selected(cx);
+        self.serialize(cx);
+        cx.notify();
+    }
+
+    // 处理 Refresh 动作
+    fn handle_refresh(&mut self, _: &Refresh, _: &mut Window, cx: &mut Context<Self>) {
+        self.refresh(cx);
+    }
+
+    /// 所有选中的条目索引
+    pub fn selection(&self) -> &HashSet<usize> {
+        &self.selection
+    }
+
+    /// 清空选中的条目
+    fn clear_selection(&mut self) {
+        self.selected_index = None;
+        self.selection.clear();
+        self.selection_anchor = None;
+    }
+
+    /// 只选中第 `index` 个条目，并以它作为范围选择的起点
+    fn reset_selection(&mut self, index: usize) {
+        self.selection.clear();
+        self.selection.insert(index);
+        self.selection_anchor = Some(index);
+    }
+
+    /// 选中从范围起点到第 `index` 个条目之间的所有可选中条目
+    ///
+    /// 范围按显示的行计算，置顶和筛选后的顺序与数据源中的顺序不同。没有起点时只选中该条目。
+    pub fn select_range_to(&mut self, index: usize, cx: &mut Context<Self>) {
+        let anchor = self.selection_anchor.unwrap_or(index);
+        let rows = self.filtered_indices(cx);
+        let rows = rows.as_deref();
+        match Self::row_of(rows, anchor).zip(Self::row_of(rows, index)) {
+            Some((anchor_row, row)) => {
+                self.selection = (anchor_row.min(row)..=anchor_row.max(row))
+                    .map(|row| Self::index_at(rows, row))
+                    .filter(|&index| self.is_selectable(index, cx))
+                    .collect();
+                // 连续扩展时保持同一个起点
+                self.selection_anchor = Some(anchor);
+            }
+            None => self.reset_selection(index),
+        }
+        self.selected_index = Some(index);
+        self.scroll_to_selected(cx);
+        cx.notify();
+    }
+
+    /// 切换第 `index` 个条目是否选中，其他选中的条目保持不变
+    pub fn toggle_selected(&mut self, index: usize, cx: &mut Context<Self>) {
+        if !self.selection.remove(&index) {
+            self.selection.insert(index);
+        }
+        self.selected_index = Some(index);
+        self.selection_anchor = Some(index);
+        cx.notify();
+    }
+
+    /// 选中的条目索引，按显示的顺序排列
+    fn selected_in_row_order(&self, cx: &App) -> Vec<usize> {
+        let rows = self.filtered_indices(cx);
+        let rows = rows.as_deref();
+        let mut selected = self
+            .selection
+            .iter()
+            .filter_map(|&index| Some((Self::row_of(rows, index)?, index)))
+            .collect::<Vec<_>>();
+        selected.sort_unstable();
+        selected.into_iter().map(|(_, index)| index).collect()
+    }
+
+    // 点击条目：Shift 选中范围，Ctrl（macOS 上为 Cmd）切换选中，否则只选中并激活该条目
+    fn handle_item_click(&mut self, index: usize, event: &ClickEvent, cx: &mut Context<Self>) {
+        let modifiers = event.modifiers();
+        if modifiers.shift {
+            self.select_range_to(index, cx);
+        } else if modifiers.secondary() {
+            self.toggle_selected(index, cx);
+        } else {
+            self.reset_selection(index);
+            self.activate(index, cx);
+        }
+    }
+
+    // 处理 Copy 动作，把选中条目的文本按显示顺序写入剪贴板
+    fn copy(&mut self, _: &Copy, _: &mut Window, cx: &mut Context<Self>) {
+        let labels = self
+            .selected_in_row_order(cx)
+            .into_iter()
+            .filter_map(|index| match self.source.item(index, cx)? {
+                PanelItem::Entry { label, .. } => Some(label),
+                PanelItem::Separator => None,
+            })
+            .collect::<Vec<_>>();
+        if !labels.is_empty() {
+            cx.write_to_clipboard(ClipboardItem::new_string(labels.join("\n")));
+        }
+    }
+
+    // 处理 Remove 动作，交由数据源移除选中的条目
+    //
+    // 被移除的条目同时取消置顶，其余置顶的条目按标识记录，不受索引变化影响。
+    fn remove(&mut self, _: &Remove, _: &mut Window, cx: &mut Context<Self>) {
+        let mut removed = self.selection.iter().copied().collect::<Vec<_>>();
+        if removed.is_empty() {
+            return;
+        }
+        removed.sort_unstable();
+        let removed_ids = removed
+            .iter()
+            .filter_map(|&index| self.source.item(index, cx)?.id())
+            .collect::<Vec<_>>();
+        if !self.source.on_remove(&removed, cx) {
+            return;
+        }
+        self.pinned.retain(|id| !removed_ids.contains(id));
+        self.clear_selection();
+        self.invalidate_filter_cache();
+        self.serialize(cx);
+        cx.notify();
+    }
+
+    /// 激活第 `index` 个条目，交由数据源处理
+    pub fn activate(&mut self, index: usize, cx: &mut Context<Self>) {
+        self.selected_index = Some(index);
+        self.source.on_activate(index, cx);
+        cx.emit(CustomPanelEvent::ItemActivated(index));
+        cx.notify();
+    }
+
+    /// 当前选中的条目索引
+    pub fn selected_index(&self) -> Option<usize> {
+        self.selected_index
+    }
+
+    /// 只选中第 `index` 个条目，开启 `focus_follows_selection` 时同时激活该条目
+    fn select(&mut self, index: usize, cx: &mut Context<Self>) {
+        self.reset_selection(index);
+        if self.selected_index == Some(index) {
+            cx.notify();
+            return;
+        }
+        self.selected_index = Some(index);
+        self.scroll_to_selected(cx);
+        if CustomPanelSettings::get_global(cx).focus_follows_selection {
+            self.activate(index, cx);
+        } else {
+            cx.notify();
+        }
+    }
+
+    /// 把选中的条目滚动到纵向列表的可见区域
+    fn scroll_to_selected(&self, cx: &App) {
+        let Some(index) = self.selected_index else {
+            return;
+        };
+        if let Some(row) = Self::row_of(self.filtered_indices(cx).as_deref(), index) {
+            self.scroll_handle.scroll_to_item(row, ScrollStrategy::Top);
+        }
+    }
+
+    /// 第 `index` 个条目所在的行，被筛掉时返回 None
+    fn row_of(rows: Option<&[usize]>, index: usize) -> Option<usize> {
+        match rows {
+            Some(rows) => rows.iter().position(|&row_index| row_index == index),
+            None => Some(index),
+        }
+    }
+
+    /// 第 `row` 行显示的条目在数据源中的索引
+    fn index_at(rows: Option<&[usize]>, row: usize) -> usize {
+        rows.map_or(row, |rows| rows[row])
+    }
+
+    /// 从选中项所在的行向后（`forward`）或向前查找最近的可选中条目，跳过分隔线
+    ///
+    /// 没有选中项时从列表的一端开始。`wrap` 为 true 时到达一端后从另一端继续查找。
+    /// 按行逐个向数据源获取条目，找到即停止，不需要获取全部条目。
+    fn adjacent_selectable(&self, forward: bool, wrap: bool, cx: &App) -> Option<usize> {
+        let rows = self.filtered_indices(cx);
+        let rows = rows.as_deref();
+        let count = self.row_count(rows, cx);
+        let mut row = self
+            .selected_index
+            .and_then(|index| Self::row_of(rows, index));
+        for _ in 0..count {
+            let next = match (row, forward) {
+                (None, true) => 0,
+                (None, false) => count - 1,
+                (Some(row), true) if row + 1 < count => row + 1,
+                (Some(row), false) if row > 0 => row - 1,
+                (Some(_), true) if wrap => 0,
+                (Some(_), false) if wrap => count - 1,
+                _ => return None,
+            };
+            let index = Self::index_at(rows, next);
+            if self.is_selectable(index, cx) {
+                return Some(index);
+            }
+            row = Some(next);
+        }
+        None
+    }
+
+    // 处理 SelectNext 动作，到达末尾后回到第一项
+    fn select_next(&mut self, _: &menu::SelectNext, _: &mut Window, cx: &mut Context<Self>) {
+        if let Some(index) = self.adjacent_selectable(true, true, cx) {
+            self.select(index, cx);
+        }
+    }
+
+    // 处理 SelectPrevious 动作，到达开头后回到最后一项
+    fn select_previous(
+        &mut self,
+        _: &menu::SelectPrevious,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(index) = self.adjacent_selectable(false, true, cx) {
+            self.select(index, cx);
+        }
+    }
+
+    // 处理 ExtendSelectionNext 动作，到达末尾后不再扩展
+    fn extend_selection_next(
+        &mut self,
+        _: &ExtendSelectionNext,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(index) = self.adjacent_selectable(true, false, cx) {
+            self.select_range_to(index, cx);
+        }
+    }
+
+    // 处理 ExtendSelectionPrevious 动作，到达开头后不再扩展
+    fn extend_selection_previous(
+        &mut self,
+        _: &ExtendSelectionPrevious,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(index) = self.adjacent_selectable(false, false, cx) {
+            self.select_range_to(index, cx);
+        }
+    }
+
+    // 处理 Confirm 动作，激活当前选中的条目
+    fn confirm(&mut self, _: &menu::Confirm, _: &mut Window, cx: &mut Context<Self>) {
+        if let Some(index) = self.selected_index {
+            self.activate(index, cx);
+        }
+    }
+
+    /// 按 `delta` 调整面板宽度，结果限制在 [`MIN_WIDTH`, `MAX_WIDTH`] 之间
+    fn resize_by(&mut self, delta: Pixels, window: &mut Window, cx: &mut Context<Self>) {
+        let width = (self.size(window, cx) + delta).clamp(MIN_WIDTH, MAX_WIDTH);
+        self.set_size(Some(width), window, cx);
+        // 记录新宽度，渲染时以标签形式播报
+        self.width_announcement = Some(i18n::t_shared_args(
+            "custom_panel.width_announcement",
+            &[&width.to_string()],
+        ));
+    }
+
+    /// 条目文字的行高，按当前语言调整默认行高
+    fn content_line_height(language: i18n::Language, window: &Window) -> Pixels {
+        window.line_height() * language.ui_line_height_multiplier()
+    }
+
+    /// 面板是否窄到需要使用紧凑布局
+    fn is_narrow(&self, window: &Window, cx: &App) -> bool {
+        !self.is_horizontal(cx) && self.size(window, cx) < NARROW_WIDTH
+    }
+
+    /// 停靠在底部时条目横向排列，更适合宽而矮的区域
+    fn is_horizontal(&self, cx: &App) -> bool {
+        CustomPanelSettings::get_global(cx).dock == settings::DockPosition::Bottom
+    }
+
+    /// 渲染第 `index` 个条目
+    ///
+    /// 纵向列表中的条目高度固定为一行，文字单行截断，分隔线在行内居中，
+    /// 这样虚拟列表可以按第一行的高度推算每一行的位置。
+    fn render_item(
+        &self,
+        index: usize,
+        item: PanelItem,
+        style: RowStyle,
+        cx: &mut Context<Self>,
+    ) -> AnyElement {
+        let pinned = self.is_item_pinned(&item);
+        let (label, tooltip, key) = match item {
+            PanelItem::Entry {
+                label,
+                tooltip,
+                key,
+            } => (label, tooltip, key),
+            // 分隔线渲染为一条使用边框颜色的细线，横向排列时为竖线
+            PanelItem::Separator => {
+                let rule = div().bg(cx.theme().colors().border_variant);
+                return div()
+                    .id(index)
+                    .debug_selector(move || format!("custom-panel-separator-{index}"))
+                    .map(|this| {
+                        if style.horizontal {
+                            this.w_px().child(rule.size_full())
+                        } else {
+                            this.h(style.line_height)
+                                .flex()
+                                .items_center()
+                                .child(rule.w_full().h_px())
+                        }
+                    })
+                    .into_any_element();
+            }
+        };
+        div()
+            // 以索引作为元素 id，使其可以响应点击
+            .id(index)
+            // 测试中通过该选择器定位条目
+            .debug_selector(move || format!("custom-panel-item-{index}"))
+            // 设置文本颜色为静音色
+            .text_color(cx.theme().colors().text_muted)
+            .when(!style.horizontal, |this| {
+                this.h(style.line_height).items_center().truncate()
+            })
+            // 置顶图标、文本和翻译键排成一行
+            .flex()
+            .gap_1()
+            // 高亮所有选中的条目
+            .when(self.selection.contains(&index), |this| {
+                this.bg(cx.theme().colors().ghost_element_selected)
+            })
+            // 点击时按修饰键选中，普通点击交由数据源处理
+            .on_click(cx.listener(move |this, event: &ClickEvent, _, cx| {
+                this.handle_item_click(index, event, cx)
+            }))
+            // 有提示时在悬停才构建提示内容，并按当前语言解析
+            .when_some(tooltip, |this, tooltip| {
+                this.tooltip(Tooltip::element(move |_, _| {
+                    div()
+                        // 测试中通过该选择器定位提示
+                        .debug_selector(move || format!("custom-panel-tooltip-{index}"))
+                        .child(tooltip.resolve())
+                        .into_any_element()
+                }))
+            })
+            // 置顶的条目在文本前显示图钉图标
+            .when(pinned, |this| {
+                this.child(
+                    div()
+                        .debug_selector(move || format!("custom-panel-pin-{index}"))
+                        .child(
+                            Icon::new(IconName::Pin)
+                                .size(IconSize::XSmall)
+                                .color(Color::Muted),
+                        ),
+                )
+            })
+            // 设置条目文本
+            .child(label)
+            // 调试模式下在文本旁显示翻译键
+            .when_some(key.filter(|_| style.show_translation_keys), |this, key| {
+                this.child(
+                    div()
+                        .debug_selector(move || format!("custom-panel-key-{index}"))
+                        .child(Label::new(key).size(LabelSize::XSmall).color(Color::Muted)),
+                )
+            })
+            .into_any_element()
+    }
+
+    // 处理 ToggleTranslationKeys 动作
+    fn toggle_translation_keys(
+        &mut self,
+        _: &ToggleTranslationKeys,
+        _: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.show_translation_keys = !self.show_translation_keys;
+        cx.notify();
+    }
+
+    // 处理 IncreaseWidth 动作
+    fn increase_width(&mut self, _: &IncreaseWidth, window: &mut Window, cx: &mut Context<Self>) {
+        self.resize_by(WIDTH_STEP, window, cx);
+    }
+
+    // 处理 DecreaseWidth 动作
+    fn decrease_width(&mut self, _: &DecreaseWidth, window: &mut Window, cx: &mut Context<Self>) {
+        self.resize_by(-WIDTH_STEP, window, cx);
     }
 
     /// 异步加载自定义面板
@@ -62,23 +1117,132 @@ impl CustomPanel {
         // 异步窗口上下文，用于在异步操作中更新 UI
         mut cx: AsyncWindowContext,
     ) -> Result<Entity<Self>> {
+        // 读取上次保存的状态，读取失败或无法识别时使用默认状态
+        let serialization_key = workspace
+            .read_with(&cx, |workspace, _| {
+                Self::serialization_key(DEFAULT_PANEL_ID, workspace)
+            })
+            .ok()
+            .flatten();
+        let state = match serialization_key {
+            Some(key) => cx
+                .background_spawn(async move { KEY_VALUE_STORE.read_kvp(&key) })
+                .await
+                .unwrap_or_else(|error| {
+                    log::warn!("failed to load custom panel state: {error}");
+                    None
+                })
+                .and_then(|json| CustomPanelState::deserialize(&json)),
+            None => None,
+        };
+
         // 在异步上下文中更新 workspace，调用 new 方法创建面板
+        workspace.update_in(&mut cx, |workspace, window, cx| {
+            let panel = Self::new(workspace, window, cx);
+            if let Some(state) = state {
+                panel.update(cx, |panel, cx| {
+                    panel.width = state.width;
+                    panel.height = state.height;
+                    panel.pinned = state.pinned.into_iter().map(SharedString::from).collect();
+                    panel.invalidate_filter_cache();
+                    cx.notify();
+                });
+            }
+            panel
+        })
+    }
+
+    /// 持久化状态使用的键，每个 workspace 中的每个面板各自保存
+    fn serialization_key(panel_id: &str, workspace: &Workspace) -> Option<String> {
         workspace
-            .update_in(&mut cx, |workspace, window, cx| {
-                Self::new(workspace, window, cx)
+            .database_id()
+            .map(|id| i64::from(id).to_string())
+            .or(workspace.session_id())
+            .map(|workspace_id| Self::serialization_key_for(panel_id, &workspace_id))
+    }
+
+    fn serialization_key_for(panel_id: &str, workspace_id: &str) -> String {
+        if panel_id == DEFAULT_PANEL_ID {
+            // 默认面板沿用加入 id 之前的键
+            format!("{}-{:?}", CUSTOM_PANEL_KEY, workspace_id)
+        } else {
+            format!("{}-{}-{:?}", CUSTOM_PANEL_KEY, panel_id, workspace_id)
+        }
+    }
+
+    /// 在 [`SERIALIZATION_DEBOUNCE`] 之后于后台保存当前状态
+    ///
+    /// 新的调用会取消尚未写入的保存，连续拖动调整尺寸时只写入最后的状态。
+    fn serialize(&mut self, cx: &mut Context<Self>) {
+        let Some(serialization_key) = self
+            .workspace
+            .read_with(cx, |workspace, _| {
+                Self::serialization_key(&self.id, workspace)
             })
+            .ok()
+            .flatten()
+        else {
+            return;
+        };
+        let mut pinned = self
+            .pinned
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>();
+        pinned.sort_unstable();
+        let state = CustomPanelState {
+            width: self.width,
+            height: self.height,
+            pinned,
+            ..Default::default()
+        };
+        let json = match serde_json::to_string(&state) {
+            Ok(json) => json,
+            Err(error) => {
+                log::warn!("failed to serialize custom panel state: {error}");
+                return;
+            }
+        };
+        self.pending_serialization = cx.spawn(async move |_, cx| {
+            cx.background_executor().timer(SERIALIZATION_DEBOUNCE).await;
+            KEY_VALUE_STORE
+                .write_kvp(serialization_key, json)
+                .await
+                .inspect_err(|error| log::warn!("failed to save custom panel state: {error}"))
+                .ok()
+        });
     }
 }
 
 /// 初始化自定义面板，注册切换焦点动作
 pub fn init(cx: &mut App) {
     // 观察新创建的 workspace 实例
-    cx.observe_new(|workspace: &mut Workspace, _, _| {
+    cx.observe_new(|workspace: &mut Workspace, _, cx| {
         // 在 workspace 中注册 ToggleFocus 动作
         workspace.register_action(|workspace, _: &ToggleFocus, window, cx| {
             // 切换 CustomPanel 的焦点
             workspace.toggle_panel_focus::<CustomPanel>(window, cx);
         });
+
+        // 翻译预览面板只是给翻译者使用的调试工具，仅在 Dev 渠道注册
+        if ReleaseChannel::try_global(cx) == Some(ReleaseChannel::Dev) {
+            workspace.register_action(|workspace, _: &OpenTranslationPreview, window, cx| {
+                // 首次打开时才创建面板并加入 dock
+                if workspace.panel::<TranslationPreviewPanel>(cx).is_none() {
+                    let panel = TranslationPreviewPanel::new(cx);
+                    workspace.add_panel(panel, window, cx);
+                }
+                workspace.focus_panel::<TranslationPreviewPanel>(window, cx);
+            });
+            workspace.register_action(
+                |workspace, _: &translation_preview::ToggleFocus, window, cx| {
+                    workspace.toggle_panel_focus::<TranslationPreviewPanel>(window, cx);
+                },
+            );
+            workspace.register_action(|_, _: &LogTranslationDiff, _, _| {
+                translation_preview::log_translation_diff();
+            });
+        }
     })
     // 分离任务，使其在后台运行
     .detach();
@@ -97,43 +1261,68 @@ impl Panel for CustomPanel {
     }
 
     // 返回面板在 dock 中的位置
-    fn position(&self, _window: &Window, _cx: &App) -> DockPosition {
-        DockPosition::Left
+    fn position(&self, _window: &Window, cx: &App) -> DockPosition {
+        CustomPanelSettings::get_global(cx).dock.into()
     }
 
     // 检查给定的位置是否有效
     fn position_is_valid(&self, position: DockPosition) -> bool {
-        // 只允许左侧或右侧位置
-        matches!(position, DockPosition::Left | DockPosition::Right)
+        // 左侧、右侧和底部都可以停靠，停靠在底部时使用横向布局
+        matches!(
+            position,
+            DockPosition::Left | DockPosition::Right | DockPosition::Bottom
+        )
     }
 
-    // 设置面板位置（当前为空实现）
-    fn set_position(&mut self, _position: DockPosition, _window: &mut Window, _cx: &mut Context<Self>) {
-        // 预留方法，可以在此更新设置或状态
+    // 设置面板位置
+    //
+    // 位置保存在设置中，Dock 在设置变化时才会把面板移动到新的位置，
+    // 面板也会在设置变化时重新渲染，在底部和两侧之间切换布局
+    fn set_position(
+        &mut self,
+        position: DockPosition,
+        _window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        settings::update_settings_file(<dyn Fs>::global(cx), cx, move |settings, _| {
+            settings.custom_panel.get_or_insert_default().dock = Some(position.into());
+        });
     }
 
-    // 返回面板的宽度
-    fn size(&self, _window: &Window, _cx: &App) -> Pixels {
-        // 如果有设置宽度则使用设置值，否则使用默认值 280 像素
-        self.width.unwrap_or(px(280.))
+    // 返回面板的尺寸，停靠在底部时为高度，否则为宽度
+    fn size(&self, _window: &Window, cx: &App) -> Pixels {
+        // 如果有保存的尺寸则使用保存值，否则宽度使用设置中的默认宽度
+        if self.is_horizontal(cx) {
+            self.height.unwrap_or(DEFAULT_HEIGHT)
+        } else {
+            self.width
+                .unwrap_or_else(|| CustomPanelSettings::get_global(cx).default_width)
+        }
     }
 
-    // 设置面板宽度
+    // 设置面板尺寸，停靠在底部时为高度，否则为宽度
     fn set_size(&mut self, size: Option<Pixels>, _window: &mut Window, cx: &mut Context<Self>) {
-        // 更新宽度
-        self.width = size;
+        // 更新尺寸
+        if self.is_horizontal(cx) {
+            self.height = size;
+        } else {
+            self.width = size;
+        }
         // 通知视图需要重新渲染
         cx.notify();
+        // 保存新的宽度，下次打开时恢复
+        self.serialize(cx);
     }
 
     // 返回面板的图标
-    fn icon(&self, _window: &Window, _cx: &App) -> Option<IconName> {
-        Some(IconName::Star)
+    fn icon(&self, _window: &Window, cx: &App) -> Option<IconName> {
+        let name = &CustomPanelSettings::get_global(cx).icon;
+        Some(resolve_icon(name, &WARNED_INVALID_ICON))
     }
 
     // 返回图标的提示文本
-    fn icon_tooltip(&self, _window: &Window, _cx: &App) -> Option<&'static str> {
-        Some(i18n::t_static("panel.custom_panel"))
+    fn icon_tooltip(&self, _window: &Window, _cx: &App) -> Option<SharedString> {
+        Some(i18n::t_shared("panel.custom_panel"))
     }
 
     // 返回切换面板的动作
@@ -158,46 +1347,1557 @@ impl Focusable for CustomPanel {
 
 // 实现 EventEmitter trait，使面板可以发出事件
 impl EventEmitter<PanelEvent> for CustomPanel {}
+impl EventEmitter<CustomPanelEvent> for CustomPanel {}
 
 // 实现 Render trait，定义面板的渲染逻辑
 impl Render for CustomPanel {
     // 渲染面板内容
-    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
-        // 使用 i18n 翻译函数获取本地化文本
-        let title = t("custom_panel.title");
-        let hello_text = t("custom_panel.hello");
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        // 窄面板中缩小间距
+        let narrow = self.is_narrow(window, cx);
+        // 停靠在底部时条目横向排列
+        let horizontal = self.is_horizontal(cx);
+        // 中日韩文字使用稍大的行高
+        let line_height = Self::content_line_height(i18n::get_language(), window);
+        // 显示翻译键只是给翻译者使用的调试工具，仅在 Dev 渠道提供
+        let dev_channel = ReleaseChannel::try_global(cx) == Some(ReleaseChannel::Dev);
+        let show_translation_keys = dev_channel && self.show_translation_keys;
+        let style = RowStyle {
+            horizontal,
+            show_translation_keys,
+            line_height,
+        };
 
         // 创建一个占满整个空间的 div 容器
         div()
+            // 按键上下文，使面板内的快捷键生效
+            .key_context(CUSTOM_PANEL_KEY)
+            // 跟踪焦点，面板获得焦点时才响应动作
+            .track_focus(&self.focus_handle)
+            // 键盘调整面板宽度
+            .on_action(cx.listener(Self::increase_width))
+            .on_action(cx.listener(Self::decrease_width))
+            // 键盘移动选中项和激活条目
+            .on_action(cx.listener(Self::select_next))
+            .on_action(cx.listener(Self::select_previous))
+            .on_action(cx.listener(Self::confirm))
+            .on_action(cx.listener(Self::handle_refresh))
+            .on_action(cx.listener(Self::handle_toggle_pin))
+            // 多选和批量操作
+            .on_action(cx.listener(Self::extend_selection_next))
+            .on_action(cx.listener(Self::extend_selection_previous))
+            .on_action(cx.listener(Self::copy))
+            .on_action(cx.listener(Self::remove))
+            .on_action(cx.listener(Self::save_filter))
+            .when(dev_channel, |this| {
+                this.on_action(cx.listener(Self::toggle_translation_keys))
+            })
+            // 顶部工具栏：左侧是面板图标和标题，右侧是刷新按钮和 Dev 渠道下的翻译键开关
+            .child(
+                h_flex()
+                    .justify_between()
+                    .gap_1()
+                    .child(
+                        h_flex()
+                            .debug_selector(|| "custom-panel-header".into())
+                            .gap_1()
+                            .min_w_0()
+                            .when_some(self.icon(window, cx), |this, icon| {
+                                this.child(
+                                    Icon::new(icon).size(IconSize::Small).color(Color::Muted),
+                                )
+                            })
+                            .child(
+                                Label::new(self.source.title(cx))
+                                    .size(LabelSize::Small)
+                                    .truncate(),
+                            ),
+                    )
+                    .child(
+                        h_flex()
+                            .gap_1()
+                            .child(self.render_saved_filters_menu(cx))
+                            .map(|this| {
+                                // 刷新期间用加载动画代替刷新按钮
+                                if self.is_refreshing() {
+                                    this.child(
+                                        div()
+                                            .debug_selector(|| "custom-panel-refreshing".into())
+                                            .child(SpinnerLabel::new().size(LabelSize::Small)),
+                                    )
+                                } else {
+                                    this.child(
+                                        IconButton::new("custom-panel-refresh", IconName::RotateCw)
+                                            .icon_size(IconSize::Small)
+                                            .tooltip(Tooltip::text(i18n::t_shared(
+                                                "custom_panel.refresh",
+                                            )))
+                                            .on_click(|_, window, cx| {
+                                                window.dispatch_action(Box::new(Refresh), cx)
+                                            }),
+                                    )
+                                }
+                            })
+                            .when(dev_channel, |this| {
+                                this.child(
+                                    IconButton::new(
+                                        "custom-panel-toggle-translation-keys",
+                                        IconName::Code,
+                                    )
+                                    .icon_size(IconSize::Small)
+                                    .toggle_state(self.show_translation_keys)
+                                    .tooltip(Tooltip::text(i18n::t_shared(
+                                        "custom_panel.show_translation_keys",
+                                    )))
+                                    .on_click(
+                                        |_, window, cx| {
+                                            window.dispatch_action(
+                                                Box::new(ToggleTranslationKeys),
+                                                cx,
+                                            )
+                                        },
+                                    ),
+                                )
+                            }),
+                    ),
+            )
+            // 搜索框，按条目文本筛选
+            .child(
+                div()
+                    .px_1()
+                    .border_1()
+                    .border_color(cx.theme().colors().border)
+                    .rounded_sm()
+                    .child(self.search_editor.clone()),
+            )
+            // 保存筛选时在搜索框下方输入名称，回车保存，Esc 放弃
+            .when_some(self.filter_name_editor.clone(), |this, editor| {
+                this.child(
+                    div()
+                        .debug_selector(|| "custom-panel-filter-name".into())
+                        .on_action(cx.listener(Self::confirm_save_filter))
+                        .on_action(cx.listener(Self::cancel_save_filter))
+                        .px_1()
+                        .border_1()
+                        .border_color(cx.theme().colors().border_focused)
+                        .rounded_sm()
+                        .child(editor),
+                )
+            })
+            // 活动文件的名称和语言，没有活动的编辑器时显示提示
+            .when(self.tracks_active_file, |this| {
+                this.child(
+                    h_flex()
+                        .debug_selector(|| "custom-panel-active-file".into())
+                        .gap_1()
+                        .min_w_0()
+                        .map(|this| match &self.active_file {
+                            Some(file) => this
+                                .child(Label::new(file.name.clone()).truncate())
+                                .when_some(file.language.clone(), |this, language| {
+                                    this.child(
+                                        Label::new(language)
+                                            .size(LabelSize::Small)
+                                            .color(Color::Muted),
+                                    )
+                                }),
+                            None => this.child(
+                                Label::new(i18n::t_shared("custom_panel.no_active_file"))
+                                    .color(Color::Muted),
+                            ),
+                        }),
+                )
+            })
             // 设置容器大小为全屏
             .size_full()
             // 设置为 flex 布局
             .flex()
             // 设置为垂直布局（列方向）
             .flex_col()
-            // 设置子元素间距为 2（默认单位）
-            .gap_2()
-            // 设置内边距为 2（默认单位）
-            .p_2()
+            // 设置子元素间距和内边距，紧凑布局时减半
+            .map(|this| {
+                if narrow {
+                    this.gap_1().p_1()
+                } else {
+                    this.gap_2().p_2()
+                }
+            })
             // 设置背景色为面板背景色
             .bg(cx.theme().colors().panel_background)
-            // 添加面板标题子元素
-            .child(
-                // 创建标题 div
+            .line_height(line_height)
+            // 条目容器，停靠在底部时横向排列并自动换行
+            .child(if horizontal {
+                // 横向排列的条目宽度不一且会换行，无法按固定行高虚拟化，全部渲染
+                let rows = self.filtered_indices(cx);
+                let rows = rows.as_deref();
+                let items = (0..self.row_count(rows, cx))
+                    .map(|row| Self::index_at(rows, row))
+                    .filter_map(|index| Some((index, self.source.item(index, cx)?)))
+                    .collect::<Vec<_>>();
                 div()
-                    // 设置文本颜色为静音色
-                    .text_color(cx.theme().colors().text_muted)
-                    // 设置标题文本内容（使用翻译）
-                    .child(title),
-            )
-            // 添加内容文本子元素
-            .child(
-                // 创建内容 div
+                    .id("custom-panel-items-horizontal")
+                    // 测试中通过该选择器判断使用的布局
+                    .debug_selector(|| "custom-panel-items-horizontal".into())
+                    // 条目换行后超出面板高度时，只滚动条目区域，工具栏保持可见
+                    .flex_1()
+                    .min_h_0()
+                    .overflow_y_scroll()
+                    .track_scroll(&self.horizontal_scroll_handle)
+                    .child(
+                        div()
+                            .flex()
+                            .flex_row()
+                            .flex_wrap()
+                            .map(|this| if narrow { this.gap_1() } else { this.gap_2() })
+                            .children(
+                                items
+                                    .into_iter()
+                                    .map(|(index, item)| self.render_item(index, item, style, cx)),
+                            ),
+                    )
+                    .vertical_scrollbar_for(&self.horizontal_scroll_handle, window, cx)
+                    .into_any_element()
+            } else {
+                // 纵向列表只渲染可见的行，可见行的条目按索引向数据源获取
+                let rows = self.filtered_indices(cx);
+                let row_count = self.row_count(rows.as_deref(), cx);
                 div()
-                    // 设置文本颜色为静音色
-                    .text_color(cx.theme().colors().text_muted)
-                    // 设置文本内容（使用翻译）
-                    .child(hello_text),
+                    .id("custom-panel-items-vertical")
+                    // 测试中通过该选择器判断使用的布局
+                    .debug_selector(|| "custom-panel-items-vertical".into())
+                    .flex_1()
+                    .min_h_0()
+                    .child(
+                        uniform_list(
+                            "custom-panel-items",
+                            row_count,
+                            cx.processor(move |this, range: Range<usize>, _window, cx| {
+                                range
+                                    .filter_map(|row| {
+                                        // 筛选时行号与数据源中的索引不再一一对应
+                                        let index = match &rows {
+                                            Some(rows) => *rows.get(row)?,
+                                            None => row,
+                                        };
+                                        let item = this.source.item(index, cx)?;
+                                        // 行间距放在行内，使每行高度相同
+                                        let element =
+                                            div().child(this.render_item(index, item, style, cx));
+                                        Some(if narrow {
+                                            element.pb_1()
+                                        } else {
+                                            element.pb_2()
+                                        })
+                                    })
+                                    .collect()
+                            }),
+                        )
+                        .size_full()
+                        .track_scroll(&self.scroll_handle),
+                    )
+                    .vertical_scrollbar_for(&self.scroll_handle, window, cx)
+                    .into_any_element()
+            })
+            // 键盘调整宽度后显示新的宽度
+            .when_some(self.width_announcement.clone(), |this, announcement| {
+                this.child(
+                    Label::new(announcement)
+                        .size(LabelSize::Small)
+                        .color(Color::Muted),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fs::FakeFs;
+    use gpui::{Modifiers, TestAppContext, VisualTestContext};
+    use project::Project;
+    use std::{
+        cell::{Cell, RefCell},
+        collections::BTreeMap,
+        rc::Rc,
+        time::Duration,
+    };
+
+    struct MockSource {
+        activated: Rc<RefCell<Vec<usize>>>,
+    }
+
+    impl CustomPanelSource for MockSource {
+        fn item_count(&self, _cx: &App) -> usize {
+            2
+        }
+
+        fn item(&self, index: usize, _cx: &App) -> Option<PanelItem> {
+            ["first", "second"]
+                .get(index)
+                .map(|label| PanelItem::new(*label))
+        }
+
+        fn on_activate(&self, index: usize, _cx: &mut App) {
+            self.activated.borrow_mut().push(index);
+        }
+    }
+
+    struct TooltipSource;
+
+    impl CustomPanelSource for TooltipSource {
+        fn item_count(&self, _cx: &App) -> usize {
+            2
+        }
+
+        fn item(&self, index: usize, _cx: &App) -> Option<PanelItem> {
+            match index {
+                0 => Some(PanelItem::new("plain")),
+                1 => Some(
+                    PanelItem::new("with tooltip")
+                        .with_tooltip(PanelTooltip::Key("custom_panel.title")),
+                ),
+                _ => None,
+            }
+        }
+
+        fn on_activate(&self, _index: usize, _cx: &mut App) {}
+    }
+
+    struct SeparatorSource;
+
+    impl CustomPanelSource for SeparatorSource {
+        fn item_count(&self, _cx: &App) -> usize {
+            3
+        }
+
+        fn item(&self, index: usize, _cx: &App) -> Option<PanelItem> {
+            match index {
+                0 => Some(PanelItem::new("first")),
+                1 => Some(PanelItem::Separator),
+                2 => Some(PanelItem::new("second")),
+                _ => None,
+            }
+        }
+
+        fn on_activate(&self, _index: usize, _cx: &mut App) {}
+    }
+
+    struct LanguagesSource;
+
+    impl CustomPanelSource for LanguagesSource {
+        fn item_count(&self, _cx: &App) -> usize {
+            3
+        }
+
+        fn item(&self, index: usize, _cx: &App) -> Option<PanelItem> {
+            ["中文", "日本語", "English"]
+                .get(index)
+                .map(|label| PanelItem::new(*label))
+        }
+
+        fn on_activate(&self, _index: usize, _cx: &mut App) {}
+    }
+
+    struct RefreshingSource {
+        item_queries: Rc<Cell<usize>>,
+    }
+
+    impl CustomPanelSource for RefreshingSource {
+        // 每次渲染纵向列表时都会查询一次条目数量
+        fn item_count(&self, _cx: &App) -> usize {
+            self.item_queries.set(self.item_queries.get() + 1);
+            1
+        }
+
+        fn item(&self, index: usize, _cx: &App) -> Option<PanelItem> {
+            (index == 0).then(|| PanelItem::new(format!("query {}", self.item_queries.get())))
+        }
+
+        fn on_activate(&self, _index: usize, _cx: &mut App) {}
+
+        fn refresh(&self, cx: &mut App) -> Task<()> {
+            let timer = cx.background_executor().timer(Duration::from_secs(1));
+            cx.background_spawn(timer)
+        }
+    }
+
+    struct RemovableSource {
+        labels: Rc<RefCell<Vec<&'static str>>>,
+    }
+
+    impl CustomPanelSource for RemovableSource {
+        fn item_count(&self, _cx: &App) -> usize {
+            self.labels.borrow().len()
+        }
+
+        fn item(&self, index: usize, _cx: &App) -> Option<PanelItem> {
+            self.labels
+                .borrow()
+                .get(index)
+                .map(|label| PanelItem::new(*label))
+        }
+
+        fn on_activate(&self, _index: usize, _cx: &mut App) {}
+
+        fn on_remove(&self, indices: &[usize], _cx: &mut App) -> bool {
+            for &index in indices.iter().rev() {
+                self.labels.borrow_mut().remove(index);
+            }
+            true
+        }
+    }
+
+    struct LargeSource {
+        count: usize,
+        item_queries: Rc<Cell<usize>>,
+    }
+
+    impl CustomPanelSource for LargeSource {
+        fn item_count(&self, _cx: &App) -> usize {
+            self.count
+        }
+
+        fn item(&self, index: usize, _cx: &App) -> Option<PanelItem> {
+            self.item_queries.set(self.item_queries.get() + 1);
+            (index < self.count).then(|| PanelItem::new(format!("item {index}")))
+        }
+
+        fn on_activate(&self, _index: usize, _cx: &mut App) {}
+    }
+
+    fn init_test(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            let settings = SettingsStore::test(cx);
+            cx.set_global(settings);
+            theme::init(theme::LoadThemes::JustBase, cx);
+        });
+    }
+
+    /// 直接修改设置中的停靠位置，不经过设置文件
+    fn dock_at(position: DockPosition, cx: &mut VisualTestContext) {
+        cx.update(|_, cx| {
+            SettingsStore::update_global(cx, |store, cx| {
+                store.update_user_settings(cx, |settings| {
+                    settings.custom_panel.get_or_insert_default().dock = Some(position.into());
+                });
+            });
+        });
+    }
+
+    #[gpui::test]
+    fn test_items_come_from_source(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let activated = Rc::new(RefCell::new(Vec::new()));
+        let source = MockSource {
+            activated: activated.clone(),
+        };
+        let (_panel, cx) = cx.add_window_view(|window, cx| {
+            CustomPanel::with_source(WeakEntity::new_invalid(), Box::new(source), window, cx)
+        });
+        cx.run_until_parked();
+
+        assert!(cx.debug_bounds("custom-panel-item-0").is_some());
+        assert!(cx.debug_bounds("custom-panel-item-2").is_none());
+        let second = cx.debug_bounds("custom-panel-item-1").unwrap();
+
+        cx.simulate_click(second.center(), Modifiers::none());
+        assert_eq!(*activated.borrow(), vec![1]);
+    }
+
+    #[gpui::test]
+    fn test_icon_tooltip_does_not_leak(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let (panel, cx) = cx.add_window_view(|window, cx| {
+            CustomPanel::with_source(
+                WeakEntity::new_invalid(),
+                Box::new(DefaultSource),
+                window,
+                cx,
+            )
+        });
+        let leaked = i18n::leaked_string_count();
+        for _ in 0..3 {
+            let tooltip = cx.update(|window, cx| panel.read(cx).icon_tooltip(window, cx));
+            assert_eq!(tooltip, Some(i18n::t("panel.custom_panel").into()));
+        }
+        assert_eq!(i18n::leaked_string_count(), leaked);
+    }
+
+    #[gpui::test]
+    fn test_item_tooltip_shown_on_hover(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let (_panel, cx) = cx.add_window_view(|window, cx| {
+            CustomPanel::with_source(
+                WeakEntity::new_invalid(),
+                Box::new(TooltipSource),
+                window,
+                cx,
+            )
+        });
+        cx.run_until_parked();
+        assert!(cx.debug_bounds("custom-panel-tooltip-1").is_none());
+
+        // 悬停在没有提示的条目上不会显示提示
+        let plain = cx.debug_bounds("custom-panel-item-0").unwrap();
+        cx.simulate_mouse_move(plain.center(), None, Modifiers::none());
+        cx.executor().advance_clock(Duration::from_secs(1));
+        cx.run_until_parked();
+        assert!(cx.debug_bounds("custom-panel-tooltip-0").is_none());
+
+        let item = cx.debug_bounds("custom-panel-item-1").unwrap();
+        cx.simulate_mouse_move(item.center(), None, Modifiers::none());
+        cx.executor().advance_clock(Duration::from_secs(1));
+        cx.run_until_parked();
+        assert!(cx.debug_bounds("custom-panel-tooltip-1").is_some());
+    }
+
+    #[test]
+    fn test_warns_once_when_i18n_is_uninitialized() {
+        let warned = AtomicBool::new(false);
+        assert!(!warn_once_if_i18n_uninitialized(true, &warned));
+        assert!(!warned.load(Ordering::Relaxed));
+
+        assert!(warn_once_if_i18n_uninitialized(false, &warned));
+        assert!(!warn_once_if_i18n_uninitialized(false, &warned));
+        assert!(!warn_once_if_i18n_uninitialized(true, &warned));
+    }
+
+    #[test]
+    fn test_panels_persist_state_under_separate_keys() {
+        // 默认面板的键与加入 id 之前相同，已保存的状态仍然可以读取
+        assert_eq!(
+            CustomPanel::serialization_key_for(DEFAULT_PANEL_ID, "42"),
+            format!("{}-{:?}", CUSTOM_PANEL_KEY, "42")
+        );
+
+        let keys = [DEFAULT_PANEL_ID, "todo", "notes"]
+            .map(|panel_id| CustomPanel::serialization_key_for(panel_id, "42"));
+        assert_ne!(keys[0], keys[1]);
+        assert_ne!(keys[0], keys[2]);
+        assert_ne!(keys[1], keys[2]);
+        // 不同 workspace 中的同一个面板也互不影响
+        assert_ne!(keys[1], CustomPanel::serialization_key_for("todo", "43"));
+    }
+
+    #[test]
+    fn test_invalid_icon_falls_back_to_star() {
+        let warned = AtomicBool::new(false);
+        assert_eq!(resolve_icon("file_tree", &warned), IconName::FileTree);
+        assert!(!warned.load(Ordering::Relaxed));
+
+        assert_eq!(resolve_icon("not_an_icon", &warned), FALLBACK_ICON);
+        assert!(warned.load(Ordering::Relaxed));
+        assert_eq!(resolve_icon("Star ", &warned), FALLBACK_ICON);
+    }
+
+    #[test]
+    fn test_panel_translation_keys_exist_in_all_locales() {
+        const KEYS: &[&str] = &[
+            "custom_panel.title",
+            "custom_panel.hello",
+            "custom_panel.width_announcement",
+            "custom_panel.refresh",
+            "custom_panel.search_placeholder",
+            "custom_panel.search_placeholder_ime",
+            "custom_panel.no_active_file",
+            "custom_panel.saved_filters",
+            "custom_panel.save_filter",
+            "custom_panel.filter_name_placeholder",
+            "custom_panel.show_translation_keys",
+            "panel.custom_panel",
+        ];
+
+        let mut missing = Vec::new();
+        for &lang in i18n::Language::all() {
+            let translations = i18n::bundled_translations(lang);
+            for &key in KEYS {
+                if translations.get(key).is_none_or(|value| value.is_empty()) {
+                    missing.push((lang.as_str(), key));
+                }
+            }
+        }
+        assert!(
+            missing.is_empty(),
+            "custom panel keys missing from bundled locales: {missing:?}"
+        );
+    }
+
+    #[test]
+    fn test_tooltip_resolves_translation_key() {
+        assert_eq!(
+            PanelTooltip::Key("custom_panel.title").resolve(),
+            SharedString::from(i18n::t("custom_panel.title"))
+        );
+        assert_eq!(
+            PanelTooltip::Text("raw".into()).resolve(),
+            SharedString::from("raw")
+        );
+    }
+
+    fn set_focus_follows_selection(enabled: bool, cx: &mut VisualTestContext) {
+        cx.update(|_, cx| {
+            SettingsStore::update_global(cx, |store, cx| {
+                store.update_user_settings(cx, |settings| {
+                    settings
+                        .custom_panel
+                        .get_or_insert_default()
+                        .focus_follows_selection = Some(enabled);
+                });
+            });
+        });
+    }
+
+    #[gpui::test]
+    fn test_focus_follows_selection(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let activated = Rc::new(RefCell::new(Vec::new()));
+        let source = MockSource {
+            activated: activated.clone(),
+        };
+        let (panel, cx) = cx.add_window_view(|window, cx| {
+            CustomPanel::with_source(WeakEntity::new_invalid(), Box::new(source), window, cx)
+        });
+        let events = Rc::new(RefCell::new(Vec::new()));
+        cx.update(|window, cx| {
+            let events = events.clone();
+            cx.subscribe(&panel, move |_, event: &CustomPanelEvent, _| {
+                events.borrow_mut().push(*event);
+            })
+            .detach();
+            panel.focus_handle(cx).focus(window, cx);
+        });
+        cx.run_until_parked();
+
+        // 默认关闭：移动选中项不会激活，按回车才激活
+        cx.dispatch_action(menu::SelectNext);
+        assert_eq!(
+            panel.read_with(cx, |panel, _| panel.selected_index()),
+            Some(0)
+        );
+        cx.dispatch_action(menu::SelectNext);
+        assert_eq!(
+            panel.read_with(cx, |panel, _| panel.selected_index()),
+            Some(1)
+        );
+        assert!(events.borrow().is_empty());
+        assert!(activated.borrow().is_empty());
+        cx.dispatch_action(menu::Confirm);
+        assert_eq!(*events.borrow(), vec![CustomPanelEvent::ItemActivated(1)]);
+        assert_eq!(*activated.borrow(), vec![1]);
+
+        // 开启后移动选中项立即激活
+        events.borrow_mut().clear();
+        activated.borrow_mut().clear();
+        set_focus_follows_selection(true, cx);
+        cx.dispatch_action(menu::SelectNext);
+        cx.dispatch_action(menu::SelectPrevious);
+        assert_eq!(
+            panel.read_with(cx, |panel, _| panel.selected_index()),
+            Some(1)
+        );
+        assert_eq!(
+            *events.borrow(),
+            vec![
+                CustomPanelEvent::ItemActivated(0),
+                CustomPanelEvent::ItemActivated(1),
+            ]
+        );
+        assert_eq!(*activated.borrow(), vec![0, 1]);
+    }
+
+    #[gpui::test]
+    fn test_default_source_uses_configured_keys(cx: &mut TestAppContext) {
+        init_test(cx);
+        cx.update(|cx| {
+            SettingsStore::update_global(cx, |store, cx| {
+                store.update_user_settings(cx, |settings| {
+                    let panel = settings.custom_panel.get_or_insert_default();
+                    panel.title_key = Some("menu.file".to_string());
+                    panel.hello_key = Some("menu.edit".to_string());
+                });
+            });
+        });
+
+        let (panel, cx) = cx.add_window_view(|window, cx| {
+            CustomPanel::with_source(
+                WeakEntity::new_invalid(),
+                Box::new(DefaultSource),
+                window,
+                cx,
+            )
+        });
+        cx.run_until_parked();
+
+        let (title, items) = panel.read_with(cx, |panel, cx| {
+            (panel.source.title(cx), panel.source.items(cx))
+        });
+        // 标题显示在标题栏中，不作为条目出现
+        assert_eq!(title, i18n::t("menu.file"));
+        assert_eq!(items, vec![PanelItem::translated("menu.edit")]);
+        assert!(cx.debug_bounds("custom-panel-item-0").is_some());
+        assert!(cx.debug_bounds("custom-panel-item-1").is_none());
+    }
+
+    #[gpui::test]
+    fn test_narrow_layout(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let (panel, cx) = cx.add_window_view(|window, cx| {
+            CustomPanel::with_source(
+                WeakEntity::new_invalid(),
+                Box::new(DefaultSource),
+                window,
+                cx,
+            )
+        });
+        let item_offset = |width: Pixels, cx: &mut VisualTestContext| {
+            cx.update(|window, cx| {
+                panel.update(cx, |panel, cx| panel.set_size(Some(width), window, cx))
+            });
+            cx.run_until_parked();
+            cx.debug_bounds("custom-panel-item-0").unwrap().origin.x
+        };
+
+        let wide = item_offset(NARROW_WIDTH, cx);
+        let narrow = item_offset(NARROW_WIDTH - px(1.), cx);
+        assert!(narrow < wide, "narrow layout should use a smaller inset");
+        assert_eq!(item_offset(DEFAULT_WIDTH, cx), wide);
+    }
+
+    #[gpui::test]
+    fn test_line_height_follows_language(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let (_panel, cx) = cx.add_window_view(|window, cx| {
+            CustomPanel::with_source(
+                WeakEntity::new_invalid(),
+                Box::new(DefaultSource),
+                window,
+                cx,
+            )
+        });
+        cx.run_until_parked();
+
+        let (english, japanese) = cx.update(|window, _| {
+            (
+                CustomPanel::content_line_height(i18n::Language::English, window),
+                CustomPanel::content_line_height(i18n::Language::Japanese, window),
+            )
+        });
+        assert!(japanese > english);
+
+        let expected =
+            cx.update(|window, _| CustomPanel::content_line_height(i18n::get_language(), window));
+        let item = cx.debug_bounds("custom-panel-item-0").unwrap();
+        assert_eq!(item.size.height, expected);
+    }
+
+    #[gpui::test]
+    fn test_refresh_requeries_source(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let item_queries = Rc::new(Cell::new(0));
+        let source = RefreshingSource {
+            item_queries: item_queries.clone(),
+        };
+        let (panel, cx) = cx.add_window_view(|window, cx| {
+            CustomPanel::with_source(WeakEntity::new_invalid(), Box::new(source), window, cx)
+        });
+        cx.update(|window, cx| panel.focus_handle(cx).focus(window, cx));
+        cx.run_until_parked();
+        assert!(cx.debug_bounds("custom-panel-refreshing").is_none());
+
+        cx.dispatch_action(Refresh);
+        cx.run_until_parked();
+        assert!(panel.read_with(cx, |panel, _| panel.is_refreshing()));
+        assert!(cx.debug_bounds("custom-panel-refreshing").is_some());
+
+        // 再次刷新会取消进行中的刷新，以新的刷新为准
+        cx.executor().advance_clock(Duration::from_millis(500));
+        cx.dispatch_action(Refresh);
+        cx.executor().advance_clock(Duration::from_millis(600));
+        cx.run_until_parked();
+        assert!(panel.read_with(cx, |panel, _| panel.is_refreshing()));
+
+        let queries_before = item_queries.get();
+        cx.executor().advance_clock(Duration::from_millis(400));
+        cx.run_until_parked();
+        assert!(!panel.read_with(cx, |panel, _| panel.is_refreshing()));
+        assert!(cx.debug_bounds("custom-panel-refreshing").is_none());
+        assert!(item_queries.get() > queries_before);
+    }
+
+    #[gpui::test]
+    fn test_rapid_events_are_coalesced_into_few_renders(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        struct Ticker;
+        impl EventEmitter<()> for Ticker {}
+
+        let item_queries = Rc::new(Cell::new(0));
+        let source = RefreshingSource {
+            item_queries: item_queries.clone(),
+        };
+        let ticker = cx.new(|_| Ticker);
+        let (panel, cx) = cx.add_window_view(|window, cx| {
+            CustomPanel::with_source(WeakEntity::new_invalid(), Box::new(source), window, cx)
+        });
+        panel.update(cx, |panel, cx| panel.subscribe_debounced(&ticker, cx));
+        cx.run_until_parked();
+
+        // 100 毫秒内连续发出 100 个事件，每次渲染都会向数据源查询一次条目数量
+        let renders_before = item_queries.get();
+        for _ in 0..100 {
+            ticker.update(cx, |_, cx| cx.emit(()));
+            cx.executor().advance_clock(Duration::from_millis(1));
+            cx.run_until_parked();
+        }
+        cx.executor().advance_clock(NOTIFY_DEBOUNCE);
+        cx.run_until_parked();
+
+        let renders = item_queries.get() - renders_before;
+        assert!(renders > 0, "events should still trigger a render");
+        assert!(renders <= 3, "expected coalesced renders, got {renders}");
+    }
+
+    #[gpui::test]
+    fn test_language_change_rerenders_panel(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let item_queries = Rc::new(Cell::new(0));
+        let source = RefreshingSource {
+            item_queries: item_queries.clone(),
+        };
+        let (_panel, cx) = cx.add_window_view(|window, cx| {
+            CustomPanel::with_source(WeakEntity::new_invalid(), Box::new(source), window, cx)
+        });
+        cx.run_until_parked();
+
+        let renders_before = item_queries.get();
+        i18n::set_language(i18n::get_language());
+        cx.run_until_parked();
+        assert!(
+            item_queries.get() > renders_before,
+            "language change should re-render"
+        );
+    }
+
+    #[gpui::test]
+    fn test_separators_render_as_rules_and_are_skipped(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let (panel, cx) = cx.add_window_view(|window, cx| {
+            CustomPanel::with_source(
+                WeakEntity::new_invalid(),
+                Box::new(SeparatorSource),
+                window,
+                cx,
+            )
+        });
+        cx.update(|window, cx| panel.focus_handle(cx).focus(window, cx));
+        cx.run_until_parked();
+
+        assert!(cx.debug_bounds("custom-panel-separator-1").is_some());
+        assert!(cx.debug_bounds("custom-panel-item-1").is_none());
+
+        let selected =
+            |cx: &mut VisualTestContext| panel.read_with(cx, |panel, _| panel.selected_index());
+        cx.dispatch_action(menu::SelectNext);
+        assert_eq!(selected(cx), Some(0));
+        cx.dispatch_action(menu::SelectNext);
+        assert_eq!(selected(cx), Some(2));
+        cx.dispatch_action(menu::SelectPrevious);
+        assert_eq!(selected(cx), Some(0));
+        cx.dispatch_action(menu::SelectPrevious);
+        assert_eq!(selected(cx), Some(2));
+    }
+
+    #[gpui::test]
+    fn test_pinned_items_sort_to_the_top(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let (panel, cx) = cx.add_window_view(|window, cx| {
+            CustomPanel::with_source(
+                WeakEntity::new_invalid(),
+                Box::new(LanguagesSource),
+                window,
+                cx,
+            )
+        });
+        cx.update(|window, cx| panel.focus_handle(cx).focus(window, cx));
+        cx.run_until_parked();
+        let top =
+            |selector: &str, cx: &mut VisualTestContext| cx.debug_bounds(selector).unwrap().top();
+        assert!(top("custom-panel-item-0", cx) < top("custom-panel-item-2", cx));
+        assert!(cx.debug_bounds("custom-panel-pin-2").is_none());
+
+        // 置顶最后一个条目后，它排在未置顶的条目前面并显示图钉
+        panel.update(cx, |panel, cx| panel.select(2, cx));
+        cx.dispatch_action(TogglePin);
+        cx.run_until_parked();
+        assert!(panel.read_with(cx, |panel, cx| panel.is_pinned(2, cx)));
+        assert!(cx.debug_bounds("custom-panel-pin-2").is_some());
+        assert!(cx.debug_bounds("custom-panel-pin-0").is_none());
+        assert!(top("custom-panel-item-2", cx) < top("custom-panel-item-0", cx));
+        assert!(top("custom-panel-item-0", cx) < top("custom-panel-item-1", cx));
+
+        // 键盘导航按显示的顺序移动
+        cx.dispatch_action(menu::SelectNext);
+        assert_eq!(
+            panel.read_with(cx, |panel, _| panel.selected_index()),
+            Some(0)
+        );
+
+        // 筛选只隐藏不匹配的条目，置顶的条目仍然显示图钉
+        let search_editor = panel.read_with(cx, |panel, _| panel.search_editor.clone());
+        cx.update(|window, cx| {
+            search_editor.update(cx, |editor, cx| editor.set_text("english", window, cx));
+        });
+        cx.run_until_parked();
+        assert!(cx.debug_bounds("custom-panel-pin-2").is_some());
+        assert!(cx.debug_bounds("custom-panel-item-0").is_none());
+        assert!(cx.debug_bounds("custom-panel-item-1").is_none());
+
+        // 取消置顶后恢复原来的顺序
+        cx.update(|window, cx| {
+            search_editor.update(cx, |editor, cx| editor.set_text("", window, cx));
+        });
+        panel.update(cx, |panel, cx| panel.toggle_pin(2, cx));
+        cx.run_until_parked();
+        assert!(!panel.read_with(cx, |panel, cx| panel.is_pinned(2, cx)));
+        assert!(cx.debug_bounds("custom-panel-pin-2").is_none());
+        assert!(top("custom-panel-item-0", cx) < top("custom-panel-item-2", cx));
+    }
+
+    #[gpui::test]
+    fn test_multi_selection(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let labels = Rc::new(RefCell::new(vec!["a", "b", "c", "d", "e"]));
+        let source = RemovableSource {
+            labels: labels.clone(),
+        };
+        let (panel, cx) = cx.add_window_view(|window, cx| {
+            CustomPanel::with_source(WeakEntity::new_invalid(), Box::new(source), window, cx)
+        });
+        cx.update(|window, cx| panel.focus_handle(cx).focus(window, cx));
+        cx.run_until_parked();
+        let click = |index: usize, modifiers: Modifiers, cx: &mut VisualTestContext| {
+            let bounds = cx
+                .debug_bounds(&format!("custom-panel-item-{index}"))
+                .unwrap();
+            cx.simulate_click(bounds.center(), modifiers);
+        };
+        let selection = |cx: &mut VisualTestContext| {
+            let mut selection = panel.read_with(cx, |panel, _| {
+                panel.selection().iter().copied().collect::<Vec<_>>()
+            });
+            selection.sort_unstable();
+            selection
+        };
+
+        // Shift+点击选中从上次点击的条目到目标条目之间的范围
+        click(1, Modifiers::none(), cx);
+        assert_eq!(selection(cx), [1]);
+        click(3, Modifiers::shift(), cx);
+        assert_eq!(selection(cx), [1, 2, 3]);
+
+        // Ctrl/Cmd+点击切换单个条目，其他选中的条目不变
+        click(2, Modifiers::secondary_key(), cx);
+        assert_eq!(selection(cx), [1, 3]);
+        click(4, Modifiers::secondary_key(), cx);
+        assert_eq!(selection(cx), [1, 3, 4]);
+
+        // 复制所有选中条目的文本，按显示顺序每行一个
+        cx.dispatch_action(Copy);
+        assert_eq!(
+            cx.read_from_clipboard().and_then(|item| item.text()),
+            Some("b\nd\ne".to_string())
+        );
+
+        // 普通点击只选中一个条目，Shift+方向键从它开始扩展
+        click(0, Modifiers::none(), cx);
+        assert_eq!(selection(cx), [0]);
+        cx.dispatch_action(ExtendSelectionNext);
+        cx.dispatch_action(ExtendSelectionNext);
+        assert_eq!(selection(cx), [0, 1, 2]);
+        cx.dispatch_action(ExtendSelectionPrevious);
+        assert_eq!(selection(cx), [0, 1]);
+
+        // 移除作用于所有选中的条目，置顶按条目标识记录，移除前面的条目后仍然指向同一个条目
+        panel.update(cx, |panel, cx| panel.toggle_pin(3, cx));
+        cx.dispatch_action(Remove);
+        cx.run_until_parked();
+        assert_eq!(*labels.borrow(), ["c", "d", "e"]);
+        assert_eq!(selection(cx), Vec::<usize>::new());
+        assert!(panel.read_with(cx, |panel, cx| panel.is_pinned(1, cx)));
+        assert!(!panel.read_with(cx, |panel, cx| panel.is_pinned(0, cx)));
+
+        // 数据源调整顺序后置顶的仍是同一个条目
+        labels.borrow_mut().reverse();
+        panel.update(cx, |panel, cx| panel.refresh(cx));
+        cx.run_until_parked();
+        assert_eq!(*labels.borrow(), ["e", "d", "c"]);
+        assert!(panel.read_with(cx, |panel, cx| panel.is_pinned(1, cx)));
+        labels.borrow_mut().insert(0, "z");
+        panel.update(cx, |panel, cx| panel.refresh(cx));
+        cx.run_until_parked();
+        assert!(panel.read_with(cx, |panel, cx| panel.is_pinned(2, cx)));
+        assert!(!panel.read_with(cx, |panel, cx| panel.is_pinned(1, cx)));
+        assert!(cx.debug_bounds("custom-panel-pin-2").is_some());
+    }
+
+    #[gpui::test]
+    async fn test_saved_filters(cx: &mut TestAppContext) {
+        init_test(cx);
+        let fs = FakeFs::new(cx.executor());
+        fs.create_dir(paths::settings_file().parent().unwrap())
+            .await
+            .unwrap();
+        fs.insert_file(paths::settings_file(), b"{}".to_vec()).await;
+        cx.update(|cx| <dyn Fs>::set_global(fs.clone(), cx));
+
+        let (panel, cx) = cx.add_window_view(|window, cx| {
+            CustomPanel::with_source(
+                WeakEntity::new_invalid(),
+                Box::new(LanguagesSource),
+                window,
+                cx,
+            )
+        });
+        cx.update(|window, cx| panel.focus_handle(cx).focus(window, cx));
+        cx.run_until_parked();
+        let search_editor = panel.read_with(cx, |panel, _| panel.search_editor.clone());
+        let name_editor = |cx: &mut VisualTestContext| {
+            panel.read_with(cx, |panel, _| panel.filter_name_editor.clone())
+        };
+
+        // 没有筛选文本时没有可保存的内容
+        cx.dispatch_action(SaveFilter);
+        assert!(name_editor(cx).is_none());
+
+        // 保存时默认以筛选文本作为名称，回车后写入设置文件
+        cx.update(|window, cx| {
+            search_editor.update(cx, |editor, cx| editor.set_text("日本", window, cx));
+        });
+        cx.run_until_parked();
+        cx.dispatch_action(SaveFilter);
+        let editor = name_editor(cx).unwrap();
+        assert_eq!(editor.read_with(cx, |editor, cx| editor.text(cx)), "日本");
+        cx.update(|window, cx| {
+            editor.update(cx, |editor, cx| editor.set_text("Japanese", window, cx));
+        });
+        cx.dispatch_action(menu::Confirm);
+        cx.run_until_parked();
+        assert!(name_editor(cx).is_none());
+        let settings_text = fs.load(paths::settings_file()).await.unwrap();
+        cx.update(|_, cx| {
+            SettingsStore::update_global(cx, |store, cx| {
+                store
+                    .set_user_settings(&settings_text, cx)
+                    .result()
+                    .unwrap();
+            });
+        });
+        assert_eq!(
+            cx.update(|_, cx| CustomPanelSettings::get_global(cx).saved_filters.clone()),
+            BTreeMap::from_iter([("Japanese".to_string(), "日本".to_string())])
+        );
+
+        // 应用保存的筛选会替换搜索框的内容
+        cx.update(|window, cx| {
+            search_editor.update(cx, |editor, cx| editor.set_text("", window, cx));
+        });
+        cx.run_until_parked();
+        assert!(cx.debug_bounds("custom-panel-item-0").is_some());
+        let applied = cx.update(|window, cx| {
+            panel.update(cx, |panel, cx| {
+                panel.apply_saved_filter("Japanese", window, cx)
+            })
+        });
+        assert!(applied);
+        cx.run_until_parked();
+        assert_eq!(
+            panel.read_with(cx, |panel, _| panel.query().to_string()),
+            "日本"
+        );
+        assert!(cx.debug_bounds("custom-panel-item-1").is_some());
+        assert!(cx.debug_bounds("custom-panel-item-0").is_none());
+        assert!(cx.debug_bounds("custom-panel-item-2").is_none());
+
+        // 不存在的筛选不改变当前的筛选
+        let applied = cx.update(|window, cx| {
+            panel.update(cx, |panel, cx| {
+                panel.apply_saved_filter("missing", window, cx)
+            })
+        });
+        assert!(!applied);
+        assert_eq!(
+            panel.read_with(cx, |panel, _| panel.query().to_string()),
+            "日本"
+        );
+    }
+
+    #[gpui::test]
+    async fn test_set_position_moves_panel_between_docks(cx: &mut TestAppContext) {
+        init_test(cx);
+        let fs = FakeFs::new(cx.executor());
+        fs.create_dir(paths::settings_file().parent().unwrap())
+            .await
+            .unwrap();
+        fs.insert_file(paths::settings_file(), b"{}".to_vec()).await;
+        cx.update(|cx| <dyn Fs>::set_global(fs.clone(), cx));
+        let project = Project::test(fs.clone(), [], cx).await;
+        let workspace = cx.add_window(|window, cx| Workspace::test_new(project, window, cx));
+        let cx = &mut VisualTestContext::from_window(*workspace, cx);
+        let panel = workspace
+            .update(cx, |workspace, window, cx| {
+                let panel = CustomPanel::new(workspace, window, cx);
+                workspace.add_panel(panel.clone(), window, cx);
+                panel
+            })
+            .unwrap();
+        let docked_at = |cx: &mut VisualTestContext| {
+            workspace
+                .read_with(cx, |workspace, cx| {
+                    [
+                        DockPosition::Left,
+                        DockPosition::Bottom,
+                        DockPosition::Right,
+                    ]
+                    .into_iter()
+                    .filter(|&position| {
+                        workspace
+                            .dock_at_position(position)
+                            .read(cx)
+                            .panel::<CustomPanel>()
+                            .is_some()
+                    })
+                    .collect::<Vec<_>>()
+                })
+                .unwrap()
+        };
+        assert_eq!(docked_at(cx), [DockPosition::Left]);
+
+        for expected in [
+            DockPosition::Right,
+            DockPosition::Bottom,
+            DockPosition::Left,
+        ] {
+            cx.update(|window, cx| {
+                panel.update(cx, |panel, cx| panel.set_position(expected, window, cx))
+            });
+            cx.run_until_parked();
+            // 测试中没有监听设置文件，手动载入写入的设置
+            let settings_text = fs.load(paths::settings_file()).await.unwrap();
+            cx.update(|_, cx| {
+                SettingsStore::update_global(cx, |store, cx| {
+                    store
+                        .set_user_settings(&settings_text, cx)
+                        .result()
+                        .unwrap();
+                });
+            });
+            cx.run_until_parked();
+            assert_eq!(docked_at(cx), [expected]);
+            assert_eq!(
+                cx.update(|window, cx| panel.read(cx).position(window, cx)),
+                expected
+            );
+        }
+    }
+
+    #[gpui::test]
+    fn test_bottom_position_uses_horizontal_layout(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let activated = Rc::new(RefCell::new(Vec::new()));
+        let source = MockSource {
+            activated: activated.clone(),
+        };
+        let (panel, cx) = cx.add_window_view(|window, cx| {
+            CustomPanel::with_source(WeakEntity::new_invalid(), Box::new(source), window, cx)
+        });
+        cx.run_until_parked();
+        assert!(cx.debug_bounds("custom-panel-items-vertical").is_some());
+        let first = cx.debug_bounds("custom-panel-item-0").unwrap();
+        let second = cx.debug_bounds("custom-panel-item-1").unwrap();
+        assert!(second.origin.y > first.origin.y);
+
+        assert!(panel.read_with(cx, |panel, _| panel.position_is_valid(DockPosition::Bottom)));
+        dock_at(DockPosition::Bottom, cx);
+        cx.run_until_parked();
+        assert_eq!(
+            cx.update(|window, cx| panel.read(cx).position(window, cx)),
+            DockPosition::Bottom
+        );
+        assert!(cx.debug_bounds("custom-panel-items-vertical").is_none());
+        assert!(cx.debug_bounds("custom-panel-items-horizontal").is_some());
+        let first = cx.debug_bounds("custom-panel-item-0").unwrap();
+        let second = cx.debug_bounds("custom-panel-item-1").unwrap();
+        assert_eq!(second.origin.y, first.origin.y);
+        assert!(second.origin.x > first.origin.x);
+    }
+
+    #[gpui::test]
+    fn test_overflowing_items_scroll_below_the_header(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        struct ManySource;
+
+        impl CustomPanelSource for ManySource {
+            fn item_count(&self, _cx: &App) -> usize {
+                2000
+            }
+
+            fn item(&self, index: usize, _cx: &App) -> Option<PanelItem> {
+                (index < 2000).then(|| PanelItem::new(format!("item {index}")))
+            }
+
+            fn on_activate(&self, _index: usize, _cx: &mut App) {}
+        }
+
+        let (panel, cx) = cx.add_window_view(|window, cx| {
+            CustomPanel::with_source(WeakEntity::new_invalid(), Box::new(ManySource), window, cx)
+        });
+        dock_at(DockPosition::Bottom, cx);
+        cx.run_until_parked();
+
+        // 标题栏始终可见，条目区域被限制在窗口内，超出的部分滚动显示
+        let viewport = cx.update(|window, _| window.viewport_size());
+        let header = cx.debug_bounds("custom-panel-header").unwrap();
+        let items = cx.debug_bounds("custom-panel-items-horizontal").unwrap();
+        assert!(header.origin.y < items.origin.y);
+        assert!(items.bottom() <= viewport.height);
+        let last = cx.debug_bounds("custom-panel-item-1999").unwrap();
+        assert!(last.bottom() > items.bottom());
+    }
+
+    #[gpui::test]
+    fn test_show_translation_keys(cx: &mut TestAppContext) {
+        init_test(cx);
+        cx.update(|cx| {
+            release_channel::init_test(semver::Version::new(0, 0, 0), ReleaseChannel::Dev, cx)
+        });
+
+        let (panel, cx) = cx.add_window_view(|window, cx| {
+            CustomPanel::with_source(
+                WeakEntity::new_invalid(),
+                Box::new(DefaultSource),
+                window,
+                cx,
+            )
+        });
+        cx.update(|window, cx| panel.focus_handle(cx).focus(window, cx));
+        cx.run_until_parked();
+        assert!(cx.debug_bounds("custom-panel-key-0").is_none());
+
+        cx.dispatch_action(ToggleTranslationKeys);
+        cx.run_until_parked();
+        let label = cx.debug_bounds("custom-panel-item-0").unwrap();
+        let key = cx.debug_bounds("custom-panel-key-0").unwrap();
+        assert!(label.contains(&key.center()));
+
+        cx.dispatch_action(ToggleTranslationKeys);
+        cx.run_until_parked();
+        assert!(cx.debug_bounds("custom-panel-key-0").is_none());
+    }
+
+    #[gpui::test]
+    fn test_keyboard_resizing(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let (panel, cx) = cx.add_window_view(|window, cx| {
+            CustomPanel::with_source(
+                WeakEntity::new_invalid(),
+                Box::new(DefaultSource),
+                window,
+                cx,
+            )
+        });
+        cx.update(|window, cx| panel.focus_handle(cx).focus(window, cx));
+        cx.run_until_parked();
+        let width =
+            |cx: &mut VisualTestContext| cx.update(|window, cx| panel.read(cx).size(window, cx));
+        assert_eq!(width(cx), DEFAULT_WIDTH);
+
+        cx.dispatch_action(IncreaseWidth);
+        assert_eq!(width(cx), DEFAULT_WIDTH + WIDTH_STEP);
+        cx.dispatch_action(DecreaseWidth);
+        cx.dispatch_action(DecreaseWidth);
+        assert_eq!(width(cx), DEFAULT_WIDTH - WIDTH_STEP);
+        assert!(panel.read_with(cx, |panel, _| panel.width_announcement.is_some()));
+
+        // 宽度不会超出允许的范围
+        cx.update(|window, cx| {
+            panel.update(cx, |panel, cx| {
+                panel.set_size(Some(MAX_WIDTH - px(5.)), window, cx)
+            })
+        });
+        cx.dispatch_action(IncreaseWidth);
+        assert_eq!(width(cx), MAX_WIDTH);
+        cx.dispatch_action(IncreaseWidth);
+        assert_eq!(width(cx), MAX_WIDTH);
+
+        cx.update(|window, cx| {
+            panel.update(cx, |panel, cx| {
+                panel.set_size(Some(MIN_WIDTH + px(5.)), window, cx)
+            })
+        });
+        cx.dispatch_action(DecreaseWidth);
+        assert_eq!(width(cx), MIN_WIDTH);
+        cx.dispatch_action(DecreaseWidth);
+        assert_eq!(width(cx), MIN_WIDTH);
+    }
+
+    #[gpui::test]
+    fn test_active_file_follows_active_editor(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let (panel, cx) = cx.add_window_view(|window, cx| {
+            CustomPanel::with_source(
+                WeakEntity::new_invalid(),
+                Box::new(DefaultSource),
+                window,
+                cx,
+            )
+        });
+        cx.run_until_parked();
+        // 数据源自定义的面板不显示活动文件
+        assert!(cx.debug_bounds("custom-panel-active-file").is_none());
+
+        panel.update(cx, |panel, cx| {
+            panel.tracks_active_file = true;
+            cx.notify();
+        });
+        cx.run_until_parked();
+        assert!(cx.debug_bounds("custom-panel-active-file").is_some());
+        assert_eq!(
+            panel.read_with(cx, |panel, _| panel.active_file.clone()),
+            None
+        );
+
+        let editor = cx.update(|window, cx| {
+            cx.new(|cx| {
+                let mut editor = Editor::single_line(window, cx);
+                editor.set_text("notes", window, cx);
+                editor
+            })
+        });
+        panel.update(cx, |panel, cx| panel.set_active_editor(Some(editor), cx));
+        assert_eq!(
+            panel.read_with(cx, |panel, _| panel.active_file.clone()),
+            Some(ActiveFile {
+                name: "notes".into(),
+                language: None,
+            })
+        );
+
+        panel.update(cx, |panel, cx| panel.set_active_editor(None, cx));
+        assert_eq!(
+            panel.read_with(cx, |panel, _| panel.active_file.clone()),
+            None
+        );
+    }
+
+    #[gpui::test]
+    fn test_default_width_comes_from_settings(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let (panel, cx) = cx.add_window_view(|window, cx| {
+            CustomPanel::with_source(
+                WeakEntity::new_invalid(),
+                Box::new(DefaultSource),
+                window,
+                cx,
             )
+        });
+        let width =
+            |cx: &mut VisualTestContext| cx.update(|window, cx| panel.read(cx).size(window, cx));
+        let set_default_width = |default_width: f32, cx: &mut VisualTestContext| {
+            cx.update(|_, cx| {
+                SettingsStore::update_global(cx, |store, cx| {
+                    store.update_user_settings(cx, |settings| {
+                        settings.custom_panel.get_or_insert_default().default_width =
+                            Some(default_width);
+                    });
+                });
+            });
+        };
+        assert_eq!(width(cx), DEFAULT_WIDTH);
+
+        set_default_width(360., cx);
+        assert_eq!(width(cx), px(360.));
+        // 无效的设置值回退到 280px
+        set_default_width(0., cx);
+        assert_eq!(width(cx), DEFAULT_WIDTH);
+
+        // 调整过的宽度优先于设置
+        set_default_width(360., cx);
+        cx.update(|window, cx| {
+            panel.update(cx, |panel, cx| panel.set_size(Some(px(300.)), window, cx))
+        });
+        assert_eq!(width(cx), px(300.));
     }
-}
\ No newline at end of file
+
+    #[gpui::test]
+    fn test_search_filters_only_on_committed_ime_text(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let (panel, cx) = cx.add_window_view(|window, cx| {
+            CustomPanel::with_source(
+                WeakEntity::new_invalid(),
+                Box::new(LanguagesSource),
+                window,
+                cx,
+            )
+        });
+        let search_editor = panel.read_with(cx, |panel, _| panel.search_editor.clone());
+        let visible_items = |cx: &mut VisualTestContext| {
+            cx.run_until_parked();
+            [
+                "custom-panel-item-0",
+                "custom-panel-item-1",
+                "custom-panel-item-2",
+            ]
+            .into_iter()
+            .enumerate()
+            .filter(|(_, selector)| cx.debug_bounds(selector).is_some())
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>()
+        };
+        assert_eq!(visible_items(cx), [0, 1, 2]);
+
+        // 输入法组字期间，搜索框中是未确认的拼音，不应按它筛选
+        for composition in ["z", "zh", "zhong"] {
+            cx.update(|window, cx| {
+                search_editor.update(cx, |editor, cx| {
+                    editor.replace_and_mark_text_in_range(None, composition, None, window, cx)
+                });
+            });
+            assert_eq!(
+                panel.read_with(cx, |panel, _| panel.query().to_string()),
+                ""
+            );
+            assert_eq!(visible_items(cx), [0, 1, 2]);
+        }
+
+        // 确认候选词后才按提交的文本筛选
+        cx.update(|window, cx| {
+            search_editor.update(cx, |editor, cx| {
+                editor.replace_text_in_range(None, "中", window, cx)
+            });
+        });
+        assert_eq!(
+            panel.read_with(cx, |panel, _| panel.query().to_string()),
+            "中"
+        );
+        assert_eq!(visible_items(cx), [0]);
+
+        // 直接输入的文本（没有组字）立即生效
+        cx.update(|window, cx| {
+            search_editor.update(cx, |editor, cx| editor.set_text("eng", window, cx));
+        });
+        assert_eq!(visible_items(cx), [2]);
+    }
+
+    #[gpui::test]
+    fn test_large_lists_render_only_visible_rows(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let item_queries = Rc::new(Cell::new(0));
+        let source = LargeSource {
+            count: 10_000,
+            item_queries: item_queries.clone(),
+        };
+        let (panel, cx) = cx.add_window_view(|window, cx| {
+            CustomPanel::with_source(WeakEntity::new_invalid(), Box::new(source), window, cx)
+        });
+        cx.update(|window, cx| panel.focus_handle(cx).focus(window, cx));
+        cx.run_until_parked();
+
+        // 只为可见区域内的行构建元素，条目按索引获取
+        let queries = item_queries.get();
+        assert!(queries > 0);
+        assert!(
+            queries < 200,
+            "expected only visible rows to be built, got {queries}"
+        );
+        assert!(cx.debug_bounds("custom-panel-item-0").is_some());
+        assert!(cx.debug_bounds("custom-panel-item-5000").is_none());
+        assert!(cx.debug_bounds("custom-panel-item-9999").is_none());
+
+        // 键盘导航仍然可用，选中项会滚动到可见区域
+        item_queries.set(0);
+        cx.dispatch_action(menu::SelectPrevious);
+        cx.run_until_parked();
+        assert_eq!(
+            panel.read_with(cx, |panel, _| panel.selected_index()),
+            Some(9999)
+        );
+        assert!(cx.debug_bounds("custom-panel-item-9999").is_some());
+        assert!(cx.debug_bounds("custom-panel-item-0").is_none());
+        let queries = item_queries.get();
+        assert!(
+            queries < 200,
+            "expected only visible rows to be built, got {queries}"
+        );
+    }
+
+    #[gpui::test]
+    fn test_filtering_large_lists_queries_items_by_index(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let item_queries = Rc::new(Cell::new(0));
+        let source = LargeSource {
+            count: 10_000,
+            item_queries: item_queries.clone(),
+        };
+        let (panel, cx) = cx.add_window_view(|window, cx| {
+            CustomPanel::with_source(WeakEntity::new_invalid(), Box::new(source), window, cx)
+        });
+        let search_editor = panel.read_with(cx, |panel, _| panel.search_editor.clone());
+        cx.update(|window, cx| {
+            search_editor.update(cx, |editor, cx| editor.set_text("item 999", window, cx));
+        });
+        cx.run_until_parked();
+
+        // 筛选按索引逐个获取条目，不会调用 items
+        assert!(cx.debug_bounds("custom-panel-item-999").is_some());
+        assert!(cx.debug_bounds("custom-panel-item-9990").is_some());
+        assert!(cx.debug_bounds("custom-panel-item-0").is_none());
+        assert!(cx.debug_bounds("custom-panel-item-1000").is_none());
+
+        // 筛选文本不变时重复使用筛选结果，重新渲染只获取可见的行
+        item_queries.set(0);
+        panel.update(cx, |_, cx| cx.notify());
+        cx.run_until_parked();
+        let queries = item_queries.get();
+        assert!(queries > 0);
+        assert!(
+            queries < 200,
+            "expected the filter result to be reused, got {queries}"
+        );
+
+        // 键盘导航按筛选后的行移动，同样不需要重新筛选
+        cx.update(|window, cx| panel.focus_handle(cx).focus(window, cx));
+        let selected =
+            |cx: &mut VisualTestContext| panel.read_with(cx, |panel, _| panel.selected_index());
+        cx.dispatch_action(menu::SelectNext);
+        assert_eq!(selected(cx), Some(999));
+        cx.dispatch_action(menu::SelectNext);
+        assert_eq!(selected(cx), Some(9990));
+        cx.dispatch_action(menu::SelectPrevious);
+        cx.dispatch_action(menu::SelectPrevious);
+        assert_eq!(selected(cx), Some(9999));
+        let queries = item_queries.get() - queries;
+        assert!(
+            queries < 200,
+            "expected the filter result to be reused, got {queries}"
+        );
+    }
+}
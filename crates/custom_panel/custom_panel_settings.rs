@@ -0,0 +1,43 @@
+use std::collections::BTreeMap;
+
+use gpui::{Pixels, px};
+use settings::{RegisterSetting, Settings};
+
+use crate::DEFAULT_WIDTH;
+
+/// 自定义面板的设置，对应设置文件中的 `custom_panel`
+#[derive(Debug, Clone, PartialEq, RegisterSetting)]
+pub struct CustomPanelSettings {
+    /// 用键盘移动选中项时是否立即激活该条目，关闭时需要按回车激活
+    pub focus_follows_selection: bool,
+    /// 面板停靠的位置
+    pub dock: settings::DockPosition,
+    /// 面板在 workspace 中没有保存过宽度时使用的宽度，不是正数时使用 280px
+    pub default_width: Pixels,
+    /// 状态栏中面板图标的名称，无法识别时使用星形图标
+    pub icon: String,
+    /// 标题栏中的标题使用的翻译键
+    pub title_key: String,
+    /// 问候语条目使用的翻译键
+    pub hello_key: String,
+    /// 保存的筛选，按名称排序，值为筛选文本
+    pub saved_filters: BTreeMap<String, String>,
+}
+
+impl Settings for CustomPanelSettings {
+    fn from_settings(content: &settings::SettingsContent) -> Self {
+        let panel = content.custom_panel.as_ref().unwrap();
+        Self {
+            focus_follows_selection: panel.focus_follows_selection.unwrap(),
+            dock: panel.dock.unwrap(),
+            default_width: panel
+                .default_width
+                .filter(|width| *width > 0.)
+                .map_or(DEFAULT_WIDTH, px),
+            icon: panel.icon.clone().unwrap(),
+            title_key: panel.title_key.clone().unwrap(),
+            hello_key: panel.hello_key.clone().unwrap(),
+            saved_filters: panel.saved_filters.clone().unwrap(),
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use gpui::Pixels;
+use serde::{Deserialize, Serialize};
+
+/// 当前的持久化格式版本，字段含义发生变化时递增，并在 [`CustomPanelState::migrate`] 中处理旧版本
+pub(crate) const CURRENT_VERSION: u32 = 2;
+
+/// 持久化保存的面板状态
+///
+/// 所有字段都有默认值，旧版本写入的状态缺少的字段会使用默认值补齐。
+/// 停靠位置保存在设置的 `custom_panel.dock` 中，旧版本写入的 `position` 字段会被忽略。
+/// 版本 2 起置顶的条目按标识保存在 `pinned_items` 中，版本 1 按索引保存的 `pinned` 字段会被忽略。
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct CustomPanelState {
+    /// 写入时的格式版本，缺失时为 0，表示加入版本号之前写入的状态
+    #[serde(default)]
+    pub version: u32,
+    /// 面板宽度，None 表示使用默认宽度
+    #[serde(default)]
+    pub width: Option<Pixels>,
+    /// 停靠在底部时的面板高度，None 表示使用默认高度
+    #[serde(default)]
+    pub height: Option<Pixels>,
+    /// 置顶的条目标识（见 [`crate::PanelItem::id`]），按字典序保存
+    #[serde(default, rename = "pinned_items")]
+    pub pinned: Vec<String>,
+}
+
+impl Default for CustomPanelState {
+    fn default() -> Self {
+        Self {
+            version: CURRENT_VERSION,
+            width: None,
+            height: None,
+            pinned: Vec::new(),
+        }
+    }
+}
+
+impl CustomPanelState {
+    /// 解析存储的状态并迁移到当前版本
+    ///
+    /// 无法解析或来自更新版本的状态会被丢弃并返回 `None`，调用方应使用默认状态，
+    /// 这样降级后不会因为看不懂新格式而得到错误的布局。
+    pub fn deserialize(json: &str) -> Option<Self> {
+        let state = match serde_json::from_str::<Self>(json) {
+            Ok(state) => state,
+            Err(error) => {
+                log::warn!("discarding unreadable custom panel state: {error}");
+                return None;
+            }
+        };
+        if state.version > CURRENT_VERSION {
+            log::warn!(
+                "discarding custom panel state from newer version {} (current is {})",
+                state.version,
+                CURRENT_VERSION
+            );
+            return None;
+        }
+        Some(state.migrate())
+    }
+
+    /// 将旧版本的状态转换为当前版本
+    fn migrate(mut self) -> Self {
+        // 版本 0 与版本 1 的字段含义相同，版本 1 按索引保存的置顶条目在解析时已被忽略，只需补上版本号
+        self.version = CURRENT_VERSION;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gpui::px;
+
+    #[test]
+    fn test_older_state_is_filled_with_defaults() {
+        // 加入版本号之前写入的状态
+        let state = CustomPanelState::deserialize(r#"{"width": 320.0}"#).unwrap();
+        assert_eq!(
+            state,
+            CustomPanelState {
+                version: CURRENT_VERSION,
+                width: Some(px(320.)),
+                ..Default::default()
+            }
+        );
+
+        let state = CustomPanelState::deserialize(r#"{"version": 2}"#).unwrap();
+        assert_eq!(state, CustomPanelState::default());
+
+        // 按索引保存的置顶条目在数据源变化后会指向错误的条目，直接丢弃
+        let state = CustomPanelState::deserialize(r#"{"version": 1, "pinned": [0, 3]}"#).unwrap();
+        assert_eq!(state, CustomPanelState::default());
+
+        // 停靠位置改为保存在设置中
+        let state =
+            CustomPanelState::deserialize(r#"{"version": 1, "position": "bottom"}"#).unwrap();
+        assert_eq!(state, CustomPanelState::default());
+    }
+
+    #[test]
+    fn test_newer_or_corrupted_state_is_discarded() {
+        assert_eq!(
+            CustomPanelState::deserialize(r#"{"version": 99, "width": 320.0}"#),
+            None
+        );
+        assert_eq!(CustomPanelState::deserialize("not json"), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let state = CustomPanelState {
+            width: Some(px(240.)),
+            height: Some(px(180.)),
+            pinned: vec!["custom_panel.hello".into(), "notes".into()],
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(CustomPanelState::deserialize(&json), Some(state));
+    }
+}
@@ -0,0 +1,158 @@
+use gpui::{App, SharedString, Task};
+use i18n::t_shared;
+use settings::Settings;
+
+use crate::CustomPanelSettings;
+
+/// 面板中显示的一项内容
+#[derive(Debug, Clone, PartialEq)]
+pub enum PanelItem {
+    /// 可以选中和激活的条目
+    Entry {
+        // 显示的文本
+        label: SharedString,
+        // 鼠标悬停时显示的提示，None 表示没有提示
+        tooltip: Option<PanelTooltip>,
+        // 文本对应的翻译键，开启调试显示时显示在文本旁边
+        key: Option<SharedString>,
+    },
+    /// 分隔线，只用于给条目分组，不能被选中或激活
+    Separator,
+}
+
+impl PanelItem {
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self::Entry {
+            label: label.into(),
+            tooltip: None,
+            key: None,
+        }
+    }
+
+    /// 使用翻译键创建条目，文本按当前语言翻译
+    pub fn translated(key: impl Into<SharedString>) -> Self {
+        let key = key.into();
+        Self::Entry {
+            label: t_shared(&key),
+            tooltip: None,
+            key: Some(key),
+        }
+    }
+
+    /// 为条目设置悬停提示，分隔线没有提示
+    pub fn with_tooltip(mut self, tooltip: PanelTooltip) -> Self {
+        if let Self::Entry { tooltip: slot, .. } = &mut self {
+            *slot = Some(tooltip);
+        }
+        self
+    }
+
+    /// 条目的标识，有翻译键时为翻译键，否则为文本，分隔线没有标识
+    ///
+    /// 置顶等需要持久化的状态按标识记录，数据源调整条目顺序或增删条目后仍然指向同一个条目，
+    /// 使用翻译键也不会因为切换语言而改变。
+    pub fn id(&self) -> Option<SharedString> {
+        match self {
+            Self::Entry { label, key, .. } => Some(key.clone().unwrap_or_else(|| label.clone())),
+            Self::Separator => None,
+        }
+    }
+
+    /// 是否可以通过键盘选中
+    pub fn is_selectable(&self) -> bool {
+        matches!(self, Self::Entry { .. })
+    }
+}
+
+/// 条目的悬停提示
+#[derive(Debug, Clone, PartialEq)]
+pub enum PanelTooltip {
+    /// 翻译键，显示时按当前语言翻译
+    Key(&'static str),
+    /// 直接显示的文本
+    Text(SharedString),
+}
+
+impl PanelTooltip {
+    /// 解析出实际显示的文本
+    ///
+    /// 只在提示真正显示时调用，这样切换语言后提示也会跟着变化
+    pub fn resolve(&self) -> SharedString {
+        match self {
+            PanelTooltip::Key(key) => t_shared(key),
+            PanelTooltip::Text(text) => text.clone(),
+        }
+    }
+}
+
+/// 自定义面板的数据源
+///
+/// 面板本身只负责布局，显示哪些条目、点击后做什么都由数据源决定，
+/// 其他模块（诊断、任务等）可以实现该 trait 提供自己的内容。
+pub trait CustomPanelSource: 'static {
+    /// 面板标题栏中显示的标题，默认使用通用的面板标题
+    fn title(&self, _cx: &App) -> SharedString {
+        t_shared("custom_panel.title")
+    }
+
+    /// 条目数量
+    fn item_count(&self, cx: &App) -> usize;
+
+    /// 返回第 `index` 个条目，索引超出范围时返回 None
+    ///
+    /// 面板筛选时按索引逐个调用，渲染时只为滚动到可见区域的行调用，
+    /// 所以实现应直接按索引取出条目，而不是每次都构造全部条目。
+    fn item(&self, index: usize, cx: &App) -> Option<PanelItem>;
+
+    /// 返回当前的全部条目
+    ///
+    /// 面板自身不会调用，只是为了方便一次性获取全部条目。
+    fn items(&self, cx: &App) -> Vec<PanelItem> {
+        (0..self.item_count(cx))
+            .filter_map(|index| self.item(index, cx))
+            .collect()
+    }
+
+    /// 第 `index` 个条目被激活（点击）时调用
+    fn on_activate(&self, index: usize, cx: &mut App);
+
+    /// 用户请求刷新时重新获取数据，返回的任务完成后面板会重新获取条目
+    ///
+    /// 默认立即完成，适合每次 `item` 都直接读取最新数据的数据源。
+    fn refresh(&self, _cx: &mut App) -> Task<()> {
+        Task::ready(())
+    }
+
+    /// 用户请求移除 `indices` 中的条目时调用，索引按从小到大排列
+    ///
+    /// 返回 true 表示条目已被移除，面板随后清空选中项并重新获取条目。
+    /// 默认不支持移除，返回 false。
+    fn on_remove(&self, _indices: &[usize], _cx: &mut App) -> bool {
+        false
+    }
+}
+
+/// 默认数据源，显示问候语
+///
+/// 标题和问候语使用的翻译键来自设置，部署方可以换成自己的文本而无需修改代码。
+pub struct DefaultSource;
+
+impl CustomPanelSource for DefaultSource {
+    fn title(&self, cx: &App) -> SharedString {
+        t_shared(&CustomPanelSettings::get_global(cx).title_key)
+    }
+
+    fn item_count(&self, _cx: &App) -> usize {
+        1
+    }
+
+    fn item(&self, index: usize, cx: &App) -> Option<PanelItem> {
+        let settings = CustomPanelSettings::get_global(cx);
+        // 每次渲染时按设置中的键翻译，设置或语言变化后立即生效
+        (index == 0).then(|| PanelItem::translated(settings.hello_key.clone()))
+    }
+
+    fn on_activate(&self, _index: usize, _cx: &mut App) {
+        // 默认条目只用于展示，激活时不做任何事
+    }
+}
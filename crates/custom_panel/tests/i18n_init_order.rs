@@ -0,0 +1,69 @@
+//! 在 `i18n::init` 之前创建面板
+//!
+//! 翻译管理器在一个进程中只能初始化一次，这个测试需要观察初始化前后的变化，
+//! 所以放在单独的测试程序中，不受其他测试的影响。
+
+use custom_panel::{CustomPanel, CustomPanelSource, DefaultSource, PanelItem};
+use gpui::{App, SharedString, TestAppContext, VisualTestContext, WeakEntity};
+use settings::SettingsStore;
+use std::{cell::RefCell, rc::Rc};
+use workspace::dock::Panel;
+
+/// 转发默认数据源的条目，并记录面板最近一次渲染时获取的文本
+struct RecordingSource {
+    rendered: Rc<RefCell<Vec<String>>>,
+}
+
+impl CustomPanelSource for RecordingSource {
+    fn item_count(&self, cx: &App) -> usize {
+        DefaultSource.item_count(cx)
+    }
+
+    fn item(&self, index: usize, cx: &App) -> Option<PanelItem> {
+        let item = DefaultSource.item(index, cx)?;
+        if let PanelItem::Entry { label, .. } = &item {
+            let mut rendered = self.rendered.borrow_mut();
+            if index == 0 {
+                rendered.clear();
+            }
+            rendered.push(label.to_string());
+        }
+        Some(item)
+    }
+
+    fn on_activate(&self, _index: usize, _cx: &mut App) {}
+}
+
+#[gpui::test]
+fn test_panel_created_before_i18n_init_is_translated_after_init(cx: &mut TestAppContext) {
+    cx.update(|cx| {
+        let settings = SettingsStore::test(cx);
+        cx.set_global(settings);
+        theme::init(theme::LoadThemes::JustBase, cx);
+    });
+    assert!(!i18n::is_initialized());
+
+    let rendered = Rc::new(RefCell::new(Vec::new()));
+    let source = RecordingSource {
+        rendered: rendered.clone(),
+    };
+    let (panel, cx) = cx.add_window_view(|window, cx| {
+        CustomPanel::with_source(WeakEntity::new_invalid(), Box::new(source), window, cx)
+    });
+    cx.run_until_parked();
+    let tooltip = |cx: &mut VisualTestContext| {
+        cx.update(|window, cx| panel.read(cx).icon_tooltip(window, cx))
+    };
+
+    // 初始化之前面板仍然可以正常创建，只是文字显示为原始键
+    assert_eq!(tooltip(cx), Some(SharedString::from("panel.custom_panel")));
+    assert_eq!(*rendered.borrow(), ["custom_panel.hello"]);
+
+    // 初始化之后面板重新渲染，文字换成翻译
+    cx.update(|_, cx| i18n::init(cx));
+    cx.run_until_parked();
+    let translated = i18n::t("panel.custom_panel");
+    assert_ne!(translated, "panel.custom_panel");
+    assert_eq!(tooltip(cx), Some(SharedString::from(translated)));
+    assert_eq!(*rendered.borrow(), [i18n::t("custom_panel.hello")]);
+}
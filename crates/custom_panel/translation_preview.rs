@@ -0,0 +1,277 @@
+use gpui::{
+    App, ClipboardItem, Context, Entity, EventEmitter, FocusHandle, Focusable, IntoElement, Render,
+    SharedString, Window, actions, div, prelude::*,
+};
+use i18n::{Language, Translations};
+use std::collections::HashSet;
+use ui::{IconName, Label, LabelSize, prelude::*};
+use workspace::dock::{DockPosition, Panel, PanelEvent};
+
+actions!(
+    translation_preview,
+    [
+        /// 打开翻译预览面板，对照英文检查当前语言的翻译
+        OpenTranslationPreview,
+        /// 切换翻译预览面板的焦点
+        ToggleFocus,
+        /// 在日志中输出当前语言与英文翻译表的差异
+        LogTranslationDiff,
+    ]
+);
+
+const TRANSLATION_PREVIEW_PANEL_KEY: &str = "TranslationPreviewPanel";
+
+/// 翻译预览中的一行
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreviewRow {
+    pub key: SharedString,
+    pub english: SharedString,
+    /// 当前语言的翻译，缺失时为 `None`
+    pub translated: Option<SharedString>,
+    /// 当前语言的翻译是否被标记为待审校
+    pub fuzzy: bool,
+}
+
+impl PreviewRow {
+    pub fn is_missing(&self) -> bool {
+        self.translated.is_none()
+    }
+
+    /// 有翻译但仍待审校
+    pub fn is_fuzzy(&self) -> bool {
+        self.fuzzy && !self.is_missing()
+    }
+}
+
+/// 以英文为基准生成按键排序的对照行
+pub fn preview_rows(
+    english: &Translations,
+    current: &Translations,
+    fuzzy: &HashSet<String>,
+) -> Vec<PreviewRow> {
+    let mut rows = english
+        .iter()
+        .map(|(key, english)| PreviewRow {
+            key: key.clone().into(),
+            english: english.clone().into(),
+            translated: current.get(key).map(|value| value.clone().into()),
+            fuzzy: fuzzy.contains(key),
+        })
+        .collect::<Vec<_>>();
+    rows.sort_by(|a, b| a.key.cmp(&b.key));
+    rows
+}
+
+/// 以英文为参照，将当前语言的翻译差异写入日志
+pub fn log_translation_diff() {
+    let language = i18n::get_language();
+    let diff = i18n::translation_diff(Language::English, language);
+    if diff.is_empty() {
+        log::info!("{} translations match English keys", language.as_str());
+        return;
+    }
+    log::info!(
+        "{} translation diff against English: {} missing, {} extra, {} untranslated, {} fuzzy",
+        language.as_str(),
+        diff.only_in_a.len(),
+        diff.only_in_b.len(),
+        diff.untranslated.len(),
+        diff.fuzzy.len()
+    );
+    for key in &diff.only_in_a {
+        log::info!("  missing: {key}");
+    }
+    for key in &diff.only_in_b {
+        log::info!("  extra: {key}");
+    }
+    for key in &diff.untranslated {
+        log::info!("  untranslated: {key}");
+    }
+    for key in &diff.fuzzy {
+        log::info!("  fuzzy: {key}");
+    }
+}
+
+/// 翻译预览面板，左侧显示英文，右侧显示当前语言，缺失的条目标红
+pub struct TranslationPreviewPanel {
+    focus_handle: FocusHandle,
+    rows: Vec<PreviewRow>,
+    width: Option<Pixels>,
+}
+
+impl TranslationPreviewPanel {
+    pub fn new(cx: &mut App) -> Entity<Self> {
+        let language = i18n::get_language();
+        let rows = preview_rows(
+            &i18n::snapshot(Language::English),
+            &i18n::snapshot(language),
+            &i18n::fuzzy_keys(language),
+        );
+        cx.new(|cx| Self {
+            focus_handle: cx.focus_handle(),
+            rows,
+            width: None,
+        })
+    }
+}
+
+impl Panel for TranslationPreviewPanel {
+    fn persistent_name() -> &'static str {
+        "Translation Preview"
+    }
+
+    fn panel_key() -> &'static str {
+        TRANSLATION_PREVIEW_PANEL_KEY
+    }
+
+    fn position(&self, _window: &Window, _cx: &App) -> DockPosition {
+        DockPosition::Right
+    }
+
+    fn position_is_valid(&self, position: DockPosition) -> bool {
+        matches!(position, DockPosition::Left | DockPosition::Right)
+    }
+
+    fn set_position(
+        &mut self,
+        _position: DockPosition,
+        _window: &mut Window,
+        _cx: &mut Context<Self>,
+    ) {
+    }
+
+    fn size(&self, _window: &Window, _cx: &App) -> Pixels {
+        self.width.unwrap_or(px(480.))
+    }
+
+    fn set_size(&mut self, size: Option<Pixels>, _window: &mut Window, cx: &mut Context<Self>) {
+        self.width = size;
+        cx.notify();
+    }
+
+    fn icon(&self, _window: &Window, _cx: &App) -> Option<IconName> {
+        Some(IconName::Book)
+    }
+
+    fn icon_tooltip(&self, _window: &Window, _cx: &App) -> Option<SharedString> {
+        Some(i18n::t("panel.translation_preview").into())
+    }
+
+    fn toggle_action(&self) -> Box<dyn gpui::Action> {
+        Box::new(ToggleFocus)
+    }
+
+    fn activation_priority(&self) -> u32 {
+        11
+    }
+}
+
+impl Focusable for TranslationPreviewPanel {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl EventEmitter<PanelEvent> for TranslationPreviewPanel {}
+
+impl Render for TranslationPreviewPanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let missing_count = self.rows.iter().filter(|row| row.is_missing()).count();
+        let fuzzy_count = self.rows.iter().filter(|row| row.is_fuzzy()).count();
+
+        v_flex()
+            .id("translation-preview")
+            .size_full()
+            .p_2()
+            .gap_1()
+            .overflow_y_scroll()
+            .bg(cx.theme().colors().panel_background)
+            .child(
+                // 待审校的条目不计入已翻译数量，单独列出
+                Label::new(format!(
+                    "{} / {} ({} fuzzy)",
+                    self.rows.len() - missing_count - fuzzy_count,
+                    self.rows.len(),
+                    fuzzy_count
+                ))
+                .size(LabelSize::Small)
+                .color(Color::Muted),
+            )
+            .children(self.rows.iter().enumerate().map(|(index, row)| {
+                let key = row.key.clone();
+                let translated = match &row.translated {
+                    Some(translated) if row.fuzzy => {
+                        Label::new(translated.clone()).color(Color::Warning)
+                    }
+                    Some(translated) => Label::new(translated.clone()),
+                    None => Label::new(key.clone()).color(Color::Error),
+                };
+                div()
+                    .id(index)
+                    .w_full()
+                    .flex()
+                    .gap_2()
+                    .when(row.is_missing(), |this| {
+                        // 点击缺失的行复制键名，方便补充到语言文件中
+                        this.cursor_pointer().on_click(move |_, _, cx| {
+                            cx.write_to_clipboard(ClipboardItem::new_string(key.to_string()))
+                        })
+                    })
+                    .child(div().flex_1().child(Label::new(row.english.clone())))
+                    .child(div().flex_1().child(translated))
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translations(entries: &[(&str, &str)]) -> Translations {
+        entries
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_missing_rows_are_flagged() {
+        let english = translations(&[("menu.save", "Save"), ("menu.open", "Open")]);
+        let current = translations(&[("menu.save", "保存"), ("menu.extra", "多余")]);
+
+        let rows = preview_rows(&english, &current, &HashSet::new());
+        assert_eq!(
+            rows,
+            vec![
+                PreviewRow {
+                    key: "menu.open".into(),
+                    english: "Open".into(),
+                    translated: None,
+                    fuzzy: false,
+                },
+                PreviewRow {
+                    key: "menu.save".into(),
+                    english: "Save".into(),
+                    translated: Some("保存".into()),
+                    fuzzy: false,
+                },
+            ]
+        );
+        assert!(rows[0].is_missing());
+        assert!(!rows[1].is_missing());
+    }
+
+    #[test]
+    fn test_fuzzy_rows_are_flagged_separately() {
+        let english = translations(&[("menu.save", "Save"), ("menu.open", "Open")]);
+        let current = translations(&[("menu.save", "保存")]);
+        let fuzzy = HashSet::from_iter(["menu.save".to_string(), "menu.open".to_string()]);
+
+        let rows = preview_rows(&english, &current, &fuzzy);
+        // 缺失的条目只算缺失，不算待审校
+        assert!(rows[0].is_missing());
+        assert!(!rows[0].is_fuzzy());
+        assert!(rows[1].is_fuzzy());
+        assert!(!rows[1].is_missing());
+    }
+}
@@ -31,6 +31,10 @@ pub struct DebuggerSettings {
     ///
     /// Default: Bottom
     pub dock: settings::DockPosition,
+    /// Whether running a task tagged "run" starts it under the debugger
+    ///
+    /// Default: false
+    pub auto_attach_on_run: bool,
 }
 
 impl Settings for DebuggerSettings {
@@ -46,6 +50,7 @@ impl Settings for DebuggerSettings {
             log_dap_communications: content.log_dap_communications.unwrap(),
             format_dap_log_messages: content.format_dap_log_messages.unwrap(),
             dock: content.dock.unwrap(),
+            auto_attach_on_run: content.auto_attach_on_run.unwrap(),
         }
     }
 }
@@ -637,7 +637,9 @@ impl DebugPanel {
                 .on_click(|_, window, cx| {
                     window.dispatch_action(zed_actions::OpenProjectDebugTasks.boxed_clone(), cx);
                 })
-                .tooltip(Tooltip::text(i18n::t_static("tooltip.configure_mcp_server")))
+                .tooltip(Tooltip::text(i18n::t_static(
+                    "tooltip.configure_mcp_server",
+                )))
         };
 
         let documentation_button = || {
@@ -1581,13 +1583,13 @@ impl Panel for DebugPanel {
             .then_some(IconName::Debug)
     }
 
-    fn icon_tooltip(&self, _window: &Window, cx: &App) -> Option<&'static str> {
-    if DebuggerSettings::get_global(cx).button {
-        Some(i18n::t_static("panel.debugger_panel"))
-    } else {
-        None
+    fn icon_tooltip(&self, _window: &Window, cx: &App) -> Option<SharedString> {
+        if DebuggerSettings::get_global(cx).button {
+            Some(i18n::t("panel.debugger_panel").into())
+        } else {
+            None
+        }
     }
-}
 
     fn toggle_action(&self) -> Box<dyn Action> {
         Box::new(ToggleFocus)
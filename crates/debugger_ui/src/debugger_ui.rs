@@ -1,15 +1,22 @@
 use std::any::TypeId;
 
+use dap::{DapRegistry, adapters::DebugAdapterName, debugger_settings::DebuggerSettings};
 use debugger_panel::DebugPanel;
 use editor::{Editor, MultiBufferOffsetUtf16};
-use gpui::{Action, App, DispatchPhase, EntityInputHandler, actions};
+use gpui::{Action, App, DispatchPhase, EntityInputHandler, SharedString, Task, actions};
+use language::language_settings::language_settings;
 use new_process_modal::{NewProcessModal, NewProcessMode};
 use onboarding_modal::DebuggerOnboardingModal;
-use project::debugger::{self, breakpoint_store::SourceBreakpoint, session::ThreadStatus};
+use project::{
+    TaskContexts,
+    debugger::{self, breakpoint_store::SourceBreakpoint, session::ThreadStatus},
+};
 use schemars::JsonSchema;
 use serde::Deserialize;
 use session::DebugSession;
+use settings::Settings;
 use stack_trace_view::StackTraceView;
+use task::{DebugScenario, SharedTaskContext};
 use tasks_ui::{Spawn, TaskOverrides};
 use ui::{FluentBuilder, InteractiveElement};
 use util::maybe;
@@ -88,6 +95,8 @@ actions!(
         /// When toggled on, only frames from the user's code are shown
         /// When toggled off, all frames are shown
         ToggleUserFrames,
+        /// Toggles whether running a task tagged "run" starts it under the debugger.
+        ToggleAutoAttachOnRun,
     ]
 );
 
@@ -121,6 +130,16 @@ pub fn init(cx: &mut App) {
             .register_action(|workspace, _: &ToggleFocus, window, cx| {
                 workspace.toggle_panel_focus::<DebugPanel>(window, cx);
             })
+            .register_action(
+                |workspace: &mut Workspace, _: &ToggleAutoAttachOnRun, _, cx| {
+                    let enabled = auto_attach_on_run(cx);
+                    let fs = workspace.app_state().fs.clone();
+                    settings::update_settings_file(fs, cx, move |settings, _| {
+                        settings.debugger.get_or_insert_default().auto_attach_on_run =
+                            Some(!enabled);
+                    });
+                },
+            )
             .register_action(|workspace: &mut Workspace, _: &Start, window, cx| {
                 NewProcessModal::show(workspace, window, NewProcessMode::Debug, None, cx);
             })
@@ -467,17 +486,140 @@ fn spawn_task_or_modal(
             let overrides = reveal_target.map(|reveal_target| TaskOverrides {
                 reveal_target: Some(reveal_target),
             });
-            let tag = task_tag.clone();
-            tasks_ui::spawn_tasks_filtered(
-                move |(_, task)| task.tags.contains(&tag),
-                overrides,
-                window,
-                cx,
-            )
-            .detach_and_log_err(cx)
+            if task_tag == RUN_TAG && auto_attach_on_run(cx) {
+                debug_tasks_with_tag(task_tag.clone(), overrides, window, cx).detach_and_log_err(cx)
+            } else {
+                tasks_ui::spawn_tasks_with_tag(task_tag.clone(), overrides, window, cx)
+                    .detach_and_log_err(cx)
+            }
         }
         Spawn::ViaModal { reveal_target } => {
             NewProcessModal::show(workspace, window, NewProcessMode::Task, *reveal_target, cx);
         }
     }
 }
+
+/// The task tag that `debugger.auto_attach_on_run` applies to.
+const RUN_TAG: &str = "run";
+
+/// Whether running a task tagged "run" currently starts it under the debugger.
+pub fn auto_attach_on_run(cx: &App) -> bool {
+    DebuggerSettings::get_global(cx).auto_attach_on_run
+}
+
+/// Starts the tasks tagged with `tag` under the debugger.
+///
+/// The tasks are turned into debug scenarios by the registered debug locators, using the
+/// debug adapter configured for the active buffer's language. When none of them can be
+/// debugged, they are spawned in the terminal as usual.
+fn debug_tasks_with_tag(
+    tag: String,
+    overrides: Option<TaskOverrides>,
+    window: &mut ui::Window,
+    cx: &mut ui::Context<Workspace>,
+) -> Task<anyhow::Result<()>> {
+    cx.spawn_in(window, async move |workspace, cx| {
+        let task_contexts = workspace
+            .update_in(cx, |workspace, window, cx| {
+                tasks_ui::task_contexts(workspace, window, cx)
+            })?
+            .await;
+        let scenarios = workspace
+            .update(cx, |workspace, cx| {
+                debug_scenarios_with_tag(workspace, &tag, &task_contexts, cx)
+            })?
+            .await;
+
+        workspace
+            .update_in(cx, |workspace, window, cx| {
+                if scenarios.is_empty() {
+                    return tasks_ui::spawn_tasks_with_tag(tag, overrides, window, cx);
+                }
+                let task_context: SharedTaskContext = task_contexts
+                    .active_context()
+                    .cloned()
+                    .unwrap_or_default()
+                    .into();
+                let active_buffer = task_contexts
+                    .location()
+                    .map(|location| location.buffer.clone());
+                for scenario in scenarios {
+                    workspace.start_debug_session(
+                        scenario,
+                        task_context.clone(),
+                        active_buffer.clone(),
+                        task_contexts.worktree(),
+                        window,
+                        cx,
+                    );
+                }
+                Task::ready(Ok(()))
+            })?
+            .await
+    })
+}
+
+/// Debug scenarios for the tasks tagged with `tag` that a debug locator knows how to debug.
+fn debug_scenarios_with_tag(
+    workspace: &Workspace,
+    tag: &str,
+    task_contexts: &TaskContexts,
+    cx: &mut App,
+) -> Task<Vec<DebugScenario>> {
+    let Some(task_inventory) = workspace
+        .project()
+        .read(cx)
+        .task_store()
+        .read(cx)
+        .task_inventory()
+        .cloned()
+    else {
+        return Task::ready(Vec::new());
+    };
+    let (file, language) = task_contexts
+        .location()
+        .map(|location| {
+            let buffer = location.buffer.read(cx);
+            (
+                buffer.file().cloned(),
+                buffer.language_at(location.range.start),
+            )
+        })
+        .unwrap_or_default();
+    let Some(adapter): Option<DebugAdapterName> =
+        language_settings(language.as_ref().map(|l| l.name()), file.as_ref(), cx)
+            .debuggers
+            .first()
+            .map(SharedString::from)
+            .or_else(|| {
+                language
+                    .as_ref()
+                    .and_then(|l| l.config().debuggers.first().map(SharedString::from))
+            })
+            .map(Into::into)
+    else {
+        return Task::ready(Vec::new());
+    };
+
+    let tasks = task_inventory
+        .read(cx)
+        .list_tasks(file, language, task_contexts.worktree(), cx);
+    let locators = DapRegistry::global(cx).locators();
+    let tag = tag.to_string();
+    cx.background_spawn(async move {
+        let mut scenarios = Vec::new();
+        for (_, task) in tasks.await {
+            if !task.tags.contains(&tag) {
+                continue;
+            }
+            for locator in locators.values() {
+                if let Some(scenario) = locator.create_scenario(&task, &task.label, &adapter).await
+                {
+                    scenarios.push(scenario);
+                    break;
+                }
+            }
+        }
+        scenarios
+    })
+}
@@ -1950,3 +1950,99 @@ async fn test_adapter_shutdown_with_child_sessions_on_app_quit(
         "Child session should have received disconnect request"
     );
 }
+
+/// Debugs every task whose command is `./run-me` with the fake adapter.
+struct RunMeLocator;
+
+#[async_trait::async_trait]
+impl dap::DapLocator for RunMeLocator {
+    fn name(&self) -> gpui::SharedString {
+        "run-me".into()
+    }
+
+    async fn create_scenario(
+        &self,
+        build_config: &task::TaskTemplate,
+        resolved_label: &str,
+        adapter: &dap::adapters::DebugAdapterName,
+    ) -> Option<task::DebugScenario> {
+        (build_config.command == "./run-me").then(|| task::DebugScenario {
+            adapter: adapter.0.clone(),
+            label: resolved_label.to_string().into(),
+            build: None,
+            config: json!({ "request": "launch" }),
+            tcp_connection: None,
+        })
+    }
+
+    async fn run(
+        &self,
+        _build_config: task::SpawnInTerminal,
+        _executor: gpui::BackgroundExecutor,
+    ) -> anyhow::Result<dap::DebugRequest> {
+        anyhow::bail!("run-me tasks are never built")
+    }
+}
+
+#[gpui::test]
+async fn test_run_task_attaches_debugger_when_enabled(
+    executor: BackgroundExecutor,
+    cx: &mut TestAppContext,
+) {
+    init_test(cx);
+
+    let fs = FakeFs::new(executor.clone());
+    fs.insert_tree(
+        path!("/project"),
+        json!({
+            ".zed": {
+                "tasks.json": r#"[{ "label": "run me", "command": "./run-me", "tags": ["run"] }]"#
+            },
+            "main.rs": "fn main() {}",
+        }),
+    )
+    .await;
+
+    let project = Project::test(fs, [path!("/project").as_ref()], cx).await;
+    let workspace = init_test_workspace(&project, cx).await;
+    let cx = &mut VisualTestContext::from_window(*workspace, cx);
+    cx.update(|_, cx| {
+        dap::DapRegistry::global(cx).add_locator(Arc::new(RunMeLocator));
+        settings::SettingsStore::update_global(cx, |store, cx| {
+            store.update_user_settings(cx, |settings| {
+                settings.project.all_languages.defaults.debuggers =
+                    Some(vec!["fake-adapter".to_string()]);
+            });
+        });
+    });
+    cx.run_until_parked();
+
+    let _subscription = project::debugger::test::intercept_debug_sessions(cx, |_| {});
+    let has_session = |cx: &mut VisualTestContext| {
+        workspace
+            .read_with(cx, |workspace, cx| {
+                workspace
+                    .panel::<DebugPanel>(cx)
+                    .is_some_and(|panel| panel.read(cx).active_session().is_some())
+            })
+            .unwrap()
+    };
+    assert!(!cx.update(|_, cx| auto_attach_on_run(cx)));
+    assert!(!has_session(cx));
+
+    cx.update(|_, cx| {
+        settings::SettingsStore::update_global(cx, |store, cx| {
+            store.update_user_settings(cx, |settings| {
+                settings.debugger.get_or_insert_default().auto_attach_on_run = Some(true);
+            });
+        });
+    });
+    assert!(cx.update(|_, cx| auto_attach_on_run(cx)));
+
+    cx.dispatch_action(Spawn::ByTag {
+        task_tag: "run".to_string(),
+        reveal_target: None,
+    });
+    cx.run_until_parked();
+    assert!(has_session(cx));
+}
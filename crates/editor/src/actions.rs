@@ -335,6 +335,26 @@ pub struct GoToPreviousDiagnostic {
     pub severity: GoToDiagnosticSeverityFilter,
 }
 
+/// Moves the cursor to the next symbol in the file's outline, wrapping at the end of the file.
+#[derive(PartialEq, Clone, Default, Debug, Deserialize, JsonSchema, Action)]
+#[action(namespace = editor)]
+#[serde(deny_unknown_fields)]
+pub struct GoToNextMethod {
+    /// Whether to also stop at symbols nested inside other symbols, such as methods in an impl.
+    #[serde(default)]
+    pub include_nested: bool,
+}
+
+/// Moves the cursor to the previous symbol in the file's outline, wrapping at the start of the file.
+#[derive(PartialEq, Clone, Default, Debug, Deserialize, JsonSchema, Action)]
+#[action(namespace = editor)]
+#[serde(deny_unknown_fields)]
+pub struct GoToPreviousMethod {
+    /// Whether to also stop at symbols nested inside other symbols, such as methods in an impl.
+    #[serde(default)]
+    pub include_nested: bool,
+}
+
 /// Adds a cursor above the current selection.
 #[derive(PartialEq, Clone, Default, Debug, Deserialize, JsonSchema, Action)]
 #[action(namespace = editor)]
@@ -353,6 +373,17 @@ pub struct AddSelectionBelow {
     pub skip_soft_wrap: bool,
 }
 
+/// Surrounds each selection with a pair of characters or an HTML tag.
+#[derive(PartialEq, Clone, Default, Debug, Deserialize, JsonSchema, Action)]
+#[action(namespace = editor)]
+#[serde(deny_unknown_fields)]
+pub struct SurroundSelections {
+    /// The opening character (such as `(` or `"`) or tag (such as `<div>`) to surround with.
+    /// When omitted, prompts for one of the common pairs.
+    #[serde(default)]
+    pub with: Option<String>,
+}
+
 /// Inserts a snippet at the cursor.
 #[derive(PartialEq, Clone, Default, Debug, Deserialize, JsonSchema, Action)]
 #[action(namespace = editor)]
@@ -592,6 +623,8 @@ actions!(
         InsertUuidV4,
         /// Inserts a UUID v7 at cursor position.
         InsertUuidV7,
+        /// Selects all text that is not currently selected.
+        InvertSelection,
         /// Joins the current line with the next line.
         JoinLines,
         /// Cuts to kill ring (Emacs-style).
@@ -683,6 +716,8 @@ actions!(
         PageUp,
         /// Pastes from clipboard.
         Paste,
+        /// Shows the definition of the symbol under the cursor inline, below the cursor.
+        PeekDefinition,
         /// Navigates to the previous edit prediction.
         PreviousEditPrediction,
         /// Goes to the previous snippet tabstop if one exists.
@@ -739,6 +774,10 @@ actions!(
         SelectToEndOfLargerSyntaxNode,
         /// Selects the next larger syntax node.
         SelectLargerSyntaxNode,
+        /// Extends each selection by one word at both ends.
+        GrowSelectionByWord,
+        /// Shrinks each selection by one word at both ends.
+        ShrinkSelectionByWord,
         /// Selects the next syntax node sibling.
         SelectNextSyntaxNode,
         /// Selects the previous syntax node sibling.
@@ -860,6 +899,9 @@ actions!(
         ToggleTabBar,
         /// Transposes characters around cursor.
         Transpose,
+        /// Removes trailing whitespace from the selected lines, or from the whole buffer
+        /// when nothing is selected.
+        TrimTrailingWhitespace,
         /// Undoes the last edit.
         Undo,
         /// Undoes the last selection change.
@@ -115,8 +115,8 @@ use gpui::{
     DispatchPhase, Edges, Entity, EntityInputHandler, EventEmitter, FocusHandle, FocusOutEvent,
     Focusable, FontId, FontStyle, FontWeight, Global, HighlightStyle, Hsla, KeyContext, Modifiers,
     MouseButton, MouseDownEvent, MouseMoveEvent, PaintQuad, ParentElement, Pixels, PressureStage,
-    Render, ScrollHandle, SharedString, SharedUri, Size, Stateful, Styled, Subscription, Task,
-    TextRun, TextStyle, TextStyleRefinement, UTF16Selection, UnderlineStyle,
+    PromptLevel, Render, ScrollHandle, SharedString, SharedUri, Size, Stateful, Styled,
+    Subscription, Task, TextRun, TextStyle, TextStyleRefinement, UTF16Selection, UnderlineStyle,
     UniformListScrollHandle, WeakEntity, WeakFocusHandle, Window, div, point, prelude::*,
     pulsating_between, px, relative, size,
 };
@@ -237,6 +237,7 @@ pub const FILE_HEADER_HEIGHT: u32 = 2;
 pub const MULTI_BUFFER_EXCERPT_HEADER_HEIGHT: u32 = 1;
 const CURSOR_BLINK_INTERVAL: Duration = Duration::from_millis(500);
 const MAX_LINE_LEN: usize = 1024;
+const PEEK_DEFINITION_HEIGHT: u32 = 12;
 const MIN_NAVIGATION_HISTORY_ROW_DELTA: i64 = 10;
 const MAX_SELECTION_HISTORY_LEN: usize = 1024;
 pub(crate) const CURSORS_VISIBLE_FOR: Duration = Duration::from_millis(2000);
@@ -1214,6 +1215,7 @@ pub struct Editor {
     linked_editing_range_task: Option<Task<Option<()>>>,
     linked_edit_ranges: linked_editing_ranges::LinkedEditingRanges,
     pending_rename: Option<RenameState>,
+    peek_definition: Option<PeekDefinitionState>,
     searchable: bool,
     cursor_shape: CursorShape,
     /// Whether the cursor is offset one character to the left when something is
@@ -1701,6 +1703,12 @@ pub struct RenameState {
     block_id: CustomBlockId,
 }
 
+#[doc(hidden)]
+pub struct PeekDefinitionState {
+    pub editor: Entity<Editor>,
+    block_id: CustomBlockId,
+}
+
 struct InvalidationStack<T>(Vec<T>);
 
 struct RegisteredEditPredictionDelegate {
@@ -2400,7 +2408,8 @@ impl Editor {
             },
             minimap_visibility: MinimapVisibility::for_mode(&mode, cx),
             offset_content: !matches!(mode, EditorMode::SingleLine),
-            show_breadcrumbs: EditorSettings::get_global(cx).toolbar.breadcrumbs,
+            show_breadcrumbs: EditorSettings::get_global(cx).toolbar.breadcrumbs
+                && !workspace::presentation_mode_active(cx),
             show_gutter: full_mode,
             show_line_numbers: (!full_mode).then_some(false),
             use_relative_line_numbers: None,
@@ -2443,6 +2452,7 @@ impl Editor {
             document_highlights_task: None,
             linked_editing_range_task: None,
             pending_rename: None,
+            peek_definition: None,
             searchable: !is_minimap,
             cursor_shape: EditorSettings::get_global(cx)
                 .cursor_shape
@@ -2528,6 +2538,10 @@ impl Editor {
                         cx.observe_in(&display_map, window, Self::on_display_map_changed),
                         cx.observe(&blink_manager, |_, _, cx| cx.notify()),
                         cx.observe_global_in::<SettingsStore>(window, Self::settings_changed),
+                        cx.observe_global_in::<workspace::PresentationMode>(
+                            window,
+                            Self::settings_changed,
+                        ),
                         cx.observe_global_in::<GlobalTheme>(window, Self::theme_changed),
                         observe_buffer_font_size_adjustment(cx, |_, cx| cx.notify()),
                         cx.observe_window_activation(window, |editor, window, cx| {
@@ -4453,6 +4467,7 @@ impl Editor {
         let mut dismissed = false;
 
         dismissed |= self.take_rename(false, window, cx).is_some();
+        dismissed |= self.dismiss_peek_definition(window, cx);
         dismissed |= self.hide_blame_popover(true, cx);
         dismissed |= hide_hover(self, cx);
         dismissed |= self.hide_signature_help(cx, SignatureHelpHiddenBy::Escape);
@@ -11434,6 +11449,97 @@ impl Editor {
         });
     }
 
+    pub fn surround_selections(
+        &mut self,
+        action: &SurroundSelections,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if let Some(with) = action.with.as_deref() {
+            let (open, close) = surround_pair(with);
+            self.surround_selections_with(&open, &close, window, cx);
+            return;
+        }
+
+        const PAIRS: [&str; 6] = ["(", "[", "{", "\"", "'", "`"];
+        let answers = PAIRS
+            .iter()
+            .map(|open| format!("{open}…{}", surround_pair(open).1))
+            .chain([i18n::t("tooltip.cancel")])
+            .collect::<Vec<_>>();
+        let answers = answers.iter().map(String::as_str).collect::<Vec<_>>();
+        let answer = window.prompt(
+            PromptLevel::Info,
+            &i18n::t("menu.surround_selections_with"),
+            None,
+            &answers,
+            cx,
+        );
+        cx.spawn_in(window, async move |editor, cx| {
+            let Some(open) = PAIRS.get(answer.await?) else {
+                return anyhow::Ok(());
+            };
+            editor.update_in(cx, |editor, window, cx| {
+                let (open, close) = surround_pair(open);
+                editor.surround_selections_with(&open, &close, window, cx);
+            })
+        })
+        .detach_and_log_err(cx);
+    }
+
+    /// Inserts `open` before and `close` after every selection, keeping the
+    /// original text selected.
+    pub fn surround_selections_with(
+        &mut self,
+        open: &str,
+        close: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.read_only(cx) {
+            return;
+        }
+        self.hide_mouse_cursor(HideMouseCursorOrigin::TypingAction, cx);
+
+        let snapshot = self.buffer.read(cx).snapshot(cx);
+        let mut edits = Vec::new();
+        let mut boundaries = Vec::new();
+        for selection in self
+            .selections
+            .all_adjusted(&self.display_snapshot(cx))
+            .iter()
+        {
+            let start_before = snapshot.anchor_before(selection.start);
+            let end_after = snapshot.anchor_after(selection.end);
+            edits.push((start_before..start_before, open.to_string()));
+            edits.push((end_after..end_after, close.to_string()));
+            boundaries.push((start_before, end_after));
+        }
+
+        self.transact(window, cx, |this, window, cx| {
+            let buffer = this.buffer.update(cx, |buffer, cx| {
+                buffer.edit(edits, None, cx);
+                buffer.snapshot(cx)
+            });
+
+            let new_selections = boundaries
+                .into_iter()
+                .map(|(start_before, end_after)| {
+                    let start = start_before.to_offset(&buffer) + open.len();
+                    let end = end_after
+                        .to_offset(&buffer)
+                        .saturating_sub_usize(close.len());
+                    start..end
+                })
+                .collect::<Vec<_>>();
+            this.change_selections(Default::default(), window, cx, |s| {
+                s.select_ranges(new_selections);
+            });
+
+            this.request_autoscroll(Autoscroll::fit(), cx);
+        });
+    }
+
     pub fn toggle_read_only(
         &mut self,
         _: &workspace::ToggleReadOnlyFile,
@@ -11972,6 +12078,68 @@ impl Editor {
         self.manipulate_immutable_lines(window, cx, |lines| lines.shuffle(&mut rand::rng()))
     }
 
+    /// Removes whitespace from the end of every line touched by a non-empty
+    /// selection, or of every line in the buffer when nothing is selected.
+    /// Leading whitespace is left untouched.
+    pub fn trim_trailing_whitespace(
+        &mut self,
+        _: &TrimTrailingWhitespace,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if self.read_only(cx) {
+            return;
+        }
+        self.hide_mouse_cursor(HideMouseCursorOrigin::TypingAction, cx);
+
+        let snapshot = self.buffer.read(cx).snapshot(cx);
+        let selections = self.selections.all::<Point>(&self.display_snapshot(cx));
+        let mut rows = if selections.iter().all(|selection| selection.is_empty()) {
+            (0..=snapshot.max_row().0).collect::<Vec<_>>()
+        } else {
+            selections
+                .iter()
+                .filter(|selection| !selection.is_empty())
+                .flat_map(|selection| {
+                    // A selection ending at the start of a line doesn't include that line.
+                    let end_row =
+                        if selection.end.column == 0 && selection.end.row > selection.start.row {
+                            selection.end.row - 1
+                        } else {
+                            selection.end.row
+                        };
+                    selection.start.row..=end_row
+                })
+                .collect()
+        };
+        rows.sort_unstable();
+        rows.dedup();
+
+        let mut edits = Vec::new();
+        for row in rows {
+            let line_end = Point::new(row, snapshot.line_len(MultiBufferRow(row)));
+            let trailing_len = snapshot
+                .reversed_chars_at(line_end)
+                .take_while(|ch| *ch != '\n' && ch.is_whitespace())
+                .map(|ch| ch.len_utf8() as u32)
+                .sum::<u32>();
+            if trailing_len > 0 {
+                edits.push((
+                    Point::new(row, line_end.column - trailing_len)..line_end,
+                    "",
+                ));
+            }
+        }
+        if edits.is_empty() {
+            return;
+        }
+
+        self.transact(window, cx, |this, _, cx| {
+            this.buffer
+                .update(cx, |buffer, cx| buffer.edit(edits, None, cx));
+        });
+    }
+
     pub fn rotate_selections_forward(
         &mut self,
         _: &RotateSelectionsForward,
@@ -15207,6 +15375,36 @@ impl Editor {
         });
     }
 
+    pub fn invert_selection(
+        &mut self,
+        _: &InvertSelection,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.hide_mouse_cursor(HideMouseCursorOrigin::MovementAction, cx);
+        let display_map = self.display_map.update(cx, |map, cx| map.snapshot(cx));
+        let selections = self.selections.all::<MultiBufferOffset>(&display_map);
+
+        // Empty selections don't select anything, so with only cursors this selects
+        // the whole buffer.
+        let mut ranges = Vec::new();
+        let mut start = MultiBufferOffset(0);
+        for selection in selections.iter().filter(|selection| !selection.is_empty()) {
+            if selection.start > start {
+                ranges.push(start..selection.start);
+            }
+            start = cmp::max(start, selection.end);
+        }
+        let end = display_map.buffer_snapshot().len();
+        if start < end || ranges.is_empty() {
+            ranges.push(start..end);
+        }
+
+        self.change_selections(Default::default(), window, cx, |s| {
+            s.select_ranges(ranges);
+        });
+    }
+
     pub fn select_line(&mut self, _: &SelectLine, window: &mut Window, cx: &mut Context<Self>) {
         self.hide_mouse_cursor(HideMouseCursorOrigin::MovementAction, cx);
         let display_map = self.display_map.update(cx, |map, cx| map.snapshot(cx));
@@ -16364,6 +16562,54 @@ impl Editor {
         }
     }
 
+    pub fn grow_selection_by_word(
+        &mut self,
+        _: &GrowSelectionByWord,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.hide_mouse_cursor(HideMouseCursorOrigin::MovementAction, cx);
+        self.change_selections(Default::default(), window, cx, |s| {
+            s.move_with(|map, selection| {
+                selection.start = movement::previous_word_start(map, selection.start);
+                selection.end = movement::next_word_end(map, selection.end);
+                selection.goal = SelectionGoal::None;
+            });
+        })
+    }
+
+    pub fn shrink_selection_by_word(
+        &mut self,
+        _: &ShrinkSelectionByWord,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.hide_mouse_cursor(HideMouseCursorOrigin::MovementAction, cx);
+        self.change_selections(Default::default(), window, cx, |s| {
+            s.move_with(|map, selection| {
+                // Step past the first word, then back to the start of the word after it, so the
+                // new start lands on a word rather than on the whitespace between words.
+                let start = movement::previous_word_start(
+                    map,
+                    movement::next_word_end(map, movement::next_word_end(map, selection.start)),
+                );
+                let end = movement::next_word_end(
+                    map,
+                    movement::previous_word_start(
+                        map,
+                        movement::previous_word_start(map, selection.end),
+                    ),
+                );
+                // Leave selections that are a single word or less untouched.
+                if start < end && selection.start < start && end < selection.end {
+                    selection.start = start;
+                    selection.end = end;
+                    selection.goal = SelectionGoal::None;
+                }
+            });
+        })
+    }
+
     pub fn select_larger_syntax_node(
         &mut self,
         _: &SelectLargerSyntaxNode,
@@ -17643,6 +17889,70 @@ impl Editor {
         }
     }
 
+    pub fn go_to_next_method(
+        &mut self,
+        action: &GoToNextMethod,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.go_to_method(Direction::Next, action.include_nested, window, cx);
+    }
+
+    pub fn go_to_previous_method(
+        &mut self,
+        action: &GoToPreviousMethod,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.go_to_method(Direction::Prev, action.include_nested, window, cx);
+    }
+
+    /// Moves the cursor to the start of the next or previous outline item, wrapping around
+    /// at the file boundaries. Only top-level items are visited unless `include_nested` is set.
+    fn go_to_method(
+        &mut self,
+        direction: Direction,
+        include_nested: bool,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.hide_mouse_cursor(HideMouseCursorOrigin::MovementAction, cx);
+        let buffer = self.buffer.read(cx).snapshot(cx);
+        let Some(outline) = buffer.outline(None) else {
+            return;
+        };
+        let starts = outline
+            .items
+            .iter()
+            .filter(|item| include_nested || item.depth == 0)
+            .map(|item| item.range.start.to_point(&buffer))
+            .collect::<Vec<_>>();
+        let head = self
+            .selections
+            .newest::<Point>(&self.display_snapshot(cx))
+            .head();
+        let target = match direction {
+            Direction::Next => starts
+                .iter()
+                .find(|start| **start > head)
+                .or(starts.first()),
+            Direction::Prev => starts
+                .iter()
+                .rev()
+                .find(|start| **start < head)
+                .or(starts.last()),
+        };
+        let Some(&target) = target else {
+            return;
+        };
+        self.change_selections(
+            SelectionEffects::scroll(Autoscroll::center()),
+            window,
+            cx,
+            |s| s.select_ranges([target..target]),
+        );
+    }
+
     pub fn go_to_next_document_highlight(
         &mut self,
         _: &GoToNextDocumentHighlight,
@@ -17896,6 +18206,124 @@ impl Editor {
         })
     }
 
+    pub fn peek_definition(
+        &mut self,
+        _: &PeekDefinition,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Task<Result<()>> {
+        let Some(provider) = self.semantics_provider.clone() else {
+            return Task::ready(Ok(()));
+        };
+        let position = self.selections.newest_anchor().head();
+        let Some((buffer, head)) = self.buffer.read(cx).text_anchor_for_position(position, cx)
+        else {
+            return Task::ready(Ok(()));
+        };
+        let Some(definitions) = provider.definitions(&buffer, head, GotoDefinitionKind::Symbol, cx)
+        else {
+            return Task::ready(Ok(()));
+        };
+
+        cx.spawn_in(window, async move |editor, cx| {
+            let Some(definitions) = definitions.await? else {
+                return Ok(());
+            };
+            editor.update_in(cx, |editor, window, cx| {
+                let mut locations = HashMap::<Entity<Buffer>, Vec<Range<Point>>>::default();
+                for definition in definitions {
+                    if !hover_links::exclude_link_to_position(&buffer, &head, &definition, cx) {
+                        continue;
+                    }
+                    let target = definition.target;
+                    let range = target.range.to_point(target.buffer.read(cx));
+                    locations.entry(target.buffer).or_default().push(range);
+                }
+                editor.show_peek_definition(position, locations, window, cx);
+            })
+        })
+    }
+
+    /// Shows every location in a read-only editor embedded below `position`, so that
+    /// multiple definitions are listed as separate excerpts.
+    fn show_peek_definition(
+        &mut self,
+        position: Anchor,
+        locations: HashMap<Entity<Buffer>, Vec<Range<Point>>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.dismiss_peek_definition(window, cx);
+        if locations.is_empty() {
+            return;
+        }
+
+        let excerpts = cx.new(|cx| {
+            let mut multibuffer = MultiBuffer::new(Capability::ReadOnly);
+            for (buffer, mut ranges) in locations {
+                ranges.sort_by_key(|range| (range.start, Reverse(range.end)));
+                ranges.dedup();
+                multibuffer.set_excerpts_for_path(
+                    PathKey::for_buffer(&buffer, cx),
+                    buffer,
+                    ranges,
+                    multibuffer_context_lines(cx),
+                    cx,
+                );
+            }
+            multibuffer
+        });
+        let project = self.project.clone();
+        let peek_editor = cx.new(|cx| {
+            let mut editor = Editor::for_multibuffer(excerpts, project, window, cx);
+            editor.set_read_only(true);
+            editor
+        });
+
+        let block_id = self.insert_blocks(
+            [BlockProperties {
+                style: BlockStyle::Flex,
+                placement: BlockPlacement::Below(position),
+                height: Some(PEEK_DEFINITION_HEIGHT),
+                render: Arc::new({
+                    let peek_editor = peek_editor.clone();
+                    move |cx: &mut BlockContext| {
+                        div()
+                            .block_mouse_except_scroll()
+                            .size_full()
+                            .border_y_1()
+                            .border_color(cx.theme().colors().border)
+                            .child(EditorElement::new(&peek_editor, cx.editor_style.clone()))
+                            .into_any_element()
+                    }
+                }),
+                priority: 0,
+            }],
+            Some(Autoscroll::fit()),
+            cx,
+        )[0];
+        self.peek_definition = Some(PeekDefinitionState {
+            editor: peek_editor,
+            block_id,
+        });
+        cx.notify();
+    }
+
+    fn dismiss_peek_definition(&mut self, window: &mut Window, cx: &mut Context<Self>) -> bool {
+        let Some(peek) = self.peek_definition.take() else {
+            return false;
+        };
+        if peek.editor.focus_handle(cx).contains_focused(window, cx) {
+            window.focus(&self.focus_handle, cx);
+        }
+        self.remove_blocks([peek.block_id].into_iter().collect(), None, cx);
+        true
+    }
+
+    pub fn peek_definition_state(&self) -> Option<&PeekDefinitionState> {
+        self.peek_definition.as_ref()
+    }
+
     pub fn open_url(&mut self, _: &OpenUrl, window: &mut Window, cx: &mut Context<Self>) {
         let selection = self.selections.newest_anchor();
         let head = selection.head();
@@ -21110,15 +21538,20 @@ impl Editor {
         _: &mut Window,
         cx: &mut Context<Self>,
     ) {
-        let currently_enabled = self.should_show_indent_guides().unwrap_or_else(|| {
+        self.show_indent_guides = Some(!self.indent_guides_enabled(cx));
+        cx.emit(EditorEvent::IndentGuidesToggled);
+        cx.notify();
+    }
+
+    /// Whether indent guides are shown, either from an explicit toggle or the language settings.
+    pub fn indent_guides_enabled(&self, cx: &App) -> bool {
+        self.should_show_indent_guides().unwrap_or_else(|| {
             self.buffer
                 .read(cx)
                 .language_settings(cx)
                 .indent_guides
                 .enabled
-        });
-        self.show_indent_guides = Some(!currently_enabled);
-        cx.notify();
+        })
     }
 
     fn should_show_indent_guides(&self) -> Option<bool> {
@@ -24130,7 +24563,8 @@ impl Editor {
         {
             let editor_settings = EditorSettings::get_global(cx);
             self.scroll_manager.vertical_scroll_margin = editor_settings.vertical_scroll_margin;
-            self.show_breadcrumbs = editor_settings.toolbar.breadcrumbs;
+            self.show_breadcrumbs =
+                editor_settings.toolbar.breadcrumbs && !workspace::presentation_mode_active(cx);
             self.cursor_shape = editor_settings.cursor_shape.unwrap_or_default();
             self.hide_mouse_mode = editor_settings.hide_mouse.unwrap_or_default();
         }
@@ -26008,6 +26442,34 @@ fn update_uncommitted_diff_for_buffer(
     })
 }
 
+/// Returns the opening and closing text used to surround a selection with `with`.
+///
+/// A tag such as `<div class="a">` closes with `</div>`, a bracket closes with its
+/// matching bracket, and anything else (quotes, for example) is used on both sides.
+fn surround_pair(with: &str) -> (String, String) {
+    if let Some(tag) = with.strip_prefix('<').and_then(|tag| tag.strip_suffix('>')) {
+        let name = tag.split_whitespace().next().unwrap_or_default();
+        if !name.is_empty() {
+            return (with.to_string(), format!("</{name}>"));
+        }
+    }
+    let close = match with {
+        "(" | ")" => ")",
+        "[" | "]" => "]",
+        "{" | "}" => "}",
+        "<" | ">" => ">",
+        _ => with,
+    };
+    let open = match close {
+        ")" => "(",
+        "]" => "[",
+        "}" => "{",
+        ">" => "<",
+        _ => with,
+    };
+    (open.to_string(), close.to_string())
+}
+
 fn char_len_with_expanded_tabs(offset: usize, text: &str, tab_size: NonZeroU32) -> usize {
     let tab_size = tab_size.get() as usize;
     let mut width = offset;
@@ -27652,6 +28114,8 @@ pub enum EditorEvent {
     },
     CursorShapeChanged,
     BreadcrumbsChanged,
+    InlineValuesToggled,
+    IndentGuidesToggled,
     PushedToNavHistory {
         anchor: Anchor,
         is_deactivate: bool,
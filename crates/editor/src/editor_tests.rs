@@ -67,7 +67,8 @@ use util::{
     uri,
 };
 use workspace::{
-    CloseActiveItem, CloseAllItems, CloseOtherItems, NavigationEntry, OpenOptions, ViewId,
+    CloseActiveItem, CloseAllItems, CloseOtherItems, NavigationEntry, OpenOptions,
+    ToolbarItemLocation, ViewId,
     item::{FollowEvent, FollowableItem, Item, ItemHandle, SaveOptions},
     register_project_item,
 };
@@ -2168,6 +2169,42 @@ fn test_prev_next_word_boundary(cx: &mut TestAppContext) {
     });
 }
 
+#[gpui::test]
+async fn test_grow_and_shrink_selection_by_word(cx: &mut TestAppContext) {
+    init_test(cx, |_| {});
+    let mut cx = EditorTestContext::new(cx).await;
+
+    cx.set_state("one two «threeˇ» four five");
+    cx.update_editor(|editor, window, cx| {
+        editor.grow_selection_by_word(&GrowSelectionByWord, window, cx)
+    });
+    cx.assert_editor_state("one «two three fourˇ» five");
+    cx.update_editor(|editor, window, cx| {
+        editor.grow_selection_by_word(&GrowSelectionByWord, window, cx)
+    });
+    cx.assert_editor_state("«one two three four fiveˇ»");
+
+    // Growing stops at the edges of the buffer.
+    cx.update_editor(|editor, window, cx| {
+        editor.grow_selection_by_word(&GrowSelectionByWord, window, cx)
+    });
+    cx.assert_editor_state("«one two three four fiveˇ»");
+
+    cx.update_editor(|editor, window, cx| {
+        editor.shrink_selection_by_word(&ShrinkSelectionByWord, window, cx)
+    });
+    cx.assert_editor_state("one «two three fourˇ» five");
+    cx.update_editor(|editor, window, cx| {
+        editor.shrink_selection_by_word(&ShrinkSelectionByWord, window, cx)
+    });
+    cx.assert_editor_state("one two «threeˇ» four five");
+
+    // A single word cannot shrink any further.
+    cx.update_editor(|editor, window, cx| {
+        editor.shrink_selection_by_word(&ShrinkSelectionByWord, window, cx)
+    });
+    cx.assert_editor_state("one two «threeˇ» four five");
+}
 #[gpui::test]
 fn test_prev_next_word_bounds_with_soft_wrap(cx: &mut TestAppContext) {
     init_test(cx, |_| {});
@@ -5323,6 +5360,51 @@ async fn test_manipulate_immutable_lines_with_multi_selection(cx: &mut TestAppCo
     "});
 }
 
+#[gpui::test]
+async fn test_trim_trailing_whitespace(cx: &mut TestAppContext) {
+    init_test(cx, |_| {});
+
+    let mut cx = EditorTestContext::new(cx).await;
+
+    // Only the selected lines are trimmed, leading whitespace is preserved
+    cx.set_state("    one  \n«two \t\n  threeˇ»  \nfour  \n");
+    cx.update_editor(|e, window, cx| {
+        e.trim_trailing_whitespace(&TrimTrailingWhitespace, window, cx);
+    });
+    cx.assert_editor_state("    one  \n«two\n  threeˇ»\nfour  \n");
+
+    // Without a selection the whole buffer is trimmed
+    cx.set_state("  one  \nˇtwo\t\n\t\n");
+    cx.update_editor(|e, window, cx| {
+        e.trim_trailing_whitespace(&TrimTrailingWhitespace, window, cx);
+    });
+    cx.assert_editor_state("  one\nˇtwo\n\n");
+}
+
+#[gpui::test]
+fn test_presentation_mode_hides_breadcrumbs(cx: &mut TestAppContext) {
+    init_test(cx, |_| {});
+
+    let editor = cx.add_window(|window, cx| {
+        let buffer = MultiBuffer::build_simple("fn main() {}\n", cx);
+        build_editor(buffer, window, cx)
+    });
+    let breadcrumb_location = |cx: &mut TestAppContext| {
+        editor
+            .update(cx, |editor, _, cx| editor.breadcrumb_location(cx))
+            .unwrap()
+    };
+    assert_eq!(breadcrumb_location(cx), ToolbarItemLocation::PrimaryLeft);
+
+    cx.update(workspace::toggle_presentation_mode);
+    cx.run_until_parked();
+    assert_eq!(breadcrumb_location(cx), ToolbarItemLocation::Hidden);
+
+    cx.update(workspace::toggle_presentation_mode);
+    cx.run_until_parked();
+    assert_eq!(breadcrumb_location(cx), ToolbarItemLocation::PrimaryLeft);
+}
+
 #[gpui::test]
 async fn test_convert_indentation_to_spaces(cx: &mut TestAppContext) {
     init_test(cx, |settings| {
@@ -7912,6 +7994,176 @@ fn test_select_all(cx: &mut TestAppContext) {
     });
 }
 
+#[gpui::test]
+async fn test_invert_selection(cx: &mut TestAppContext) {
+    init_test(cx, |_| {});
+    let mut cx = EditorTestContext::new(cx).await;
+
+    cx.set_state("one «twoˇ» three «fourˇ» five");
+    cx.update_editor(|editor, window, cx| editor.invert_selection(&InvertSelection, window, cx));
+    cx.assert_editor_state("«one ˇ»two« three ˇ»four« fiveˇ»");
+
+    // Selections touching the buffer boundaries leave no empty ranges behind.
+    cx.set_state("«oneˇ» two «threeˇ»");
+    cx.update_editor(|editor, window, cx| editor.invert_selection(&InvertSelection, window, cx));
+    cx.assert_editor_state("one« two ˇ»three");
+
+    // With only cursors, nothing is selected, so the whole buffer becomes selected.
+    cx.set_state("one ˇtwo\nthreeˇ");
+    cx.update_editor(|editor, window, cx| editor.invert_selection(&InvertSelection, window, cx));
+    cx.assert_editor_state("«one two\nthreeˇ»");
+
+    // Inverting a full selection leaves a cursor at the end of the buffer.
+    cx.update_editor(|editor, window, cx| editor.invert_selection(&InvertSelection, window, cx));
+    cx.assert_editor_state("one two\nthreeˇ");
+}
+
+#[gpui::test]
+async fn test_surround_selections(cx: &mut TestAppContext) {
+    init_test(cx, |_| {});
+    let mut cx = EditorTestContext::new(cx).await;
+
+    let surround = |with: &str| SurroundSelections {
+        with: Some(with.to_string()),
+    };
+
+    cx.set_state("one «twoˇ» three");
+    cx.update_editor(|editor, window, cx| editor.surround_selections(&surround("("), window, cx));
+    cx.assert_editor_state("one («twoˇ») three");
+
+    // Every selection is surrounded, and closing brackets are mapped to their pair.
+    cx.set_state("«oneˇ» two «threeˇ»");
+    cx.update_editor(|editor, window, cx| editor.surround_selections(&surround("]"), window, cx));
+    cx.assert_editor_state("[«oneˇ»] two [«threeˇ»]");
+
+    cx.set_state("say «hiˇ»");
+    cx.update_editor(|editor, window, cx| editor.surround_selections(&surround("\""), window, cx));
+    cx.assert_editor_state("say \"«hiˇ»\"");
+
+    cx.set_state("«textˇ»");
+    cx.update_editor(|editor, window, cx| {
+        editor.surround_selections(&surround("<span class=\"a\">"), window, cx)
+    });
+    cx.assert_editor_state("<span class=\"a\">«textˇ»</span>");
+
+    // Read-only editors are left untouched.
+    cx.set_state("«textˇ»");
+    cx.update_editor(|editor, window, cx| {
+        editor.set_read_only(true);
+        editor.surround_selections(&surround("("), window, cx)
+    });
+    cx.assert_editor_state("«textˇ»");
+}
+
+#[gpui::test]
+async fn test_go_to_next_and_previous_method(cx: &mut TestAppContext) {
+    init_test(cx, |_| {});
+    let mut cx = EditorTestContext::new(cx).await;
+    cx.update_buffer(|buffer, cx| buffer.set_language(Some(rust_lang()), cx));
+
+    cx.set_state(indoc! {"
+        ˇfn a() {}
+
+        fn b() {
+            fn inner() {}
+        }
+
+        impl S {
+            fn m() {}
+        }
+    "});
+    cx.run_until_parked();
+
+    let next = |cx: &mut EditorTestContext, include_nested: bool| {
+        cx.update_editor(|editor, window, cx| {
+            editor.go_to_next_method(&GoToNextMethod { include_nested }, window, cx)
+        });
+    };
+    let previous = |cx: &mut EditorTestContext, include_nested: bool| {
+        cx.update_editor(|editor, window, cx| {
+            editor.go_to_previous_method(&GoToPreviousMethod { include_nested }, window, cx)
+        });
+    };
+
+    // Nested symbols are skipped by default.
+    next(&mut cx, false);
+    cx.assert_editor_state(indoc! {"
+        fn a() {}
+
+        ˇfn b() {
+            fn inner() {}
+        }
+
+        impl S {
+            fn m() {}
+        }
+    "});
+    next(&mut cx, false);
+    cx.assert_editor_state(indoc! {"
+        fn a() {}
+
+        fn b() {
+            fn inner() {}
+        }
+
+        ˇimpl S {
+            fn m() {}
+        }
+    "});
+
+    // Wraps around at the end and the start of the file.
+    next(&mut cx, false);
+    cx.assert_editor_state(indoc! {"
+        ˇfn a() {}
+
+        fn b() {
+            fn inner() {}
+        }
+
+        impl S {
+            fn m() {}
+        }
+    "});
+    previous(&mut cx, false);
+    cx.assert_editor_state(indoc! {"
+        fn a() {}
+
+        fn b() {
+            fn inner() {}
+        }
+
+        ˇimpl S {
+            fn m() {}
+        }
+    "});
+
+    next(&mut cx, true);
+    cx.assert_editor_state(indoc! {"
+        fn a() {}
+
+        fn b() {
+            fn inner() {}
+        }
+
+        impl S {
+            ˇfn m() {}
+        }
+    "});
+    previous(&mut cx, true);
+    previous(&mut cx, true);
+    cx.assert_editor_state(indoc! {"
+        fn a() {}
+
+        fn b() {
+            ˇfn inner() {}
+        }
+
+        impl S {
+            fn m() {}
+        }
+    "});
+}
+
 #[gpui::test]
 fn test_select_line(cx: &mut TestAppContext) {
     init_test(cx, |_| {});
@@ -23330,6 +23582,62 @@ async fn test_goto_definition_no_fallback(cx: &mut TestAppContext) {
     });
 }
 
+#[gpui::test]
+async fn test_peek_definition(cx: &mut TestAppContext) {
+    init_test(cx, |_| {});
+    let mut cx = EditorLspTestContext::new_rust(
+        lsp::ServerCapabilities {
+            definition_provider: Some(lsp::OneOf::Left(true)),
+            ..lsp::ServerCapabilities::default()
+        },
+        cx,
+    )
+    .await;
+    let original_state = r#"fn one() {
+        let mut a = ˇtwo();
+    }
+
+    fn two() {}"#
+        .unindent();
+    cx.set_state(&original_state);
+
+    let mut go_to_definition = cx
+        .lsp
+        .set_request_handler::<lsp::request::GotoDefinition, _, _>(move |params, _| async move {
+            Ok(Some(lsp::GotoDefinitionResponse::Scalar(lsp::Location {
+                uri: params.text_document_position_params.text_document.uri,
+                range: lsp::Range::new(lsp::Position::new(4, 3), lsp::Position::new(4, 6)),
+            })))
+        });
+
+    cx.update_editor(|editor, window, cx| editor.peek_definition(&PeekDefinition, window, cx))
+        .await
+        .expect("Failed to peek definition");
+    go_to_definition
+        .next()
+        .await
+        .expect("Should have called the go_to_definition handler");
+
+    let peek_text = cx.update_editor(|editor, _, cx| {
+        editor
+            .peek_definition_state()
+            .expect("Peek view should be open")
+            .editor
+            .read(cx)
+            .text(cx)
+    });
+    assert!(
+        peek_text.contains("fn two() {}"),
+        "Peek view should show the definition, got {peek_text:?}"
+    );
+    // The cursor stays where it was.
+    cx.assert_editor_state(&original_state);
+
+    cx.update_editor(|editor, window, cx| editor.cancel(&Cancel, window, cx));
+    cx.update_editor(|editor, _, _| assert!(editor.peek_definition_state().is_none()));
+    cx.assert_editor_state(&original_state);
+}
+
 #[gpui::test]
 async fn test_find_all_references_editor_reuse(cx: &mut TestAppContext) {
     init_test(cx, |_| {});
@@ -295,6 +295,7 @@ impl EditorElement {
         register_action(editor, window, Editor::move_line_up);
         register_action(editor, window, Editor::move_line_down);
         register_action(editor, window, Editor::transpose);
+        register_action(editor, window, Editor::trim_trailing_whitespace);
         register_action(editor, window, Editor::rewrap);
         register_action(editor, window, Editor::cut);
         register_action(editor, window, Editor::kill_ring_cut);
@@ -373,6 +374,8 @@ impl EditorElement {
         register_action(editor, window, Editor::select_to_beginning);
         register_action(editor, window, Editor::select_to_end);
         register_action(editor, window, Editor::select_all);
+        register_action(editor, window, Editor::invert_selection);
+        register_action(editor, window, Editor::surround_selections);
         register_action(editor, window, |editor, action, window, cx| {
             editor.select_all_matches(action, window, cx).log_err();
         });
@@ -396,6 +399,8 @@ impl EditorElement {
         register_action(editor, window, Editor::toggle_comments);
         register_action(editor, window, Editor::select_larger_syntax_node);
         register_action(editor, window, Editor::select_smaller_syntax_node);
+        register_action(editor, window, Editor::grow_selection_by_word);
+        register_action(editor, window, Editor::shrink_selection_by_word);
         register_action(editor, window, Editor::select_next_syntax_node);
         register_action(editor, window, Editor::select_prev_syntax_node);
         register_action(
@@ -432,6 +437,11 @@ impl EditorElement {
                 .go_to_definition_split(action, window, cx)
                 .detach_and_log_err(cx);
         });
+        register_action(editor, window, |editor, action, window, cx| {
+            editor
+                .peek_definition(action, window, cx)
+                .detach_and_log_err(cx);
+        });
         register_action(editor, window, |editor, action, window, cx| {
             editor
                 .go_to_declaration(action, window, cx)
@@ -506,6 +516,9 @@ impl EditorElement {
         if editor.read(cx).inline_diagnostics_enabled() {
             register_action(editor, window, Editor::toggle_inline_diagnostics);
         }
+        if editor.read(cx).has_debug_session(cx) {
+            register_action(editor, window, Editor::toggle_inline_values);
+        }
         if editor.read(cx).supports_minimap(cx) {
             register_action(editor, window, Editor::toggle_minimap);
         }
@@ -536,6 +549,8 @@ impl EditorElement {
         register_action(editor, window, Editor::cancel_edit_review_comment_action);
         register_action(editor, window, Editor::go_to_previous_change);
         register_action(editor, window, Editor::go_to_next_change);
+        register_action(editor, window, Editor::go_to_previous_method);
+        register_action(editor, window, Editor::go_to_next_method);
         register_action(editor, window, Editor::go_to_prev_reference);
         register_action(editor, window, Editor::go_to_next_reference);
 
@@ -2204,6 +2219,9 @@ impl EditorElement {
         if minimap_settings.show == ShowMinimap::Auto && !scrollbars_shown {
             return None;
         }
+        if workspace::presentation_mode_active(cx) {
+            return None;
+        }
 
         let minimap_font_size = self.editor.read_with(cx, |editor, cx| {
             editor.minimap().map(|minimap_editor| {
@@ -19,7 +19,7 @@ pub mod inlay_hints;
 
 use std::sync::OnceLock;
 
-use gpui::{Context, HighlightStyle, Hsla, Rgba, Task};
+use gpui::{App, Context, HighlightStyle, Hsla, Rgba, Task};
 use multi_buffer::Anchor;
 use project::{InlayHint, InlayId};
 use text::Rope;
@@ -180,6 +180,18 @@ impl Editor {
         self.inline_value_cache.enabled
     }
 
+    pub fn has_debug_session(&self, cx: &App) -> bool {
+        self.project.as_ref().is_some_and(|project| {
+            project
+                .read(cx)
+                .dap_store()
+                .read(cx)
+                .sessions()
+                .next()
+                .is_some()
+        })
+    }
+
     #[cfg(any(test, feature = "test-support"))]
     pub fn inline_value_inlays(&self, cx: &gpui::App) -> Vec<Inlay> {
         self.display_map
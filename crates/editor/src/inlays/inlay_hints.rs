@@ -25,7 +25,8 @@ use util::debug_panic;
 
 use super::{Inlay, InlayId};
 use crate::{
-    Editor, EditorSnapshot, PointForPosition, ToggleInlayHints, ToggleInlineValues, debounce_value,
+    Editor, EditorEvent, EditorSnapshot, PointForPosition, ToggleInlayHints, ToggleInlineValues,
+    debounce_value,
     hover_links::{InlayHighlight, TriggerPoint, show_link_definition},
     hover_popover::{self, InlayHover},
     inlays::InlaySplice,
@@ -243,6 +244,7 @@ impl Editor {
         self.inline_value_cache.enabled = !self.inline_value_cache.enabled;
 
         self.refresh_inline_values(cx);
+        cx.emit(EditorEvent::InlineValuesToggled);
     }
 
     pub fn toggle_inlay_hints(
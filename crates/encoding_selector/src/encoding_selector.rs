@@ -5,15 +5,20 @@ use editor::Editor;
 use encoding_rs::Encoding;
 use fuzzy::{StringMatch, StringMatchCandidate, match_strings};
 use gpui::{
-    App, AppContext, Context, DismissEvent, Entity, EventEmitter, FocusHandle, Focusable,
+    Action, App, AppContext, Context, DismissEvent, Entity, EventEmitter, FocusHandle, Focusable,
     InteractiveElement, ParentElement, Render, Styled, Task, WeakEntity, Window, actions,
 };
 use language::Buffer;
 use picker::{Picker, PickerDelegate};
+use schemars::JsonSchema;
+use serde::Deserialize;
 use std::sync::Arc;
 use ui::{HighlightedLabel, ListItem, ListItemSpacing, Toggleable, v_flex};
 use util::ResultExt;
-use workspace::{ModalView, Toast, Workspace, notifications::NotificationId};
+use workspace::{
+    ModalView, SaveIntent, Toast, Workspace,
+    notifications::{DetachAndPromptErr, NotificationId},
+};
 
 actions!(
     encoding_selector,
@@ -23,6 +28,36 @@ actions!(
     ]
 );
 
+/// Reloads the active buffer from disk, decoding it with the given encoding.
+#[derive(PartialEq, Clone, Default, Debug, Deserialize, JsonSchema, Action)]
+#[action(namespace = encoding_selector)]
+#[serde(deny_unknown_fields)]
+pub struct ReopenWithEncoding {
+    /// The name of the encoding, such as `"GBK"` or `"Shift_JIS"`.
+    pub encoding: String,
+}
+
+/// Saves the active buffer, encoding it with the given encoding.
+#[derive(PartialEq, Clone, Default, Debug, Deserialize, JsonSchema, Action)]
+#[action(namespace = encoding_selector)]
+#[serde(deny_unknown_fields)]
+pub struct SaveWithEncoding {
+    /// The name of the encoding, such as `"GBK"` or `"Shift_JIS"`.
+    pub encoding: String,
+}
+
+/// The encodings offered in the File > Encoding menu, the most common ones
+/// for CJK text. The full list is available from [`Toggle`].
+pub fn menu_encodings() -> [&'static Encoding; 5] {
+    [
+        encoding_rs::UTF_8,
+        encoding_rs::GBK,
+        encoding_rs::SHIFT_JIS,
+        encoding_rs::EUC_KR,
+        encoding_rs::BIG5,
+    ]
+}
+
 pub fn init(cx: &mut App) {
     cx.observe_new(EncodingSelector::register).detach();
 }
@@ -40,6 +75,12 @@ impl EncodingSelector {
         workspace.register_action(move |workspace, _: &Toggle, window, cx| {
             Self::toggle(workspace, window, cx);
         });
+        workspace.register_action(move |workspace, action: &ReopenWithEncoding, _, cx| {
+            Self::reopen_with_encoding(workspace, &action.encoding, cx);
+        });
+        workspace.register_action(move |workspace, action: &SaveWithEncoding, window, cx| {
+            Self::save_with_encoding(workspace, &action.encoding, window, cx);
+        });
     }
 
     pub fn toggle(
@@ -47,6 +88,75 @@ impl EncodingSelector {
         window: &mut Window,
         cx: &mut Context<Workspace>,
     ) -> Option<()> {
+        let buffer = Self::active_buffer(workspace, true, cx)?;
+        workspace.toggle_modal(window, cx, move |window, cx| {
+            EncodingSelector::new(buffer, window, cx)
+        });
+        Some(())
+    }
+
+    /// Reloads the active buffer from disk, decoding it with `encoding`.
+    pub fn reopen_with_encoding(
+        workspace: &mut Workspace,
+        encoding: &str,
+        cx: &mut Context<Workspace>,
+    ) -> Option<()> {
+        let encoding = Self::encoding_for_label(workspace, encoding, cx)?;
+        let buffer = Self::active_buffer(workspace, true, cx)?;
+        buffer.update(cx, |buffer, cx| {
+            let _ = buffer.reload_with_encoding(encoding, cx);
+        });
+        Some(())
+    }
+
+    /// Saves the active buffer, encoding it with `encoding` from now on.
+    pub fn save_with_encoding(
+        workspace: &mut Workspace,
+        encoding: &str,
+        window: &mut Window,
+        cx: &mut Context<Workspace>,
+    ) -> Option<()> {
+        let encoding = Self::encoding_for_label(workspace, encoding, cx)?;
+        let buffer = Self::active_buffer(workspace, false, cx)?;
+        buffer.update(cx, |buffer, _| {
+            // A byte order mark only makes sense for the Unicode encodings.
+            buffer.set_has_bom(buffer.has_bom() && encoding == encoding_rs::UTF_8);
+            buffer.set_encoding(encoding);
+        });
+        workspace
+            .save_active_item(SaveIntent::Save, window, cx)
+            .detach_and_prompt_err("Failed to save", window, cx, |_, _, _| None);
+        Some(())
+    }
+
+    fn encoding_for_label(
+        workspace: &mut Workspace,
+        label: &str,
+        cx: &mut Context<Workspace>,
+    ) -> Option<&'static Encoding> {
+        let encoding = Encoding::for_label(label.as_bytes());
+        if encoding.is_none() {
+            workspace.show_toast(
+                Toast::new(
+                    NotificationId::unique::<EncodingSelector>(),
+                    format!("Unknown encoding {label:?}"),
+                ),
+                cx,
+            );
+        }
+        encoding
+    }
+
+    /// Returns the buffer of the active editor if its encoding can be changed,
+    /// showing a toast explaining why not otherwise.
+    ///
+    /// Reinterpreting the file on disk would discard unsaved edits, so
+    /// `require_saved` refuses dirty buffers.
+    fn active_buffer(
+        workspace: &mut Workspace,
+        require_saved: bool,
+        cx: &mut Context<Workspace>,
+    ) -> Option<Entity<Buffer>> {
         let (_, buffer, _) = workspace
             .active_item(cx)?
             .act_as::<Editor>(cx)?
@@ -56,7 +166,7 @@ impl EncodingSelector {
         let buffer_handle = buffer.read(cx);
         let project = workspace.project().read(cx);
 
-        if buffer_handle.is_dirty() {
+        if require_saved && buffer_handle.is_dirty() {
             workspace.show_toast(
                 Toast::new(
                     NotificationId::unique::<EncodingSelector>(),
@@ -64,7 +174,7 @@ impl EncodingSelector {
                 ),
                 cx,
             );
-            return Some(());
+            return None;
         }
         if project.is_shared() {
             workspace.show_toast(
@@ -74,7 +184,7 @@ impl EncodingSelector {
                 ),
                 cx,
             );
-            return Some(());
+            return None;
         }
         if project.is_via_remote_server() {
             workspace.show_toast(
@@ -84,13 +194,10 @@ impl EncodingSelector {
                 ),
                 cx,
             );
-            return Some(());
+            return None;
         }
 
-        workspace.toggle_modal(window, cx, move |window, cx| {
-            EncodingSelector::new(buffer, window, cx)
-        });
-        Some(())
+        Some(buffer)
     }
 
     fn new(buffer: Entity<Buffer>, window: &mut Window, cx: &mut Context<Self>) -> Self {
@@ -1,8 +1,10 @@
-use gpui::{App, ClipboardItem, PromptLevel, actions};
+use gpui::{App, ClipboardItem, PromptLevel, Window, actions};
 use system_specs::{CopySystemSpecsIntoClipboard, SystemSpecs};
 use util::ResultExt;
 use workspace::Workspace;
-use zed_actions::feedback::{EmailZed, FileBugReport, RequestFeature};
+use zed_actions::feedback::{
+    EmailZed, FileBugReport, IssueKind, OpenPrefilledIssue, RequestFeature,
+};
 
 actions!(
     zed,
@@ -14,21 +16,54 @@ actions!(
 
 const ZED_REPO_URL: &str = "https://github.com/zed-industries/zed";
 
-const REQUEST_FEATURE_URL: &str = "https://github.com/zed-industries/zed/discussions/new/choose";
-
-fn file_bug_report_url(specs: &SystemSpecs) -> String {
-    format!(
-        concat!(
+fn prefilled_issue_url(kind: IssueKind, body: &str) -> String {
+    let base = match kind {
+        IssueKind::BugReport => concat!(
             "https://github.com/zed-industries/zed/issues/new",
             "?",
             "template=10_bug_report.yml",
             "&",
-            "environment={}"
+            "environment="
         ),
-        urlencoding::encode(&specs.to_string())
+        IssueKind::FeatureRequest => concat!(
+            "https://github.com/zed-industries/zed/discussions/new",
+            "?",
+            "category=feature-requests",
+            "&",
+            "body="
+        ),
+    };
+    format!("{base}{}", urlencoding::encode(body))
+}
+
+/// The body of a pre-filled report: a template in the UI language, followed by
+/// the system information. The specs include the UI language code, so that
+/// maintainers know which language the report is written in.
+fn prefilled_issue_body(
+    kind: IssueKind,
+    translate: impl Fn(&str) -> String,
+    specs: &str,
+) -> String {
+    let template = match kind {
+        IssueKind::BugReport => translate("feedback.bug_report_template"),
+        IssueKind::FeatureRequest => translate("feedback.feature_request_template"),
+    };
+    format!(
+        "{template}\n\n### {}\n\n{specs}\n",
+        translate("feedback.environment"),
     )
 }
 
+fn open_prefilled_issue(kind: IssueKind, window: &mut Window, cx: &mut App) {
+    let specs = SystemSpecs::new(window, cx);
+    cx.spawn(async move |cx| {
+        let specs = specs.await.to_string();
+        let body = prefilled_issue_body(kind, i18n::t, &specs);
+        cx.update(|cx| cx.open_url(&prefilled_issue_url(kind, &body)));
+    })
+    .detach();
+}
+
 fn email_zed_url(specs: &SystemSpecs) -> String {
     format!(
         concat!("mailto:hi@zed.dev", "?", "body={}"),
@@ -65,19 +100,14 @@ pub fn init(cx: &mut App) {
                 })
                 .detach();
             })
-            .register_action(|_, _: &RequestFeature, _, cx| {
-                cx.open_url(REQUEST_FEATURE_URL);
+            .register_action(|_, _: &RequestFeature, window, cx| {
+                open_prefilled_issue(IssueKind::FeatureRequest, window, cx);
             })
-            .register_action(move |_, _: &FileBugReport, window, cx| {
-                let specs = SystemSpecs::new(window, cx);
-                cx.spawn_in(window, async move |_, cx| {
-                    let specs = specs.await;
-                    cx.update(|_, cx| {
-                        cx.open_url(&file_bug_report_url(&specs));
-                    })
-                    .log_err();
-                })
-                .detach();
+            .register_action(|_, _: &FileBugReport, window, cx| {
+                open_prefilled_issue(IssueKind::BugReport, window, cx);
+            })
+            .register_action(|_, action: &OpenPrefilledIssue, window, cx| {
+                open_prefilled_issue(action.kind, window, cx);
             })
             .register_action(move |_, _: &EmailZed, window, cx| {
                 let specs = SystemSpecs::new(window, cx);
@@ -96,3 +126,32 @@ pub fn init(cx: &mut App) {
     })
     .detach();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use i18n::Language;
+
+    #[test]
+    fn test_prefilled_issue_body_uses_current_language() {
+        let translations = i18n::bundled_translations(Language::SimplifiedChinese);
+        let translate = |key: &str| translations.get(key).cloned().unwrap();
+        let specs = "Zed: v1.0.0\nLanguage: zh-CN";
+
+        let body = prefilled_issue_body(IssueKind::BugReport, translate, specs);
+        assert!(body.starts_with(&translate("feedback.bug_report_template")));
+        assert!(body.contains(&translate("feedback.environment")));
+        assert!(body.contains(specs));
+        // The UI language comes from the system specs only
+        assert_eq!(body.matches("zh-CN").count(), 1);
+
+        let url = prefilled_issue_url(IssueKind::BugReport, &body);
+        assert!(url.contains("template=10_bug_report.yml"));
+        assert!(url.ends_with(&format!("&environment={}", urlencoding::encode(&body))));
+
+        let body = prefilled_issue_body(IssueKind::FeatureRequest, translate, specs);
+        assert!(body.starts_with(&translate("feedback.feature_request_template")));
+        let url = prefilled_issue_url(IssueKind::FeatureRequest, &body);
+        assert!(url.ends_with(&format!("&body={}", urlencoding::encode(&body))));
+    }
+}
@@ -327,6 +327,11 @@ impl TestAppContext {
         self.test_platform.opened_url.borrow().clone()
     }
 
+    /// The last path that was revealed with cx.reveal_path() during this test.
+    pub fn revealed_path(&self) -> Option<PathBuf> {
+        self.test_platform.revealed_path.borrow().clone()
+    }
+
     /// Simulates the user resizing the window to the new size.
     pub fn simulate_window_resize(&self, window_handle: AnyWindowHandle, size: Size<Pixels>) {
         self.test_window(window_handle).simulate_resize(size);
@@ -0,0 +1,60 @@
+//! 为可选的语言资源生成环境变量，指向要打包的文件
+//!
+//! 每个非默认语言的语言文件对应一个 `I18N_LOCALE_*`，术语表对应 `I18N_GLOSSARY`。
+//! 下游构建可能删掉部分文件，缺失的文件改为打包一个空的 `{}`：缺失的语言运行时回退到英文，
+//! 缺失的术语表不做检查，而不是让 `include_str!` 直接编译失败。英文是回退的终点，始终要求存在。
+
+use std::path::{Path, PathBuf};
+
+/// 非默认语言的代码及对应的环境变量名
+const OPTIONAL_LOCALES: &[(&str, &str)] = &[
+    ("zh-CN", "I18N_LOCALE_ZH_CN"),
+    ("zh-TW", "I18N_LOCALE_ZH_TW"),
+    ("ja", "I18N_LOCALE_JA"),
+    ("ko", "I18N_LOCALE_KO"),
+];
+
+fn main() {
+    let manifest_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(std::env::var("OUT_DIR").unwrap());
+    let locales_dir = manifest_dir.join("../../assets/locales");
+    println!("cargo:rerun-if-changed={}", locales_dir.display());
+
+    for (code, env_var) in OPTIONAL_LOCALES {
+        bundle_optional_file(
+            &locales_dir,
+            &out_dir,
+            &format!("{code}.json"),
+            env_var,
+            &format!("{code} will fall back to English"),
+        );
+    }
+    bundle_optional_file(
+        &locales_dir,
+        &out_dir,
+        "glossary.json",
+        "I18N_GLOSSARY",
+        "translations will not be checked against the glossary",
+    );
+}
+
+/// 让 `env_var` 指向 `locales_dir` 中的 `file_name`，文件缺失时警告并指向 `out_dir` 中的空文件
+fn bundle_optional_file(
+    locales_dir: &Path,
+    out_dir: &Path,
+    file_name: &str,
+    env_var: &str,
+    fallback: &str,
+) {
+    let path = locales_dir.join(file_name);
+    println!("cargo:rerun-if-changed={}", path.display());
+    let path = if path.exists() {
+        path
+    } else {
+        println!("cargo:warning={file_name} is missing, {fallback}");
+        let empty = out_dir.join(file_name);
+        std::fs::write(&empty, "{}").unwrap();
+        empty
+    };
+    println!("cargo:rustc-env={env_var}={}", path.display());
+}
@@ -0,0 +1,157 @@
+//! 术语表检查
+//!
+//! 术语表记录常用英文术语在各语言中的标准译法。英文原文包含某个术语的条目，
+//! 其译文也应该包含该术语的标准译法，否则同一个术语会在界面上出现多种译法。
+
+use crate::{Language, Translations};
+use std::collections::HashMap;
+
+/// 术语表：英文术语到各语言标准译法的映射，语言以 [`Language::as_str`] 的代码作为键
+pub(crate) type Glossary = HashMap<String, HashMap<String, String>>;
+
+/// 译文没有使用术语的标准译法
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlossaryViolation {
+    /// 出问题的翻译键
+    pub key: String,
+    /// 英文原文中出现的术语
+    pub term: String,
+    /// 术语表中的标准译法
+    pub expected: String,
+    /// 当前的译文
+    pub actual: String,
+}
+
+/// 随程序打包的术语表，构建时缺失或解析失败时返回空表
+pub(crate) fn bundled_glossary() -> Glossary {
+    serde_json::from_str(include_str!(env!("I18N_GLOSSARY"))).unwrap_or_else(|error| {
+        log::error!("failed to parse bundled glossary: {error}");
+        Glossary::default()
+    })
+}
+
+/// 以英文原文为参照检查 `translations` 中的术语译法，结果按键和术语排序
+///
+/// 缺少译文或译文与英文相同（尚未翻译）的条目不在检查范围内，这些由翻译差异统计负责。
+pub(crate) fn check(
+    lang: Language,
+    glossary: &Glossary,
+    english: &Translations,
+    translations: &Translations,
+) -> Vec<GlossaryViolation> {
+    let terms = glossary
+        .iter()
+        .filter_map(|(term, renderings)| {
+            let expected = renderings.get(lang.as_str())?;
+            Some((term, words(term), expected))
+        })
+        .collect::<Vec<_>>();
+    if terms.is_empty() {
+        return Vec::new();
+    }
+
+    let mut violations = Vec::new();
+    for (key, source) in english {
+        let Some(actual) = translations.get(key) else {
+            continue;
+        };
+        if actual == source {
+            continue;
+        }
+        let source_words = words(source);
+        for (term, term_words, expected) in &terms {
+            if contains_words(&source_words, term_words) && !actual.contains(expected.as_str()) {
+                violations.push(GlossaryViolation {
+                    key: key.clone(),
+                    term: (*term).clone(),
+                    expected: (*expected).clone(),
+                    actual: actual.clone(),
+                });
+            }
+        }
+    }
+    violations.sort_by(|a, b| a.key.cmp(&b.key).then_with(|| a.term.cmp(&b.term)));
+    violations
+}
+
+/// 按非字母数字字符切分并转为小写，保留单词顺序以便匹配多个单词组成的术语
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+fn contains_words(text: &[String], term: &[String]) -> bool {
+    !term.is_empty() && text.windows(term.len()).any(|window| window == term)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translations(entries: &[(&str, &str)]) -> Translations {
+        entries
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_inconsistent_term_is_reported() {
+        let glossary = Glossary::from_iter([(
+            "Settings".to_string(),
+            HashMap::from_iter([("zh-CN".to_string(), "设置".to_string())]),
+        )]);
+        let english = translations(&[
+            ("menu.settings", "Settings"),
+            ("menu.open_settings", "Open Settings"),
+            ("menu.open_default_settings", "Open Default Settings"),
+            ("menu.settings_sync", "Settings Sync"),
+            ("menu.open_file", "Open File"),
+        ]);
+        let current = translations(&[
+            ("menu.settings", "设置"),
+            ("menu.open_settings", "打开设定"),
+            ("menu.open_default_settings", "打开默认设置"),
+            // 尚未翻译的条目由翻译差异统计负责
+            ("menu.settings_sync", "Settings Sync"),
+            ("menu.open_file", "打开文件"),
+        ]);
+
+        assert_eq!(
+            check(Language::SimplifiedChinese, &glossary, &english, &current),
+            vec![GlossaryViolation {
+                key: "menu.open_settings".to_string(),
+                term: "Settings".to_string(),
+                expected: "设置".to_string(),
+                actual: "打开设定".to_string(),
+            }]
+        );
+        // 术语表中没有该语言的译法时不做检查
+        assert!(check(Language::Japanese, &glossary, &english, &current).is_empty());
+    }
+
+    #[test]
+    fn test_terms_match_whole_words() {
+        let glossary = Glossary::from_iter([(
+            "Tab".to_string(),
+            HashMap::from_iter([("zh-CN".to_string(), "标签页".to_string())]),
+        )]);
+        let english = translations(&[("menu.table", "Insert Table")]);
+        let current = translations(&[("menu.table", "插入表格")]);
+        assert!(check(Language::SimplifiedChinese, &glossary, &english, &current).is_empty());
+    }
+
+    #[test]
+    fn test_bundled_locales_follow_bundled_glossary() {
+        let glossary = bundled_glossary();
+        assert!(!glossary.is_empty());
+        let english = crate::bundled_translations(Language::English);
+        for lang in Language::all() {
+            let current = crate::bundled_translations(*lang);
+            let violations = check(*lang, &glossary, &english, &current);
+            assert!(violations.is_empty(), "{lang:?}: {violations:#?}");
+        }
+    }
+}
@@ -1,12 +1,33 @@
-use gpui::App;
-use once_cell::sync::OnceCell;
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+mod glossary;
+mod interpolate;
+mod language_settings;
+mod number_format;
+mod plural;
+mod po;
+
+use fs::Fs;
+use futures::{StreamExt as _, channel::mpsc};
+pub use glossary::GlossaryViolation;
+use gpui::{App, SharedString, Subscription};
+use interpolate::{Args, interpolate};
+pub use language_settings::UiLanguageSettings;
+use number_format::NumberFormat;
+use once_cell::sync::{Lazy, OnceCell};
+pub use plural::PluralCategory;
+use plural::{plural_form_category, plural_key};
+pub use po::PoParseError;
+use serde::{Deserialize, Deserializer, Serialize};
+use settings::{Settings as _, SettingsStore};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
 use std::sync::Mutex;
 use std::sync::RwLock;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 
 /// 支持的语言列表
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+///
+/// 变体的声明顺序即界面上的显示顺序，`Ord` 和 [`Language::all`] 都遵循这个顺序。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum Language {
     #[serde(rename = "en")]
     English,
@@ -24,14 +45,47 @@ impl Language {
     pub fn from_str(s: &str) -> Option<Self> {
         match s {
             "en" => Some(Language::English),
-            "zh-CN" | "zh_cn" | "zh" => Some(Language::SimplifiedChinese),
-            "zh-TW" | "zh_tw" => Some(Language::TraditionalChinese),
+            "zh-CN" | "zh_cn" | "zh" | "zh-Hans" => Some(Language::SimplifiedChinese),
+            "zh-TW" | "zh_tw" | "zh-Hant" | "zh-HK" | "zh-MO" => Some(Language::TraditionalChinese),
             "ja" => Some(Language::Japanese),
             "ko" => Some(Language::Korean),
             _ => None,
         }
     }
 
+    /// 系统界面语言，无法识别时使用英文
+    ///
+    /// 通过 `sys_locale` 读取平台的语言设置：macOS 上是首选语言列表的第一项，
+    /// Linux 上是 `LC_ALL`、`LANG` 等环境变量，Windows 上是用户默认的界面语言。
+    pub fn from_system() -> Self {
+        sys_locale::get_locale()
+            .and_then(|locale| Self::from_locale(&locale))
+            .unwrap_or(Language::English)
+    }
+
+    /// 将系统 locale（如 `zh_CN.UTF-8`、`zh-Hant-TW`、`ja-JP`）解析为支持的语言
+    ///
+    /// 去掉编码和修饰符后，从最具体到最宽泛依次交给 [`Language::from_str`] 匹配，
+    /// 例如 `zh-Hant-TW`、`zh-Hant`、`zh`。
+    fn from_locale(locale: &str) -> Option<Self> {
+        let tag = locale.split(['.', '@']).next()?;
+        let subtags = tag.split(['-', '_']).collect::<Vec<_>>();
+        (1..=subtags.len())
+            .rev()
+            .find_map(|len| Self::from_str(&subtags[..len].join("-")))
+    }
+
+    /// 所有支持的语言
+    pub fn all() -> &'static [Language] {
+        &[
+            Language::English,
+            Language::SimplifiedChinese,
+            Language::TraditionalChinese,
+            Language::Japanese,
+            Language::Korean,
+        ]
+    }
+
     pub fn as_str(&self) -> &'static str {
         match self {
             Language::English => "en",
@@ -41,52 +95,269 @@ impl Language {
             Language::Korean => "ko",
         }
     }
+
+    /// 语言用自身文字书写的名称，用于语言选择和读屏播报
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::SimplifiedChinese => "简体中文",
+            Language::TraditionalChinese => "繁體中文",
+            Language::Japanese => "日本語",
+            Language::Korean => "한국어",
+        }
+    }
+}
+
+impl Language {
+    /// 界面文字行高的倍数，面板和菜单渲染文字时乘到默认行高上
+    ///
+    /// 中日韩文字的字形占满整个字框，稍大的行距读起来不那么拥挤。
+    pub fn ui_line_height_multiplier(&self) -> f32 {
+        match self {
+            Language::English => 1.0,
+            Language::SimplifiedChinese
+            | Language::TraditionalChinese
+            | Language::Japanese
+            | Language::Korean => 1.15,
+        }
+    }
+
+    /// 这种语言的文字是否通常通过输入法组字输入
+    ///
+    /// 组字期间输入框中的是未确认的拼音、假名或字母，按输入内容实时处理的界面应等待确认。
+    pub fn uses_ime(&self) -> bool {
+        match self {
+            Language::English => false,
+            Language::SimplifiedChinese
+            | Language::TraditionalChinese
+            | Language::Japanese
+            | Language::Korean => true,
+        }
+    }
 }
 
 /// 翻译数据
 pub type Translations = HashMap<String, String>;
 
+/// 翻译条目数少于英文的 1/N 时视为接近为空
+const NEAR_EMPTY_RATIO: usize = 10;
+
+/// 语言文件的内容：翻译条目加上可选的 `@metadata` 元数据
+#[derive(Debug, Default, Deserialize)]
+struct LocaleFile {
+    #[serde(rename = "@metadata", default)]
+    metadata: LocaleMetadata,
+    #[serde(flatten, deserialize_with = "deserialize_translations")]
+    translations: Translations,
+}
+
+/// 语言文件中的一个条目：普通字符串，或嵌套的对象
+///
+/// 嵌套对象的键全部是 `#` 加复数类别（如 `#one`）时视为复数条目，否则视为分组，
+/// 加载时展开为以 `.` 连接的键，如 `{"menu": {"file": {"save": "Save"}}}` 即 `menu.file.save`。
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LocaleEntry {
+    Text(String),
+    Nested(HashMap<String, LocaleEntry>),
+}
+
+/// 读取翻译条目，分组展开为带点的键，复数条目的每个形式展开为 `键#类别` 的独立条目
+fn deserialize_translations<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Translations, D::Error> {
+    let mut translations = Translations::new();
+    for (key, entry) in HashMap::<String, LocaleEntry>::deserialize(deserializer)? {
+        flatten_entry(key, entry, &mut translations);
+    }
+    Ok(translations)
+}
+
+fn flatten_entry(key: String, entry: LocaleEntry, translations: &mut Translations) {
+    match entry {
+        LocaleEntry::Text(text) => {
+            translations.insert(key, text);
+        }
+        LocaleEntry::Nested(children) if is_plural_entry(&children) => {
+            for (form_key, form) in children {
+                if let (Some(category), LocaleEntry::Text(text)) =
+                    (plural_form_category(&form_key), form)
+                {
+                    translations.insert(plural_key(&key, category), text);
+                }
+            }
+        }
+        LocaleEntry::Nested(children) => {
+            for (child, entry) in children {
+                flatten_entry(format!("{key}.{child}"), entry, translations);
+            }
+        }
+    }
+}
+
+/// 对象的键全部是 `#` 加复数类别、值全部是字符串时才是复数条目
+///
+/// 要求显式的 `#` 前缀，子键恰好名为 `one`、`other` 的分组不会被误当作复数条目。
+fn is_plural_entry(children: &HashMap<String, LocaleEntry>) -> bool {
+    !children.is_empty()
+        && children.iter().all(|(form_key, form)| {
+            plural_form_category(form_key).is_some() && matches!(form, LocaleEntry::Text(_))
+        })
+}
+
+/// 语言文件中与翻译条目平行的元数据
+#[derive(Debug, Default, Deserialize)]
+struct LocaleMetadata {
+    /// 标记为待审校的键（例如机器翻译），与 gettext 的 fuzzy 标记含义相同
+    ///
+    /// 待审校的条目照常用于显示，只是在覆盖率统计中单独计数。
+    #[serde(default)]
+    fuzzy: HashSet<String>,
+}
+
+impl LocaleFile {
+    fn parse(json: &str) -> Self {
+        serde_json::from_str(json).unwrap_or_default()
+    }
+}
+
 /// 全局翻译管理器
-static I18N_MANAGER: OnceCell<Mutex<I18nManager>> = OnceCell::new();
+///
+/// 查找翻译只获取读锁，多个线程可以同时翻译；只有切换语言和合并翻译时才获取写锁。
+/// 锁中毒时查找函数与未初始化时一样返回原始键。
+static I18N_MANAGER: OnceCell<RwLock<I18nManager>> = OnceCell::new();
+
+/// 翻译开关，关闭后所有翻译函数直接返回原始键
+static TRANSLATION_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// 语言代数，每次切换语言或翻译变化时递增，缓存据此判断条目是否过期
+static LANGUAGE_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// 向读屏工具播报文本的回调
+pub type Announcer = Box<dyn Fn(&str) + Send + Sync>;
+
+/// 已安装的播报回调，None 表示没有活动的辅助技术，切换语言时不播报
+static ANNOUNCER: RwLock<Option<Announcer>> = RwLock::new(None);
 
 #[derive(Debug)]
 pub struct I18nManager {
     current_language: Language,
     translations: HashMap<Language, Translations>,
+    fuzzy: HashMap<Language, HashSet<String>>,
 }
 
 impl I18nManager {
     pub fn new() -> Self {
         let mut translations = HashMap::new();
-    
+        let mut fuzzy = HashMap::new();
+
         // 加载所有语言的翻译
-        for lang in [
-            Language::English,
-            Language::SimplifiedChinese,
-            Language::TraditionalChinese,
-            Language::Japanese,
-            Language::Korean,
-        ] {
-            translations.insert(lang, Self::load_translations(lang));
+        for &lang in Language::all() {
+            let file = Self::load_locale_file(lang);
+            translations.insert(lang, file.translations);
+            fuzzy.insert(lang, file.metadata.fuzzy);
         }
-    
+
+        let mut manager = Self::with_translations(Language::from_system(), translations);
+        manager.fuzzy = fuzzy;
+        manager
+    }
+
+    fn with_translations(
+        current_language: Language,
+        mut translations: HashMap<Language, Translations>,
+    ) -> Self {
+        let english_len = translations
+            .get(&Language::English)
+            .map_or(0, |translations| translations.len());
+        // 空的翻译表直接移除，这样查找时会立即回退到英文，而不是显示原始键
+        translations
+            .retain(|lang, translations| Self::check_coverage(*lang, translations, english_len));
+
         Self {
-            current_language: Language::SimplifiedChinese,
+            current_language,
             translations,
+            fuzzy: HashMap::new(),
         }
     }
+
+    /// 检查翻译表是否为空或接近为空，并记录警告
+    ///
+    /// 返回 `false` 表示该语言的翻译表为空，应当交给回退链处理
+    fn check_coverage(lang: Language, translations: &Translations, english_len: usize) -> bool {
+        if translations.is_empty() {
+            log::warn!(
+                "locale file for {} contains no translations, falling back to English",
+                lang.as_str()
+            );
+            return false;
+        }
+        if lang != Language::English && translations.len() * NEAR_EMPTY_RATIO < english_len {
+            log::warn!(
+                "locale file for {} only translates {} of {} keys",
+                lang.as_str(),
+                translations.len(),
+                english_len
+            );
+        }
+        true
+    }
+
     fn load_translations(lang: Language) -> Translations {
+        Self::load_locale_file(lang).translations
+    }
+
+    fn load_locale_file(lang: Language) -> LocaleFile {
         match lang {
-            Language::English => Self::load_from_json(include_str!("../../assets/locales/en.json")),
-            Language::SimplifiedChinese => Self::load_from_json(include_str!("../../assets/locales/zh-CN.json")),
-            Language::TraditionalChinese => Self::load_from_json(include_str!("../../assets/locales/zh-TW.json")),
-            Language::Japanese => Self::load_from_json(include_str!("../../assets/locales/ja.json")),
-            Language::Korean => Self::load_from_json(include_str!("../../assets/locales/ko.json")),
+            Language::English => LocaleFile::parse(include_str!("../../assets/locales/en.json")),
+            // 其他语言的文件由 build.rs 定位，构建时缺失的语言打包为空表，运行时回退到英文
+            Language::SimplifiedChinese => {
+                LocaleFile::parse(include_str!(env!("I18N_LOCALE_ZH_CN")))
+            }
+            Language::TraditionalChinese => {
+                LocaleFile::parse(include_str!(env!("I18N_LOCALE_ZH_TW")))
+            }
+            Language::Japanese => LocaleFile::parse(include_str!(env!("I18N_LOCALE_JA"))),
+            Language::Korean => LocaleFile::parse(include_str!(env!("I18N_LOCALE_KO"))),
         }
     }
 
-    fn load_from_json(json: &str) -> Translations {
-        serde_json::from_str(json).unwrap_or_default()
+    /// 加载目录中的翻译文件（如 `zh-CN.json`），文件中的键覆盖已有的翻译
+    ///
+    /// 只覆盖文件中出现的键，其余键仍使用内置翻译。文件覆盖的键视为已审校，
+    /// 除非它在文件的元数据中仍被标记为待审校。目录或文件不存在时跳过，
+    /// 无法解析的文件记录警告后跳过，对应语言继续使用内置翻译。
+    pub fn load_from_dir(&mut self, dir: &Path) {
+        for &lang in Language::all() {
+            let path = dir.join(format!("{}.json", lang.as_str()));
+            let Ok(json) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            match serde_json::from_str::<LocaleFile>(&json) {
+                Ok(overrides) => {
+                    log::info!(
+                        "loaded {} translation overrides from {}",
+                        overrides.translations.len(),
+                        path.display()
+                    );
+                    let fuzzy = self.fuzzy.entry(lang).or_default();
+                    fuzzy.retain(|key| !overrides.translations.contains_key(key));
+                    fuzzy.extend(overrides.metadata.fuzzy);
+                    self.merge(lang, overrides.translations);
+                }
+                Err(error) => log::warn!("failed to parse {}: {error}", path.display()),
+            }
+        }
+    }
+
+    /// 将 `overrides` 叠加到某个语言已有的翻译上，同一个键以 `overrides` 为准
+    ///
+    /// 内置翻译、语言包和用户覆盖等各层翻译都通过这里合并，按调用顺序后合并的层优先，
+    /// 未出现在 `overrides` 中的键保持不变。合并后通过 [`reset_all_caches`] 清空翻译缓存，
+    /// 以免继续返回旧的翻译。
+    pub fn merge(&mut self, lang: Language, overrides: Translations) {
+        self.translations.entry(lang).or_default().extend(overrides);
+        reset_all_caches();
     }
 
     pub fn set_language(&mut self, lang: Language) {
@@ -97,67 +368,695 @@ impl I18nManager {
         self.current_language
     }
 
+    /// 用当前语言描述“语言已切换为当前语言”的播报文本
+    ///
+    /// 安装了播报回调时，[`set_language`] 会通过 [`set_announcer`] 安装的回调播报这段文本。
+    pub fn language_changed_announcement(&self) -> String {
+        self.translate_with_args(
+            "i18n.language_changed",
+            &[self.current_language.native_name()],
+        )
+    }
+
+    /// 翻译 `key`，当前语言缺少该键时回退到英文，英文也没有时才返回键本身
+    ///
+    /// 部分翻译的语言（如 `ko`）中未翻译的条目会显示英文，而不是 `menu.zoom_in` 这样的键。
     pub fn translate(&self, key: &str) -> String {
-        self.translations
-            .get(&self.current_language)
-            .and_then(|trans| trans.get(key))
+        [self.current_language, Language::English]
+            .iter()
+            .find_map(|lang| self.translations.get(lang)?.get(key))
             .cloned()
             .unwrap_or_else(|| key.to_string())
     }
 
     pub fn translate_with_args(&self, key: &str, args: &[&str]) -> String {
-        let mut result = self.translate(key);
-        for (i, arg) in args.iter().enumerate() {
-            result = result.replace(&format!("{{{}}}", i), arg);
+        interpolate(&self.translate(key), &Args::Positional(args))
+    }
+
+    /// 翻译 `key`，模板中的 `{name}` 替换为同名参数的值
+    ///
+    /// 参数按名称而不是位置匹配，译文可以按目标语言的语序自由排列占位符。
+    /// 名称为数字的参数（如 `("0", path)`）替换 `{0}`，位置和命名占位符可以出现在同一个模板中。
+    pub fn translate_with_named(&self, key: &str, args: &[(&str, &str)]) -> String {
+        interpolate(&self.translate(key), &Args::Named(args))
+    }
+
+    /// 按 `count` 选择复数条目 `key` 的形式并翻译
+    ///
+    /// 依据当前语言的 CLDR 复数规则选择类别，缺少该类别时使用 `other`。当前语言没有这个条目时
+    /// 与 [`translate`](Self::translate) 一样回退到英文，并按英文的规则重新选择类别。
+    /// 模板中的 `{count}` 替换为按语言格式化的数量，`{0}`、`{1}` ... 替换为 `args`。
+    pub fn translate_plural(&self, key: &str, count: i64, args: &[&str]) -> String {
+        let Some((lang, template)) = [self.current_language, Language::English]
+            .into_iter()
+            .find_map(|lang| Some((lang, self.plural_template(lang, key, count)?)))
+        else {
+            return key.to_string();
+        };
+
+        let count = self.number_format(lang).format_number(count);
+        let indices = (0..args.len())
+            .map(|index| index.to_string())
+            .collect::<Vec<_>>();
+        let named = std::iter::once(("count", count.as_str()))
+            .chain(indices.iter().map(String::as_str).zip(args.iter().copied()))
+            .collect::<Vec<_>>();
+        interpolate(template, &Args::Named(&named))
+    }
+
+    // 返回 `lang` 中复数条目 `key` 适用于 `count` 的形式
+    fn plural_template(&self, lang: Language, key: &str, count: i64) -> Option<&String> {
+        let translations = self.translations.get(&lang)?;
+        let category = PluralCategory::for_count(lang, count);
+        [category, PluralCategory::Other]
+            .iter()
+            .find_map(|category| translations.get(&plural_key(key, category.as_str())))
+    }
+
+    /// 某个语言格式化数字和字节数使用的符号和单位，来自该语言的 `format.*` 条目
+    ///
+    /// 与 [`translate`](Self::translate) 一样，该语言缺少的条目回退到英文。
+    fn number_format(&self, lang: Language) -> NumberFormat {
+        NumberFormat::from_lookup(|key| {
+            [lang, Language::English]
+                .iter()
+                .find_map(|lang| self.translations.get(lang)?.get(key))
+                .cloned()
+        })
+    }
+
+    /// 该语言的 `key` 是否被标记为待审校
+    pub fn is_fuzzy(&self, lang: Language, key: &str) -> bool {
+        self.fuzzy
+            .get(&lang)
+            .is_some_and(|fuzzy| fuzzy.contains(key))
+    }
+}
+
+/// 返回某个语言推荐的默认设置
+///
+/// 目前只包含 CJK 字体回退，英文没有额外的推荐设置。
+pub fn locale_default_settings(lang: Language) -> serde_json::Value {
+    let font_fallbacks: &[&str] = match lang {
+        Language::English => return serde_json::json!({}),
+        Language::SimplifiedChinese => &["PingFang SC", "Microsoft YaHei", "Noto Sans CJK SC"],
+        Language::TraditionalChinese => &["PingFang TC", "Microsoft JhengHei", "Noto Sans CJK TC"],
+        Language::Japanese => &["Hiragino Sans", "Yu Gothic", "Noto Sans CJK JP"],
+        Language::Korean => &["Apple SD Gothic Neo", "Malgun Gothic", "Noto Sans CJK KR"],
+    };
+    serde_json::json!({
+        "ui_font_fallbacks": font_fallbacks,
+        "buffer_font_fallbacks": font_fallbacks,
+    })
+}
+
+/// 将语言推荐的默认设置合并进用户设置，用户已经设置的键始终优先
+pub fn apply_locale_default_settings(settings: &mut serde_json::Value, lang: Language) {
+    merge_missing(settings, locale_default_settings(lang));
+}
+
+fn merge_missing(target: &mut serde_json::Value, defaults: serde_json::Value) {
+    let (serde_json::Value::Object(target), serde_json::Value::Object(defaults)) =
+        (target, defaults)
+    else {
+        return;
+    };
+    for (key, value) in defaults {
+        match target.get_mut(&key) {
+            Some(existing) => merge_missing(existing, value),
+            None => {
+                target.insert(key, value);
+            }
         }
-        result
     }
 }
 
+/// 当前语言格式化数字使用的符号和单位，i18n 尚未初始化时使用英文的格式
+fn current_number_format() -> NumberFormat {
+    I18N_MANAGER
+        .get()
+        .and_then(|m| m.read().ok())
+        .map(|m| m.number_format(m.get_language()))
+        .unwrap_or_default()
+}
+
+/// 按当前语言的小数点符号格式化浮点数
+pub fn format_float(value: f64, precision: usize) -> String {
+    current_number_format().format_float(value, precision)
+}
+
+/// 按当前语言的千位分组符号格式化整数，例如 `1,234,567`
+pub fn format_number(value: i64) -> String {
+    current_number_format().format_number(value)
+}
+
+/// 将字节数格式化为带当前语言单位的字符串，例如 `1.5 MB`
+pub fn format_bytes(bytes: u64) -> String {
+    current_number_format().format_bytes(bytes)
+}
+
 /// 初始化 i18n 系统
-pub fn init(_cx: &mut App) {
-    let manager = I18nManager::new();
-    I18N_MANAGER.set(Mutex::new(manager)).unwrap();
+///
+/// 初始语言为设置中保存的 `ui_language`，没有保存时使用系统语言，系统语言不受支持时使用英文。
+pub fn init(cx: &mut App) {
+    let mut manager = I18nManager::new();
+    manager.load_from_dir(paths::locales_dir());
+    I18N_MANAGER.set(RwLock::new(manager)).unwrap();
+    // 初始化之前创建的视图显示的是原始键，通知订阅者用翻译重新渲染
+    reset_all_caches();
+    notify_subscribers(get_language());
+
+    // 用户设置文件在 init 之后才加载完成，设置变化时切换到保存的语言
+    let mut applied = None;
+    apply_language_setting(&mut applied, cx);
+    cx.observe_global::<SettingsStore>(move |cx| apply_language_setting(&mut applied, cx))
+        .detach();
+}
+
+// 设置中的语言与上次应用的不同时切换语言，设置被删除时恢复为系统语言
+fn apply_language_setting(applied: &mut Option<Language>, cx: &App) {
+    let setting = UiLanguageSettings::get_global(cx).ui_language;
+    if setting == *applied {
+        return;
+    }
+    *applied = setting;
+    let lang = setting.unwrap_or_else(Language::from_system);
+    if lang != get_language() {
+        set_language(lang);
+    }
+}
+
+/// 切换界面语言并保存到用户设置，下次启动时恢复
+pub fn persist_language(lang: Language, cx: &mut App) {
+    set_language(lang);
+    let value = language_settings::setting_value(lang);
+    settings::update_settings_file(<dyn Fs>::global(cx), cx, move |settings, _| {
+        settings.ui_language = Some(value);
+    });
+}
+
+/// 只用随程序打包的翻译初始化翻译管理器，供测试使用
+///
+/// 与 [`init`] 不同，可以重复调用，也不需要注册设置。
+#[cfg(any(test, feature = "test-support"))]
+pub fn init_test() {
+    I18N_MANAGER.get_or_init(|| RwLock::new(I18nManager::new()));
+}
+
+/// 翻译管理器是否已经通过 [`init`] 初始化
+///
+/// 未初始化时所有翻译函数都会返回原始键。
+pub fn is_initialized() -> bool {
+    I18N_MANAGER.get().is_some()
 }
 
 /// 设置当前语言
+///
+/// 安装了播报回调时，会用新语言播报“语言已切换为 …”。
 pub fn set_language(lang: Language) {
+    let should_announce = ANNOUNCER
+        .read()
+        .map_or(false, |announcer| announcer.is_some());
+    let mut announcement = None;
     if let Some(manager) = I18N_MANAGER.get() {
-        if let Ok(mut m) = manager.lock() {
+        if let Ok(mut m) = manager.write() {
             m.set_language(lang);
+            if should_announce {
+                announcement = Some(m.language_changed_announcement());
+            }
+        }
+    }
+    // 清空缓存并递增代数，各缓存不会再返回旧语言的条目
+    reset_all_caches();
+    notify_subscribers(lang);
+    // 释放翻译管理器的锁之后再调用回调，回调中可以继续使用翻译函数
+    if let Some(announcement) = announcement {
+        announce(&announcement);
+    }
+}
+
+/// 安装或移除读屏播报回调
+///
+/// gpui 目前没有向读屏工具播报的接口，由平台集成提供回调。平台集成应只在检测到辅助技术
+/// 处于活动状态时安装回调，并在其退出时传入 `None`，没有回调时切换语言不会产生任何播报。
+pub fn set_announcer(announcer: Option<Announcer>) {
+    if let Ok(mut slot) = ANNOUNCER.write() {
+        *slot = announcer;
+    }
+}
+
+// 通过已安装的回调播报文本
+fn announce(message: &str) {
+    if let Ok(announcer) = ANNOUNCER.read() {
+        if let Some(announcer) = announcer.as_ref() {
+            announcer(message);
+        }
+    }
+}
+
+/// 语言切换的订阅者，每次切换都向其发送新的语言
+static SUBSCRIBERS: Mutex<Vec<mpsc::UnboundedSender<Language>>> = Mutex::new(Vec::new());
+
+/// 订阅语言切换，每次调用 [`set_language`] 后都以新语言调用 `callback`
+///
+/// `set_language` 可以在任何线程上调用，回调不会在其中同步执行，而是在主线程上运行，
+/// 所以回调中可以更新实体，例如调用 `cx.notify()` 让视图用新语言重新渲染。
+/// 返回的订阅被丢弃后不再调用回调，调用 [`Subscription::detach`] 则在整个应用生命周期内保持订阅。
+pub fn subscribe(cx: &mut App, callback: impl Fn(Language, &mut App) + 'static) -> Subscription {
+    let (tx, mut rx) = mpsc::unbounded();
+    let unsubscribe = tx.clone();
+    let closed = tx.clone();
+    if let Ok(mut subscribers) = SUBSCRIBERS.lock() {
+        subscribers.push(tx);
+    }
+    // 监听任务本身不随订阅一起丢弃，否则分离的订阅会立即失效
+    cx.spawn(async move |cx| {
+        while let Some(lang) = rx.next().await {
+            // 订阅丢弃前已经发出、尚未处理的通知同样忽略
+            if closed.is_closed() {
+                break;
+            }
+            cx.update(|cx| callback(lang, cx));
+        }
+    })
+    .detach();
+    // 关闭通道后任务随即退出，下次通知时该订阅者也会被移除
+    Subscription::new(move || unsubscribe.close_channel())
+}
+
+// 向所有订阅者发送新语言，同时移除订阅已经被丢弃的订阅者
+fn notify_subscribers(lang: Language) {
+    if let Ok(mut subscribers) = SUBSCRIBERS.lock() {
+        subscribers.retain(|tx| tx.unbounded_send(lang).is_ok());
+    }
+}
+
+/// 返回当前的语言代数
+pub fn language_generation() -> u64 {
+    LANGUAGE_GENERATION.load(Ordering::Relaxed)
+}
+
+/// 按语言代数失效的缓存
+///
+/// 每个条目记录写入时的代数，读取时代数不一致即视为过期并移除。
+/// 切换语言只需递增代数，不必清空整个缓存，仍在使用的条目会在下次访问时按需重建。
+#[derive(Debug)]
+struct GenerationCache<V> {
+    entries: HashMap<String, (u64, V)>,
+}
+
+impl<V: Clone> GenerationCache<V> {
+    fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+
+    fn get(&mut self, key: &str, generation: u64) -> Option<V> {
+        match self.entries.get(key) {
+            Some((entry_generation, value)) if *entry_generation == generation => {
+                Some(value.clone())
+            }
+            Some(_) => {
+                self.entries.remove(key);
+                None
+            }
+            None => None,
         }
     }
-    // 清理静态缓存以便下次获取新语言的翻译
-    clear_static_cache();
+
+    fn insert(&mut self, key: String, generation: u64, value: V) {
+        self.entries.insert(key, (generation, value));
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+    }
 }
 
 /// 获取当前语言
 pub fn get_language() -> Language {
     I18N_MANAGER
         .get()
-        .and_then(|m| m.lock().ok())
+        .and_then(|m| m.read().ok())
         .map(|m| m.get_language())
         .unwrap_or(Language::English)
 }
 
+/// 所有可供选择的界面语言，顺序与 [`Language::all`] 相同
+pub fn available_languages() -> &'static [Language] {
+    Language::all()
+}
+
+/// 开启或关闭翻译
+///
+/// 关闭后 `t`、`t_args` 和 `t_static` 都会原样返回键，便于定位界面文字对应的键，
+/// 或者截取不受语言影响的截图。切换语言不受影响，重新开启后立即恢复正常查找。
+pub fn set_translation_enabled(enabled: bool) {
+    TRANSLATION_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// 翻译当前是否开启
+pub fn translation_enabled() -> bool {
+    TRANSLATION_ENABLED.load(Ordering::Relaxed)
+}
+
+/// 返回某个语言随程序打包的翻译表，不包含用户目录中的覆盖
+pub fn bundled_translations(lang: Language) -> Translations {
+    I18nManager::load_translations(lang)
+}
+
+/// 返回某个语言当前已加载的翻译表副本
+pub fn snapshot(lang: Language) -> Translations {
+    I18N_MANAGER
+        .get()
+        .and_then(|m| m.read().ok())
+        .and_then(|m| m.translations.get(&lang).cloned())
+        .unwrap_or_default()
+}
+
+/// 某个语言的 `key` 是否被标记为待审校（fuzzy）
+///
+/// 待审校的条目仍然正常显示，只在翻译预览和覆盖率统计中单独计数。
+pub fn is_fuzzy(lang: Language, key: &str) -> bool {
+    I18N_MANAGER
+        .get()
+        .and_then(|m| m.read().ok())
+        .is_some_and(|m| m.is_fuzzy(lang, key))
+}
+
+/// 返回某个语言当前被标记为待审校的键
+pub fn fuzzy_keys(lang: Language) -> HashSet<String> {
+    I18N_MANAGER
+        .get()
+        .and_then(|m| m.read().ok())
+        .and_then(|m| m.fuzzy.get(&lang).cloned())
+        .unwrap_or_default()
+}
+
+/// 将某个语言当前加载的翻译导出为 gettext `.po` 格式，`msgid` 为英文原文
+pub fn export_po(lang: Language) -> String {
+    po::to_po(lang, &snapshot(Language::English), &snapshot(lang))
+}
+
+/// 将 `.po` 文件中已翻译的条目合并进该语言的翻译表，返回导入的条目数
+///
+/// 文件中的键覆盖已有翻译，其余键保持不变。i18n 尚未初始化时不会导入任何条目。
+pub fn import_po(lang: Language, po: &str) -> Result<usize, PoParseError> {
+    let translations = po::parse_po(po)?;
+    let Some(mut manager) = I18N_MANAGER.get().and_then(|m| m.write().ok()) else {
+        return Ok(0);
+    };
+    let count = translations.len();
+    manager.merge(lang, translations);
+    Ok(count)
+}
+
+/// 相似度低于该值的条目不作为翻译建议
+const SIMILARITY_THRESHOLD: f32 = 0.3;
+
+/// 查找英文原文与 `english` 相似的已有翻译，返回 `(英文, 译文)`，最相似的排在最前
+///
+/// 相似度按单词集合的重合程度（Jaccard 系数）计算，忽略大小写和标点。
+pub fn similar_translations(lang: Language, english: &str) -> Vec<(String, String)> {
+    similar_translations_in(&snapshot(Language::English), &snapshot(lang), english)
+}
+
+fn similar_translations_in(
+    english: &Translations,
+    current: &Translations,
+    source: &str,
+) -> Vec<(String, String)> {
+    let source_words = words(source);
+    if source_words.is_empty() {
+        return Vec::new();
+    }
+
+    let mut matches = english
+        .iter()
+        .filter_map(|(key, text)| {
+            let translated = current.get(key)?;
+            let text_words = words(text);
+            let shared = source_words.intersection(&text_words).count();
+            let similarity = shared as f32 / source_words.union(&text_words).count() as f32;
+            (similarity >= SIMILARITY_THRESHOLD)
+                .then(|| (similarity, text.clone(), translated.clone()))
+        })
+        .collect::<Vec<_>>();
+    matches.sort_by(|a, b| b.0.total_cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+    matches.dedup_by(|a, b| a.1 == b.1 && a.2 == b.2);
+    matches
+        .into_iter()
+        .map(|(_, text, translated)| (text, translated))
+        .collect()
+}
+
+fn words(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// 检查某个语言当前加载的翻译是否使用了术语表中的标准译法
+///
+/// 英文原文包含术语、但译文没有使用对应标准译法的条目会被报告，结果按键排序。
+pub fn check_glossary(lang: Language) -> Vec<GlossaryViolation> {
+    glossary::check(
+        lang,
+        &glossary::bundled_glossary(),
+        &snapshot(Language::English),
+        &snapshot(lang),
+    )
+}
+
+/// 两种语言翻译表之间的差异，所有键都按字母顺序排列
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TranslationDiff {
+    /// 只在 A 中出现的键
+    pub only_in_a: Vec<String>,
+    /// 只在 B 中出现的键
+    pub only_in_b: Vec<String>,
+    /// 两边都有、但 B 的值与 A 完全相同的键，通常是直接复制过来还没有翻译
+    pub untranslated: Vec<String>,
+    /// 两边都有、但 B 中被标记为待审校的键，不计入 `untranslated`
+    pub fuzzy: Vec<String>,
+}
+
+impl TranslationDiff {
+    pub fn is_empty(&self) -> bool {
+        self.only_in_a.is_empty()
+            && self.only_in_b.is_empty()
+            && self.untranslated.is_empty()
+            && self.fuzzy.is_empty()
+    }
+}
+
+/// 比较两种语言当前加载的翻译表，用于以 A 为参照审查 B 的翻译
+pub fn translation_diff(a: Language, b: Language) -> TranslationDiff {
+    translation_diff_in(&snapshot(a), &snapshot(b), &fuzzy_keys(b))
+}
+
+fn translation_diff_in(
+    a: &Translations,
+    b: &Translations,
+    fuzzy_in_b: &HashSet<String>,
+) -> TranslationDiff {
+    let mut diff = TranslationDiff::default();
+    for (key, value) in a {
+        match b.get(key) {
+            None => diff.only_in_a.push(key.clone()),
+            Some(_) if fuzzy_in_b.contains(key) => diff.fuzzy.push(key.clone()),
+            Some(other) if other == value => diff.untranslated.push(key.clone()),
+            Some(_) => {}
+        }
+    }
+    diff.only_in_b = b
+        .keys()
+        .filter(|key| !a.contains_key(*key))
+        .cloned()
+        .collect();
+    diff.only_in_a.sort();
+    diff.only_in_b.sort();
+    diff.untranslated.sort();
+    diff.fuzzy.sort();
+    diff
+}
+
+/// 是否记录每个键被解析的次数
+static ACCESS_RECORDING: AtomicBool = AtomicBool::new(false);
+
+/// 本次会话中每个键被解析的次数，只在开启记录时更新
+static ACCESS_COUNTS: Lazy<Mutex<AccessCounts>> = Lazy::new(|| Mutex::new(AccessCounts::default()));
+
+/// 键的解析次数统计
+#[derive(Debug, Default)]
+struct AccessCounts {
+    counts: HashMap<String, u64>,
+}
+
+impl AccessCounts {
+    fn record(&mut self, key: &str) {
+        match self.counts.get_mut(key) {
+            Some(count) => *count += 1,
+            None => {
+                self.counts.insert(key.to_string(), 1);
+            }
+        }
+    }
+
+    /// 解析次数最多的 `n` 个键，次数相同时按键排序
+    fn hottest(&self, n: usize) -> Vec<(String, u64)> {
+        let mut keys = self
+            .counts
+            .iter()
+            .map(|(key, count)| (key.clone(), *count))
+            .collect::<Vec<_>>();
+        keys.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        keys.truncate(n);
+        keys
+    }
+}
+
+/// 开启或关闭解析次数的记录，开启时清空之前的统计
+///
+/// 关闭时每次翻译只多一次原子读取。统计结果可以用来决定启动时预先缓存哪些键，
+/// 也能发现渲染代码中多余的重复查找。
+pub fn set_access_recording(enabled: bool) {
+    if enabled {
+        if let Ok(mut counts) = ACCESS_COUNTS.lock() {
+            counts.counts.clear();
+        }
+    }
+    ACCESS_RECORDING.store(enabled, Ordering::Relaxed);
+}
+
+/// 开启记录以来解析次数最多的 `n` 个键及其次数，从多到少排列
+pub fn hot_keys(n: usize) -> Vec<(String, u64)> {
+    ACCESS_COUNTS
+        .lock()
+        .map(|counts| counts.hottest(n))
+        .unwrap_or_default()
+}
+
+fn record_access(key: &str) {
+    if !ACCESS_RECORDING.load(Ordering::Relaxed) {
+        return;
+    }
+    if let Ok(mut counts) = ACCESS_COUNTS.lock() {
+        counts.record(key);
+    }
+}
+
 /// 翻译函数 - 简单版本
 pub fn t(key: &str) -> String {
+    record_access(key);
+    lookup(key)
+}
+
+fn lookup(key: &str) -> String {
     I18N_MANAGER
         .get()
-        .and_then(|m| m.lock().ok())
+        .filter(|_| translation_enabled())
+        .and_then(|m| m.read().ok())
         .map(|m| m.translate(key))
         .unwrap_or_else(|| key.to_string())
 }
 
 /// 翻译函数 - 带参数版本
 pub fn t_args(key: &str, args: &[&str]) -> String {
+    record_access(key);
     I18N_MANAGER
         .get()
-        .and_then(|m| m.lock().ok())
+        .filter(|_| translation_enabled())
+        .and_then(|m| m.read().ok())
         .map(|m| m.translate_with_args(key, args))
         .unwrap_or_else(|| key.to_string())
 }
 
+/// 翻译函数 - 命名参数版本，模板中的 `{name}` 替换为同名参数的值
+pub fn t_named(key: &str, args: &[(&str, &str)]) -> String {
+    record_access(key);
+    I18N_MANAGER
+        .get()
+        .filter(|_| translation_enabled())
+        .and_then(|m| m.read().ok())
+        .map(|m| m.translate_with_named(key, args))
+        .unwrap_or_else(|| key.to_string())
+}
+
+/// 翻译函数 - 复数版本，按 `count` 选择复数条目的形式，见 [`I18nManager::translate_plural`]
+pub fn t_plural(key: &str, count: i64, args: &[&str]) -> String {
+    record_access(key);
+    I18N_MANAGER
+        .get()
+        .filter(|_| translation_enabled())
+        .and_then(|m| m.read().ok())
+        .map(|m| m.translate_plural(key, count, args))
+        .unwrap_or_else(|| key.to_string())
+}
+
+/// `t_shared` 已经返回过的字符串，同一语言下重复调用共享同一份引用计数的内容
+static SHARED_VALUES: Lazy<Mutex<GenerationCache<SharedString>>> =
+    Lazy::new(|| Mutex::new(GenerationCache::new()));
+
+/// 翻译函数 - 返回 `SharedString`，供 gpui 渲染代码使用
+///
+/// 同一语言下重复调用返回同一份缓存的字符串，克隆只增加引用计数，每帧渲染不会重新分配，
+/// 也不需要像 [`t_static`] 那样泄漏内存。切换语言后缓存按语言代数自动失效。
+pub fn t_shared(key: &str) -> SharedString {
+    record_access(key);
+    // 未初始化或关闭翻译时返回的是原始键，不写入缓存，以免之后仍然返回原始键
+    if !is_initialized() || !translation_enabled() {
+        return SharedString::from(key.to_string());
+    }
+    cached_shared(&SHARED_VALUES, key, language_generation(), lookup)
+}
+
+/// `t_shared` 的带参数版本，参数每次都不同，只有模板会被缓存
+pub fn t_shared_args(key: &str, args: &[&str]) -> SharedString {
+    interpolate(&t_shared(key), &Args::Positional(args)).into()
+}
+
+// 翻译时不持有缓存的锁：翻译需要获取翻译管理器的锁，而 `I18nManager::merge`
+// 会在持有翻译管理器的锁时清空缓存，两者交叉持有会死锁
+fn cached_shared(
+    cache: &Mutex<GenerationCache<SharedString>>,
+    key: &str,
+    generation: u64,
+    translate: impl FnOnce(&str) -> String,
+) -> SharedString {
+    if let Some(value) = cache
+        .lock()
+        .ok()
+        .and_then(|mut cache| cache.get(key, generation))
+    {
+        return value;
+    }
+    let value = SharedString::from(translate(key));
+    if let Ok(mut cache) = cache.lock() {
+        cache.insert(key.to_string(), generation, value.clone());
+    }
+    value
+}
+
+/// 批量翻译，按顺序返回每个键的翻译
+///
+/// 只获取一次锁，适合一次性解析整个菜单这类大量的键。
+pub fn t_all<'a>(keys: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let manager = I18N_MANAGER
+        .get()
+        .filter(|_| translation_enabled())
+        .and_then(|m| m.read().ok());
+    keys.into_iter()
+        .inspect(|key| record_access(key))
+        .map(|key| match &manager {
+            Some(manager) => manager.translate(key),
+            None => key.to_string(),
+        })
+        .collect()
+}
+
 /// 宏版本 - 更简洁的使用方式
 #[macro_export]
 macro_rules! t {
@@ -169,80 +1068,1079 @@ macro_rules! t {
     };
 }
 
-/// 静态字符串缓存，用于返回 &'static str
-static STATIC_TRANSLATIONS: OnceCell<RwLock<HashMap<String, String>>> = OnceCell::new();
+/// 复数的宏版本，`t_plural!("key", count, arg0, arg1)` 等同于
+/// `t_plural("key", count, &[arg0, arg1])`
+#[macro_export]
+macro_rules! t_plural {
+    ($key:expr, $count:expr $(, $arg:expr)* $(,)?) => {
+        $crate::t_plural($key, $count, &[$($arg),*])
+    };
+}
+
+/// 命名参数的宏版本，`t_named!("key", count = n, name = file)` 等同于
+/// `t_named("key", &[("count", n), ("name", file)])`
+///
+/// 参数名也可以是数字，如 `t_named!("key", 0 = path, count = n)`，
+/// 用于同时包含位置和命名占位符的模板。
+#[macro_export]
+macro_rules! t_named {
+    ($key:expr, $($name:tt = $value:expr),* $(,)?) => {
+        $crate::t_named($key, &[$((stringify!($name), $value)),*])
+    };
+}
 
-/// 初始化静态翻译缓存
-fn init_static_translations() {
-    let mut cache = HashMap::new();
-    
-    // 加载所有语言的翻译到缓存
-    let en_translations = load_from_json_static(include_str!("../../assets/locales/en.json"));
-    let zh_cn_translations = load_from_json_static(include_str!("../../assets/locales/zh-CN.json"));
-    let zh_tw_translations = load_from_json_static(include_str!("../../assets/locales/zh-TW.json"));
-    let ja_translations = load_from_json_static(include_str!("../../assets/locales/ja.json"));
-    let ko_translations = load_from_json_static(include_str!("../../assets/locales/ko.json"));
-    
-    for (key, value) in en_translations {
-        cache.insert(format!("en:{}", key), value);
-    }
-    for (key, value) in zh_cn_translations {
-        cache.insert(format!("zh-CN:{}", key), value);
+/// 通过 [`tr_key!`] 标记的翻译键
+#[doc(hidden)]
+pub struct TranslationKey(pub &'static str);
+
+inventory::collect!(TranslationKey);
+
+/// 宏内部使用
+#[doc(hidden)]
+pub mod __private {
+    pub use inventory;
+}
+
+/// 标记翻译键以供提取工具收集，展开后就是键本身的字面量
+///
+/// 注册发生在链接期，运行时没有任何额外开销。
+#[macro_export]
+macro_rules! tr_key {
+    ($key:literal) => {{
+        $crate::__private::inventory::submit! {
+            $crate::TranslationKey($key)
+        }
+        $key
+    }};
+}
+
+/// 返回代码中所有通过 [`tr_key!`] 标记的翻译键
+pub fn registered_keys() -> impl Iterator<Item = &'static str> {
+    inventory::iter::<TranslationKey>
+        .into_iter()
+        .map(|key| key.0)
+}
+
+/// `t_static` 已经返回过的字符串，同一语言下重复调用不会再次泄漏
+static STATIC_VALUES: Lazy<Mutex<GenerationCache<&'static str>>> =
+    Lazy::new(|| Mutex::new(GenerationCache::new()));
+
+/// `t_static` 已经泄漏出去的字符串，以内容为键
+///
+/// 相同内容只泄漏一次：重复调用、切换语言后再切回来或清空缓存后重新翻译，都返回同一个引用，
+/// 泄漏的总量以出现过的不同译文数量为上限，不会随调用次数增长。
+static INTERNED_STRINGS: Lazy<RwLock<HashMap<String, &'static str>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// `t_static` 泄漏的字符串数量
+static LEAKED_STRINGS: AtomicUsize = AtomicUsize::new(0);
+
+fn leak(value: String) -> &'static str {
+    LEAKED_STRINGS.fetch_add(1, Ordering::Relaxed);
+    Box::leak(value.into_boxed_str())
+}
+
+/// 返回 `t_static` 至今泄漏的字符串数量，用于发现在渲染路径上反复调用 `t_static` 的代码
+pub fn leaked_string_count() -> usize {
+    LEAKED_STRINGS.load(Ordering::Relaxed)
+}
+
+/// 翻译函数 - 返回 &'static str，用于需要静态字符串的场景
+/// 注意：这个函数会返回缓存的字符串引用，所以字符串内容不会改变直到语言切换
+///
+/// 译文与 [`t`] 一样来自翻译管理器，语言包和用户覆盖同样生效。
+pub fn t_static(key: &str) -> &'static str {
+    record_access(key);
+    if !translation_enabled() {
+        return intern(&INTERNED_STRINGS, key.to_string());
     }
-    for (key, value) in zh_tw_translations {
-        cache.insert(format!("zh-TW:{}", key), value);
+
+    let generation = language_generation();
+    if let Some(value) = STATIC_VALUES
+        .lock()
+        .ok()
+        .and_then(|mut values| values.get(key, generation))
+    {
+        return value;
     }
-    for (key, value) in ja_translations {
-        cache.insert(format!("ja:{}", key), value);
+
+    let value = intern(&INTERNED_STRINGS, lookup(key));
+    if let Ok(mut values) = STATIC_VALUES.lock() {
+        values.insert(key.to_string(), generation, value);
     }
-    for (key, value) in ko_translations {
-        cache.insert(format!("ko:{}", key), value);
+    value
+}
+
+// 返回与 `value` 内容相同的静态字符串，每种内容只泄漏一次
+//
+// 为了返回 &'static str，只能把字符串泄漏到静态内存中。
+// 能接受 SharedString 的地方（如 Panel::icon_tooltip）应改用 `t_shared`。
+fn intern(interned: &RwLock<HashMap<String, &'static str>>, value: String) -> &'static str {
+    if let Some(interned) = interned
+        .read()
+        .ok()
+        .and_then(|interned| interned.get(&value).copied())
+    {
+        return interned;
     }
-    
-    STATIC_TRANSLATIONS.set(RwLock::new(cache)).ok();
+
+    let Ok(mut interned) = interned.write() else {
+        return leak(value);
+    };
+    // 获取写锁之前其他线程可能已经泄漏过同一个字符串
+    *interned
+        .entry(value)
+        .or_insert_with_key(|value| leak(value.clone()))
 }
 
-/// 加载静态翻译
-fn load_from_json_static(json: &str) -> HashMap<String, String> {
-    serde_json::from_str(json).unwrap_or_default()
+/// 立即清空所有翻译结果的缓存（目前是 `t_static` 和 `t_shared` 的缓存）
+///
+/// 在运行时替换翻译的嵌入方调用一次即可，不会有旧的翻译留在任何缓存中。
+/// 切换语言和合并翻译时已经会调用，新增缓存时也应在这里一并清空。
+/// `t_static` 泄漏的字符串以内容为键，不对应任何键或语言，所以不需要清空。
+pub fn reset_all_caches() {
+    // 同时持有所有缓存的锁再清空，其他线程不会看到部分缓存已清空、部分仍是旧值的状态
+    let static_values = STATIC_VALUES.lock();
+    let shared_values = SHARED_VALUES.lock();
+    // 递增代数，清空前已经开始翻译、之后才写回缓存的旧值也会被视为过期
+    LANGUAGE_GENERATION.fetch_add(1, Ordering::Relaxed);
+    if let Ok(mut values) = static_values {
+        values.clear();
+    }
+    if let Ok(mut values) = shared_values {
+        values.clear();
+    }
 }
 
-/// 翻译函数 - 返回 &'static str，用于需要静态字符串的场景
-/// 注意：这个函数会返回缓存的字符串引用，所以字符串内容不会改变直到语言切换
-pub fn t_static(key: &str) -> &'static str {
-    // 确保静态缓存已初始化
-    if STATIC_TRANSLATIONS.get().is_none() {
-        init_static_translations();
-    }
-    
-    let cache = STATIC_TRANSLATIONS.get().unwrap();
-    
-    // 获取当前语言的键
-    let lang_key = format!("{}:{}", get_language().as_str(), key);
-    
-    // 优先查找带语言前缀的键
-    if let Ok(cache) = cache.read() {
-        if let Some(value) = cache.get(&lang_key) {
-            // 这是一个 hack，但为了返回 &'static str，我们需要确保字符串在静态内存中
-            // 在实际使用中，我们应该重构 trait 定义来返回 String
-            // 临时方案：使用 Box::leak（注意：这会造成内存泄漏，仅用于演示）
-            // 更好的方案是修改 trait 定义
-            return Box::leak(value.clone().into_boxed_str());
-        }
-        
-        // 回退到原始键
-        if let Some(value) = cache.get(key) {
-            return Box::leak(value.clone().into_boxed_str());
-        }
-    }
-    
-    // 如果找不到翻译，返回键本身
-    Box::leak(key.to_string().into_boxed_str())
-}
-
-/// 清理静态翻译缓存（当语言改变时调用）
-pub fn clear_static_cache() {
-    if let Some(cache) = STATIC_TRANSLATIONS.get() {
-        let _ = cache.write().map(|mut c| c.clear());
-    }
-}
\ No newline at end of file
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        cell::RefCell,
+        rc::Rc,
+        sync::{Arc, MutexGuard, PoisonError},
+    };
+
+    // 修改全局语言、翻译开关或缓存的测试持有这把锁，避免并行运行时互相干扰
+    static GLOBAL_STATE: Mutex<()> = Mutex::new(());
+
+    /// 独占全局的 i18n 状态直到返回的锁被释放
+    ///
+    /// 持有锁的测试失败时锁会中毒，其他测试仍然可以继续使用。
+    fn lock_global_state() -> MutexGuard<'static, ()> {
+        GLOBAL_STATE.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn translations(entries: &[(&str, &str)]) -> Translations {
+        entries
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_empty_locale_falls_back_to_english() {
+        let mut manager = I18nManager::with_translations(
+            Language::Japanese,
+            HashMap::from_iter([
+                (
+                    Language::English,
+                    translations(&[("menu.save", "Save"), ("menu.open", "Open")]),
+                ),
+                (Language::Japanese, Translations::new()),
+            ]),
+        );
+        assert_eq!(manager.translate("menu.save"), "Save");
+        assert_eq!(manager.translate_with_args("menu.open", &[]), "Open");
+        assert_eq!(manager.translate("menu.missing"), "menu.missing");
+
+        manager.set_language(Language::English);
+        assert_eq!(manager.translate("menu.save"), "Save");
+    }
+
+    #[test]
+    fn test_missing_key_falls_back_to_english() {
+        // 日文翻译表缺少 menu.zoom_in
+        let manager = I18nManager::with_translations(
+            Language::Japanese,
+            HashMap::from_iter([
+                (
+                    Language::English,
+                    translations(&[("menu.save", "Save"), ("menu.zoom_in", "Zoom In {0}")]),
+                ),
+                (Language::Japanese, translations(&[("menu.save", "保存")])),
+            ]),
+        );
+        assert_eq!(manager.translate("menu.save"), "保存");
+        assert_eq!(manager.translate("menu.zoom_in"), "Zoom In {0}");
+        assert_eq!(
+            manager.translate_with_args("menu.zoom_in", &["2x"]),
+            "Zoom In 2x"
+        );
+        assert_eq!(manager.translate("menu.missing"), "menu.missing");
+    }
+
+    #[test]
+    fn test_translate_with_args_handles_literal_braces() {
+        let manager = I18nManager::with_translations(
+            Language::English,
+            HashMap::from_iter([(
+                Language::English,
+                translations(&[
+                    ("test.snippet", "Wrap {0} in {{ }}"),
+                    ("test.literal", "Use {{0}} for the first argument, e.g. {0}"),
+                    ("test.pair", "{0} then {1}"),
+                ]),
+            )]),
+        );
+        assert_eq!(
+            manager.translate_with_args("test.snippet", &["x"]),
+            "Wrap x in { }"
+        );
+        assert_eq!(
+            manager.translate_with_args("test.literal", &["a.rs"]),
+            "Use {0} for the first argument, e.g. a.rs"
+        );
+        // 参数值中的占位符文本原样输出，不影响后续替换
+        assert_eq!(
+            manager.translate_with_args("test.pair", &["{1}", "b"]),
+            "{1} then b"
+        );
+    }
+
+    #[test]
+    fn test_static_translation_uses_manager_translations() {
+        let _lock = lock_global_state();
+        let manager = I18N_MANAGER.get_or_init(|| RwLock::new(I18nManager::new()));
+        set_translation_enabled(true);
+        set_language(Language::Korean);
+        manager.write().unwrap().merge(
+            Language::English,
+            HashMap::from_iter([("test.static_override".to_string(), "Override".to_string())]),
+        );
+
+        // 合并进管理器的翻译同样生效，当前语言缺少该键时回退到英文
+        assert_eq!(t_static("test.static_override"), "Override");
+        assert_eq!(t_static("test.static_missing"), "test.static_missing");
+
+        // 再次合并后不会留下旧的译文
+        manager.write().unwrap().merge(
+            Language::Korean,
+            HashMap::from_iter([("test.static_override".to_string(), "덮어쓰기".to_string())]),
+        );
+        assert_eq!(t_static("test.static_override"), "덮어쓰기");
+        set_language(Language::English);
+    }
+
+    #[test]
+    fn test_locale_substituted_at_build_time_falls_back_to_english() {
+        // build.rs 为缺失的语言文件打包的内容
+        let substituted = LocaleFile::parse("{}");
+        assert!(substituted.translations.is_empty());
+
+        let manager = I18nManager::with_translations(
+            Language::Korean,
+            HashMap::from_iter([
+                (
+                    Language::English,
+                    I18nManager::load_translations(Language::English),
+                ),
+                (Language::Korean, substituted.translations),
+            ]),
+        );
+        assert_eq!(manager.translate("menu.save"), "Save");
+    }
+
+    #[test]
+    fn test_load_from_dir_overrides_bundled_translations() {
+        let mut manager = I18nManager::with_translations(
+            Language::SimplifiedChinese,
+            HashMap::from_iter([
+                (
+                    Language::English,
+                    translations(&[("menu.save", "Save"), ("menu.open", "Open")]),
+                ),
+                (
+                    Language::SimplifiedChinese,
+                    translations(&[("menu.save", "保存"), ("menu.open", "打开")]),
+                ),
+            ]),
+        );
+
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("zh-CN.json"), r#"{"menu.save": "存盘"}"#).unwrap();
+        std::fs::write(dir.path().join("ja.json"), "not json").unwrap();
+        manager.load_from_dir(dir.path());
+
+        assert_eq!(manager.translate("menu.save"), "存盘");
+        assert_eq!(manager.translate("menu.open"), "打开");
+        manager.set_language(Language::English);
+        assert_eq!(manager.translate("menu.save"), "Save");
+
+        // 无法解析的文件和不存在的目录都被跳过，继续使用内置翻译
+        manager.load_from_dir(&dir.path().join("missing"));
+        manager.set_language(Language::Japanese);
+        assert_eq!(manager.translate("menu.save"), "Save");
+    }
+
+    #[test]
+    fn test_later_merges_take_precedence() {
+        let mut manager = I18nManager::with_translations(
+            Language::SimplifiedChinese,
+            HashMap::from_iter([(
+                Language::SimplifiedChinese,
+                translations(&[
+                    ("menu.save", "保存"),
+                    ("menu.open", "打开"),
+                    ("menu.close", "关闭"),
+                ]),
+            )]),
+        );
+
+        // 语言包覆盖内置翻译，用户覆盖再覆盖语言包
+        manager.merge(
+            Language::SimplifiedChinese,
+            translations(&[("menu.save", "储存"), ("menu.open", "开启")]),
+        );
+        manager.merge(
+            Language::SimplifiedChinese,
+            translations(&[("menu.save", "存盘")]),
+        );
+
+        assert_eq!(manager.translate("menu.save"), "存盘");
+        assert_eq!(manager.translate("menu.open"), "开启");
+        assert_eq!(manager.translate("menu.close"), "关闭");
+    }
+
+    #[test]
+    fn test_fuzzy_entries_are_counted_separately() {
+        let file = LocaleFile::parse(
+            r#"{
+                "@metadata": { "fuzzy": ["menu.open", "menu.close"] },
+                "menu.save": "保存",
+                "menu.open": "开く",
+                "menu.close": "Close"
+            }"#,
+        );
+        let english = translations(&[
+            ("menu.save", "Save"),
+            ("menu.open", "Open"),
+            ("menu.close", "Close"),
+            ("menu.find", "Find"),
+        ]);
+        let mut manager = I18nManager::with_translations(
+            Language::Japanese,
+            HashMap::from_iter([
+                (Language::English, english.clone()),
+                (Language::Japanese, file.translations.clone()),
+            ]),
+        );
+        manager
+            .fuzzy
+            .insert(Language::Japanese, file.metadata.fuzzy.clone());
+
+        // 待审校的条目照常显示
+        assert_eq!(manager.translate("menu.open"), "开く");
+        assert!(manager.is_fuzzy(Language::Japanese, "menu.open"));
+        assert!(manager.is_fuzzy(Language::Japanese, "menu.close"));
+        assert!(!manager.is_fuzzy(Language::Japanese, "menu.save"));
+        assert!(!manager.is_fuzzy(Language::English, "menu.open"));
+
+        let diff = translation_diff_in(&english, &file.translations, &file.metadata.fuzzy);
+        assert_eq!(diff.only_in_a, vec!["menu.find".to_string()]);
+        assert_eq!(diff.untranslated, Vec::<String>::new());
+        assert_eq!(
+            diff.fuzzy,
+            vec!["menu.close".to_string(), "menu.open".to_string()]
+        );
+
+        // 用户文件覆盖的键视为已审校
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("ja.json"), r#"{"menu.open": "開く"}"#).unwrap();
+        manager.load_from_dir(dir.path());
+        assert!(!manager.is_fuzzy(Language::Japanese, "menu.open"));
+        assert!(manager.is_fuzzy(Language::Japanese, "menu.close"));
+    }
+
+    #[test]
+    fn test_language_from_locale() {
+        for (locale, expected) in [
+            ("en-US", Some(Language::English)),
+            ("en_GB.UTF-8", Some(Language::English)),
+            ("zh_CN.UTF-8", Some(Language::SimplifiedChinese)),
+            ("zh-Hans-CN", Some(Language::SimplifiedChinese)),
+            ("zh-Hant-TW", Some(Language::TraditionalChinese)),
+            ("zh_TW.Big5", Some(Language::TraditionalChinese)),
+            ("zh-HK", Some(Language::TraditionalChinese)),
+            ("ja-JP", Some(Language::Japanese)),
+            ("ko_KR.UTF-8@euro", Some(Language::Korean)),
+            ("fr-FR", None),
+            ("C", None),
+            ("", None),
+        ] {
+            assert_eq!(Language::from_locale(locale), expected, "{locale}");
+        }
+    }
+
+    #[test]
+    fn test_cjk_languages_use_taller_lines() {
+        assert_eq!(Language::English.ui_line_height_multiplier(), 1.0);
+        for lang in Language::all()
+            .iter()
+            .filter(|lang| **lang != Language::English)
+        {
+            assert!(
+                lang.ui_line_height_multiplier() > Language::English.ui_line_height_multiplier()
+            );
+        }
+    }
+
+    #[test]
+    fn test_locale_default_settings() {
+        assert_eq!(
+            locale_default_settings(Language::English),
+            serde_json::json!({})
+        );
+        let defaults = locale_default_settings(Language::Japanese);
+        assert_eq!(defaults["ui_font_fallbacks"][0], "Hiragino Sans");
+
+        let mut settings = serde_json::json!({
+            "ui_font_fallbacks": ["My Font"],
+            "theme": "One Dark",
+        });
+        apply_locale_default_settings(&mut settings, Language::Japanese);
+        assert_eq!(
+            settings,
+            serde_json::json!({
+                "ui_font_fallbacks": ["My Font"],
+                "buffer_font_fallbacks": ["Hiragino Sans", "Yu Gothic", "Noto Sans CJK JP"],
+                "theme": "One Dark",
+            })
+        );
+    }
+
+    #[test]
+    fn test_language_ordering_matches_display_order() {
+        let mut languages = vec![
+            Language::Korean,
+            Language::English,
+            Language::Japanese,
+            Language::TraditionalChinese,
+            Language::SimplifiedChinese,
+        ];
+        languages.sort();
+        assert_eq!(languages, Language::all());
+    }
+
+    #[test]
+    fn test_translation_keys_are_namespaced() {
+        const ALLOWED_NAMESPACES: &[&str] = &[
+            "a11y",
+            "custom_panel",
+            "feedback",
+            "form",
+            "format",
+            "i18n",
+            "language",
+            "menu",
+            "panel",
+            "settings",
+            "tooltip",
+            "welcome",
+        ];
+
+        let mut offending = Vec::new();
+        for &lang in Language::all() {
+            for key in I18nManager::load_translations(lang).keys() {
+                let namespace = key.split_once('.').map(|(namespace, _)| namespace);
+                if !namespace.is_some_and(|namespace| ALLOWED_NAMESPACES.contains(&namespace)) {
+                    offending.push(format!("{}: {}", lang.as_str(), key));
+                }
+            }
+        }
+        offending.sort();
+        assert!(
+            offending.is_empty(),
+            "translation keys outside of an allowed namespace: {offending:#?}"
+        );
+    }
+
+    #[test]
+    fn test_disabling_translation_returns_raw_keys() {
+        let _lock = lock_global_state();
+        I18N_MANAGER.get_or_init(|| RwLock::new(I18nManager::new()));
+        set_language(Language::English);
+        assert_eq!(t("menu.save"), "Save");
+
+        set_translation_enabled(false);
+        assert_eq!(t("menu.save"), "menu.save");
+        assert_eq!(t_static("menu.save"), "menu.save");
+        assert_eq!(t_args("welcome.message", &["Zed"]), "welcome.message");
+        assert_eq!(t_shared("menu.save"), "menu.save");
+        assert_eq!(
+            t_named("menu.save", &[("count", &format_number(3))]),
+            "menu.save"
+        );
+        assert_eq!(
+            t_all(["menu.save", "menu.missing"]),
+            ["menu.save", "menu.missing"]
+        );
+
+        set_language(Language::SimplifiedChinese);
+        assert_eq!(get_language(), Language::SimplifiedChinese);
+        assert_eq!(t("menu.save"), "menu.save");
+
+        set_translation_enabled(true);
+        assert_eq!(t("menu.save"), "保存");
+        assert_eq!(
+            t_all(["menu.save", "menu.missing"]),
+            ["保存", "menu.missing"]
+        );
+        assert_eq!(t_static("menu.save"), "保存");
+    }
+
+    #[test]
+    fn test_shared_translations_are_reused_until_language_changes() {
+        let cache = Mutex::new(GenerationCache::new());
+        let chinese = |key: &str| match key {
+            "menu.save" => "保存".to_string(),
+            _ => key.to_string(),
+        };
+        let english = |key: &str| match key {
+            "menu.save" => "Save".to_string(),
+            _ => key.to_string(),
+        };
+
+        let first = cached_shared(&cache, "menu.save", 0, chinese);
+        let second = cached_shared(&cache, "menu.save", 0, |_| unreachable!());
+        assert_eq!(first, "保存");
+        assert_eq!(first, second);
+        // 两次返回的是同一份缓冲区，克隆只增加引用计数
+        assert_eq!(first.as_ptr(), second.as_ptr());
+
+        let switched = cached_shared(&cache, "menu.save", 1, english);
+        assert_eq!(switched, "Save");
+        assert_eq!(
+            cached_shared(&cache, "menu.save", 1, |_| unreachable!()),
+            "Save"
+        );
+    }
+
+    #[test]
+    fn test_language_change_announcement_uses_new_language() {
+        let mut manager = I18nManager::with_translations(
+            Language::English,
+            HashMap::from_iter([
+                (
+                    Language::English,
+                    translations(&[("i18n.language_changed", "Language changed to {0}")]),
+                ),
+                (
+                    Language::Japanese,
+                    translations(&[("i18n.language_changed", "言語を{0}に変更しました")]),
+                ),
+            ]),
+        );
+        assert_eq!(
+            manager.language_changed_announcement(),
+            "Language changed to English"
+        );
+
+        manager.set_language(Language::Japanese);
+        assert_eq!(
+            manager.language_changed_announcement(),
+            "言語を日本語に変更しました"
+        );
+
+        // 没有该键译文的语言回退到英文文本，但语言名称仍使用该语言自身的写法
+        manager.set_language(Language::Korean);
+        assert_eq!(
+            manager.language_changed_announcement(),
+            "Language changed to 한국어"
+        );
+    }
+
+    #[test]
+    fn test_set_language_announces_only_with_announcer() {
+        let _lock = lock_global_state();
+        init_test();
+        set_translation_enabled(true);
+        let announced = Arc::new(Mutex::new(Vec::new()));
+
+        // 没有安装回调时不播报
+        set_language(Language::English);
+        set_announcer(Some(Box::new({
+            let announced = announced.clone();
+            move |message| announced.lock().unwrap().push(message.to_string())
+        })));
+        set_language(Language::SimplifiedChinese);
+        set_announcer(None);
+        set_language(Language::English);
+
+        assert_eq!(*announced.lock().unwrap(), ["语言已切换为简体中文"]);
+    }
+
+    #[test]
+    fn test_bundled_announcement_is_localized() {
+        let mut manager = I18nManager::new();
+        manager.set_language(Language::SimplifiedChinese);
+        assert_eq!(
+            manager.language_changed_announcement(),
+            "语言已切换为简体中文"
+        );
+    }
+
+    #[test]
+    fn test_hot_keys_are_ranked_by_access_count() {
+        let mut counts = AccessCounts::default();
+        for key in [
+            "menu.open",
+            "menu.save",
+            "menu.save",
+            "menu.close",
+            "menu.save",
+            "menu.open",
+        ] {
+            counts.record(key);
+        }
+        assert_eq!(
+            counts.hottest(2),
+            [("menu.save".to_string(), 3), ("menu.open".to_string(), 2)]
+        );
+        assert_eq!(
+            counts.hottest(10),
+            [
+                ("menu.save".to_string(), 3),
+                ("menu.open".to_string(), 2),
+                ("menu.close".to_string(), 1),
+            ]
+        );
+        assert!(counts.hottest(0).is_empty());
+    }
+
+    #[test]
+    fn test_generation_cache_discards_stale_entries_lazily() {
+        let mut cache = GenerationCache::new();
+        cache.insert("menu.save".to_string(), 0, "保存");
+        cache.insert("menu.open".to_string(), 0, "打开");
+        assert_eq!(cache.get("menu.save", 0), Some("保存"));
+
+        // 代数变化后，旧条目在访问时才被移除，其余条目保留到各自被访问
+        assert_eq!(cache.get("menu.save", 1), None);
+        assert_eq!(cache.entries.len(), 1);
+        cache.insert("menu.save".to_string(), 1, "Save");
+        assert_eq!(cache.get("menu.save", 1), Some("Save"));
+        assert_eq!(cache.entries.len(), 2);
+        assert_eq!(cache.get("menu.open", 1), None);
+        assert_eq!(cache.entries.len(), 1);
+    }
+
+    #[test]
+    fn test_reset_all_caches_clears_every_cache() {
+        let _lock = lock_global_state();
+        let generation = language_generation();
+        STATIC_VALUES
+            .lock()
+            .unwrap()
+            .insert("test.reset_static".to_string(), generation, "static");
+        SHARED_VALUES.lock().unwrap().insert(
+            "test.reset_shared".to_string(),
+            generation,
+            SharedString::from("shared"),
+        );
+
+        reset_all_caches();
+
+        assert!(
+            !STATIC_VALUES
+                .lock()
+                .unwrap()
+                .entries
+                .contains_key("test.reset_static")
+        );
+        assert!(
+            !SHARED_VALUES
+                .lock()
+                .unwrap()
+                .entries
+                .contains_key("test.reset_shared")
+        );
+        assert!(language_generation() > generation);
+    }
+
+    #[test]
+    fn test_static_translations_are_leaked_once() {
+        let _lock = lock_global_state();
+        let interned = RwLock::new(HashMap::new());
+        let first = intern(&interned, "保存".to_string());
+        assert_eq!(first, "保存");
+
+        // 清空缓存、重复调用都返回同一个引用，不会再次泄漏
+        reset_all_caches();
+        for _ in 0..3 {
+            assert!(std::ptr::eq(first, intern(&interned, "保存".to_string())));
+        }
+
+        // 不同内容各自泄漏一次，不同键或语言的相同译文共用同一个引用
+        let english = intern(&interned, "Save".to_string());
+        assert_eq!(english, "Save");
+        assert!(std::ptr::eq(english, intern(&interned, "Save".to_string())));
+
+        assert_eq!(interned.read().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_set_language_advances_generation() {
+        let _lock = lock_global_state();
+        let generation = language_generation();
+        set_language(get_language());
+        assert!(language_generation() > generation);
+    }
+
+    #[gpui::test]
+    fn test_subscribers_are_notified_of_language_changes(cx: &mut gpui::TestAppContext) {
+        let _lock = lock_global_state();
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let subscription = cx.update(|cx| {
+            let changes = changes.clone();
+            subscribe(cx, move |lang, _| changes.borrow_mut().push(lang))
+        });
+
+        let lang = get_language();
+        set_language(lang);
+        cx.run_until_parked();
+        assert_eq!(*changes.borrow(), [lang]);
+
+        // 丢弃订阅后不再通知
+        drop(subscription);
+        let notified = changes.borrow().len();
+        set_language(lang);
+        cx.run_until_parked();
+        assert_eq!(changes.borrow().len(), notified);
+
+        // 分离的订阅一直有效
+        let detached = Rc::new(RefCell::new(Vec::new()));
+        cx.update(|cx| {
+            let detached = detached.clone();
+            subscribe(cx, move |lang, _| detached.borrow_mut().push(lang)).detach()
+        });
+        set_language(lang);
+        cx.run_until_parked();
+        set_language(lang);
+        cx.run_until_parked();
+        assert_eq!(*detached.borrow(), [lang, lang]);
+    }
+
+    #[test]
+    fn test_number_format_comes_from_current_language() {
+        let manager = I18nManager::with_translations(
+            Language::Japanese,
+            HashMap::from_iter([
+                (
+                    Language::English,
+                    translations(&[
+                        ("format.decimal_separator", "."),
+                        ("format.kilobytes", "KB"),
+                    ]),
+                ),
+                (
+                    Language::Japanese,
+                    translations(&[
+                        ("format.decimal_separator", ","),
+                        ("format.group_separator", "."),
+                    ]),
+                ),
+            ]),
+        );
+        let format = manager.number_format(Language::Japanese);
+        assert_eq!(format.format_number(1234567), "1.234.567");
+        // 缺少的单位使用英文的翻译
+        assert_eq!(format.format_bytes(1536), "1,5 KB");
+
+        let format = manager.number_format(Language::English);
+        assert_eq!(format.format_number(1234567), "1,234,567");
+        assert_eq!(format.format_bytes(1536), "1.5 KB");
+    }
+
+    #[test]
+    fn test_placeholders_survive_translation() {
+        let english = bundled_translations(Language::English);
+        let mut manager = I18nManager::new();
+        let mut offending = Vec::new();
+
+        for &lang in Language::all() {
+            let translations = bundled_translations(lang);
+            manager.set_language(lang);
+            for (key, english_value) in &english {
+                let expected = interpolate::placeholders(english_value);
+                if expected.is_empty() {
+                    continue;
+                }
+                // 缺失的译文回退到英文，由覆盖率检查负责
+                if let Some(value) = translations.get(key) {
+                    if interpolate::placeholders(value) != expected {
+                        offending.push((lang.as_str(), key.clone(), "placeholder set differs"));
+                        continue;
+                    }
+                }
+
+                // 按实际调用方式传入参数，结果中不应残留任何占位符
+                let output = match expected
+                    .iter()
+                    .map(|name| name.parse::<usize>())
+                    .collect::<Result<Vec<_>, _>>()
+                {
+                    Ok(indices) => {
+                        let count = indices.iter().max().map_or(0, |max| max + 1);
+                        manager.translate_with_args(key, &vec!["x"; count])
+                    }
+                    Err(_) => {
+                        let args = expected
+                            .iter()
+                            .map(|name| (name.as_str(), "x"))
+                            .collect::<Vec<_>>();
+                        manager.translate_with_named(key, &args)
+                    }
+                };
+                if !interpolate::placeholders(&output).is_empty() {
+                    offending.push((lang.as_str(), key.clone(), "placeholder left in output"));
+                }
+            }
+        }
+
+        offending.sort();
+        assert!(
+            offending.is_empty(),
+            "translations with broken placeholders: {offending:?}"
+        );
+    }
+
+    #[test]
+    fn test_numeric_parameters_are_formatted_and_interpolated() {
+        let manager = I18nManager::with_translations(
+            Language::SimplifiedChinese,
+            HashMap::from_iter([
+                (
+                    Language::English,
+                    translations(&[("test.split_into_panes", "Split into {count} Panes")]),
+                ),
+                (
+                    Language::SimplifiedChinese,
+                    translations(&[("test.split_into_panes", "拆分为 {count} 个窗格")]),
+                ),
+            ]),
+        );
+        let count = manager
+            .number_format(Language::SimplifiedChinese)
+            .format_number(1200);
+        assert_eq!(
+            manager.translate_with_named("test.split_into_panes", &[("count", &count)]),
+            "拆分为 1,200 个窗格"
+        );
+    }
+
+    #[test]
+    fn test_named_arguments_follow_translation_word_order() {
+        let mut manager = I18nManager::with_translations(
+            Language::Japanese,
+            HashMap::from_iter([
+                (
+                    Language::English,
+                    translations(&[("test.opened", "{user} opened {file} ({0})")]),
+                ),
+                (
+                    Language::Japanese,
+                    translations(&[("test.opened", "{file} を {user} が開きました（{0}）")]),
+                ),
+            ]),
+        );
+        let args = [("user", "Alice"), ("file", "main.rs"), ("0", "2")];
+        assert_eq!(
+            manager.translate_with_named("test.opened", &args),
+            "main.rs を Alice が開きました（2）"
+        );
+
+        manager.set_language(Language::English);
+        assert_eq!(
+            manager.translate_with_named("test.opened", &args),
+            "Alice opened main.rs (2)"
+        );
+    }
+
+    #[test]
+    fn test_t_named_macro() {
+        assert_eq!(
+            t_named!("test.not_a_key", count = "3", 0 = "x"),
+            t_named("test.not_a_key", &[("count", "3"), ("0", "x")])
+        );
+    }
+
+    #[test]
+    fn test_plural_forms_follow_language_rules() {
+        let english = LocaleFile::parse(
+            r##"{
+                "test.files_changed": {
+                    "#one": "{count} file changed in {0}",
+                    "#other": "{count} files changed in {0}"
+                },
+                "test.english_only": { "#one": "{count} item", "#other": "{count} items" }
+            }"##,
+        );
+        assert_eq!(
+            english.translations["test.files_changed#one"],
+            "{count} file changed in {0}"
+        );
+        let japanese = LocaleFile::parse(
+            r##"{ "test.files_changed": { "#other": "{0} の {count} 個のファイルを変更" } }"##,
+        );
+        let mut manager = I18nManager::with_translations(
+            Language::English,
+            HashMap::from_iter([
+                (Language::English, english.translations),
+                (Language::Japanese, japanese.translations),
+            ]),
+        );
+
+        assert_eq!(
+            manager.translate_plural("test.files_changed", 1, &["main"]),
+            "1 file changed in main"
+        );
+        assert_eq!(
+            manager.translate_plural("test.files_changed", 1200, &["main"]),
+            "1,200 files changed in main"
+        );
+
+        // 日文没有复数变化，所有数量都使用 other
+        manager.set_language(Language::Japanese);
+        for count in [1, 3] {
+            assert_eq!(
+                manager.translate_plural("test.files_changed", count, &["main"]),
+                format!("main の {count} 個のファイルを変更")
+            );
+        }
+        // 缺少的条目回退到英文，并按英文的规则选择形式
+        assert_eq!(
+            manager.translate_plural("test.english_only", 1, &[]),
+            "1 item"
+        );
+        assert_eq!(
+            manager.translate_plural("test.missing", 1, &[]),
+            "test.missing"
+        );
+    }
+
+    #[test]
+    fn test_t_plural_macro() {
+        assert_eq!(
+            t_plural!("test.not_a_key", 2, "x"),
+            t_plural("test.not_a_key", 2, &["x"])
+        );
+    }
+
+    #[test]
+    fn test_nested_keys_are_flattened() {
+        let file = LocaleFile::parse(
+            r##"{
+                "menu.edit.undo": "Undo",
+                "menu": {
+                    "file": { "save": "Save", "open": "Open" },
+                    "quit": "Quit"
+                },
+                "git": {
+                    "files_changed": { "#one": "{count} file", "#other": "{count} files" }
+                },
+                "count": { "one": "One", "other": "Other" }
+            }"##,
+        );
+        let manager = I18nManager::with_translations(
+            Language::English,
+            HashMap::from_iter([(Language::English, file.translations)]),
+        );
+
+        assert_eq!(manager.translate("menu.edit.undo"), "Undo");
+        assert_eq!(manager.translate("menu.file.save"), "Save");
+        assert_eq!(manager.translate("menu.file.open"), "Open");
+        assert_eq!(manager.translate("menu.quit"), "Quit");
+        // 分组内的复数条目仍按复数形式展开
+        assert_eq!(
+            manager.translate_plural("git.files_changed", 2, &[]),
+            "2 files"
+        );
+        assert_eq!(manager.translate("menu.file"), "menu.file");
+        // 子键恰好是复数类别名称的分组仍然是普通的键
+        assert_eq!(manager.translate("count.one"), "One");
+        assert_eq!(manager.translate("count.other"), "Other");
+        assert_eq!(manager.translate_plural("count", 2, &[]), "count");
+    }
+
+    #[test]
+    fn test_translation_diff() {
+        let english = translations(&[
+            ("menu.open", "Open"),
+            ("menu.save", "Save"),
+            ("menu.close", "Close"),
+            ("menu.removed", "Removed"),
+        ]);
+        let japanese = translations(&[
+            ("menu.open", "開く"),
+            ("menu.save", "Save"),
+            ("menu.close", "Close"),
+            ("menu.stale", "古い"),
+        ]);
+
+        let diff = translation_diff_in(&english, &japanese, &HashSet::new());
+        assert_eq!(
+            diff,
+            TranslationDiff {
+                only_in_a: vec!["menu.removed".to_string()],
+                only_in_b: vec!["menu.stale".to_string()],
+                untranslated: vec!["menu.close".to_string(), "menu.save".to_string()],
+                fuzzy: Vec::new(),
+            }
+        );
+        assert!(
+            translation_diff_in(&english, &english.clone(), &HashSet::new())
+                .only_in_a
+                .is_empty()
+        );
+        assert!(
+            translation_diff_in(&Translations::new(), &Translations::new(), &HashSet::new())
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_similar_translations_are_ranked_by_similarity() {
+        let english = translations(&[
+            ("menu.open_file", "Open File"),
+            ("menu.open_recent_file", "Open Recent File"),
+            ("menu.open_folder", "Open Folder"),
+            ("menu.close_window", "Close Window"),
+        ]);
+        let current = translations(&[
+            ("menu.open_file", "打开文件"),
+            ("menu.open_recent_file", "打开最近的文件"),
+            ("menu.open_folder", "打开文件夹"),
+            ("menu.close_window", "关闭窗口"),
+        ]);
+
+        let similar = similar_translations_in(&english, &current, "Open file...");
+        assert_eq!(
+            similar,
+            vec![
+                ("Open File".to_string(), "打开文件".to_string()),
+                ("Open Recent File".to_string(), "打开最近的文件".to_string()),
+                ("Open Folder".to_string(), "打开文件夹".to_string()),
+            ]
+        );
+        assert!(similar_translations_in(&english, &current, "Toggle Minimap").is_empty());
+        assert!(similar_translations_in(&english, &current, "").is_empty());
+    }
+
+    #[test]
+    fn test_tr_key_registers_key() {
+        let key = tr_key!("menu.save");
+        assert_eq!(key, "menu.save");
+        assert!(registered_keys().any(|key| key == "menu.save"));
+    }
+}
@@ -0,0 +1,159 @@
+//! 插值引擎
+//!
+//! 所有带参数的翻译函数都通过 [`interpolate`] 完成替换，
+//! 这样花括号的解析规则（转义、缺失参数、嵌套）只存在于这一处。
+
+#[cfg(test)]
+use std::collections::BTreeSet;
+
+/// 占位符参数的解析策略
+pub(crate) enum Args<'a> {
+    /// 位置参数：`{0}`、`{1}` ...
+    Positional(&'a [&'a str]),
+    /// 命名参数：`{count}`、`{name}` ...
+    Named(&'a [(&'a str, &'a str)]),
+}
+
+impl Args<'_> {
+    fn resolve(&self, name: &str) -> Option<&str> {
+        match self {
+            Args::Positional(args) => name
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| args.get(index).copied()),
+            Args::Named(args) => args
+                .iter()
+                .find(|(arg_name, _)| *arg_name == name)
+                .map(|(_, value)| *value),
+        }
+    }
+}
+
+/// 从左到右单次扫描模板并替换占位符
+///
+/// - `{{` 和 `}}` 输出字面量花括号
+/// - `{name}` 替换为解析到的参数；参数缺失时原样保留占位符
+/// - 未闭合或嵌套的 `{` 按字面量输出
+///
+/// 参数值本身不会被再次扫描，因此值中包含 `{1}` 之类的文本不会影响后续替换。
+pub(crate) fn interpolate(template: &str, args: &Args) -> String {
+    scan(template, |name| args.resolve(name))
+}
+
+/// 返回模板中所有占位符的名称，解析规则与 [`interpolate`] 相同
+///
+/// 转义的 `{{0}}` 不算占位符。
+#[cfg(test)]
+pub(crate) fn placeholders(template: &str) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    scan(template, |name| {
+        names.insert(name.to_string());
+        None
+    });
+    names
+}
+
+/// 扫描模板，对每个占位符调用 `resolve`，返回 `None` 时原样保留占位符
+fn scan<'a>(template: &str, mut resolve: impl FnMut(&str) -> Option<&'a str>) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(index) = rest.find(['{', '}']) {
+        result.push_str(&rest[..index]);
+        let tail = &rest[index..];
+
+        if tail.starts_with("{{") {
+            result.push('{');
+            rest = &tail[2..];
+        } else if tail.starts_with("}}") {
+            result.push('}');
+            rest = &tail[2..];
+        } else if tail.starts_with('}') {
+            result.push('}');
+            rest = &tail[1..];
+        } else {
+            let inner = &tail[1..];
+            match inner.find(['{', '}']) {
+                Some(end) if inner[end..].starts_with('}') => {
+                    let name = &inner[..end];
+                    match resolve(name) {
+                        Some(value) => result.push_str(value),
+                        None => result.push_str(&tail[..end + 2]),
+                    }
+                    rest = &inner[end + 1..];
+                }
+                _ => {
+                    result.push('{');
+                    rest = inner;
+                }
+            }
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn positional(template: &str, args: &[&str]) -> String {
+        interpolate(template, &Args::Positional(args))
+    }
+
+    #[test]
+    fn test_positional_arguments() {
+        assert_eq!(positional("{0} + {1}", &["a", "b"]), "a + b");
+        assert_eq!(positional("{1} {0} {1}", &["a", "b"]), "b a b");
+        assert_eq!(positional("no placeholders", &["a"]), "no placeholders");
+    }
+
+    #[test]
+    fn test_named_arguments() {
+        let args = Args::Named(&[("count", "3"), ("name", "main.rs")]);
+        assert_eq!(interpolate("{name}: {count}", &args), "main.rs: 3");
+        assert_eq!(interpolate("{0} {missing}", &args), "{0} {missing}");
+
+        // 名称为数字的参数替换位置占位符，两种占位符可以混用
+        let args = Args::Named(&[("0", "main.rs"), ("count", "3")]);
+        assert_eq!(interpolate("{0}: {count}", &args), "main.rs: 3");
+    }
+
+    #[test]
+    fn test_escaped_braces() {
+        assert_eq!(positional("{{0}} = {0}", &["a"]), "{0} = a");
+        assert_eq!(positional("fn main() {{ }}", &[]), "fn main() { }");
+        assert_eq!(positional("{{{0}}}", &["a"]), "{a}");
+    }
+
+    #[test]
+    fn test_missing_arguments_are_left_in_place() {
+        assert_eq!(positional("{0} {1}", &["a"]), "a {1}");
+        assert_eq!(positional("{name}", &["a"]), "{name}");
+        assert_eq!(positional("{}", &["a"]), "{}");
+    }
+
+    #[test]
+    fn test_values_are_not_rescanned() {
+        assert_eq!(positional("{0} {1}", &["{1}", "b"]), "{1} b");
+        assert_eq!(positional("{0}", &["{{"]), "{{");
+    }
+
+    #[test]
+    fn test_placeholders() {
+        let names = |template: &str| placeholders(template).into_iter().collect::<Vec<_>>();
+        assert_eq!(names("{1} {0} {1}"), ["0", "1"]);
+        assert_eq!(names("{name}: {count}"), ["count", "name"]);
+        assert_eq!(names("{{0}} and {{name}}"), Vec::<String>::new());
+        assert_eq!(names("{outer{0}}"), ["0"]);
+    }
+
+    #[test]
+    fn test_nested_and_unbalanced_braces() {
+        assert_eq!(positional("{outer{0}}", &["a"]), "{outera}");
+        assert_eq!(positional("{0", &["a"]), "{0");
+        assert_eq!(positional("0}", &["a"]), "0}");
+        assert_eq!(positional("{ {0}", &["a"]), "{ a");
+    }
+}
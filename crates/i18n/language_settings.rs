@@ -0,0 +1,48 @@
+use settings::{RegisterSetting, Settings};
+
+use crate::Language;
+
+/// 界面语言的设置，对应设置文件中的 `ui_language`
+#[derive(Debug, Clone, Copy, PartialEq, RegisterSetting)]
+pub struct UiLanguageSettings {
+    /// 用户选择的界面语言，None 表示跟随系统语言
+    pub ui_language: Option<Language>,
+}
+
+impl Settings for UiLanguageSettings {
+    fn from_settings(content: &settings::SettingsContent) -> Self {
+        Self {
+            ui_language: content.ui_language.as_deref().and_then(parse_language),
+        }
+    }
+}
+
+/// 按 `Language` 的 serde 表示（如 `"zh-CN"`）解析设置中的语言，无法识别时返回 None
+pub(crate) fn parse_language(value: &str) -> Option<Language> {
+    match serde_json::from_value(serde_json::Value::from(value)) {
+        Ok(lang) => Some(lang),
+        Err(_) => {
+            log::warn!("unknown ui_language {value:?}, using the system language");
+            None
+        }
+    }
+}
+
+/// 语言在设置文件中的写法，与 `Language` 的 serde 表示相同
+pub(crate) fn setting_value(lang: Language) -> String {
+    lang.as_str().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_setting_value_round_trips() {
+        for &lang in Language::all() {
+            assert_eq!(parse_language(&setting_value(lang)), Some(lang));
+        }
+        assert_eq!(parse_language("zh-CN"), Some(Language::SimplifiedChinese));
+        assert_eq!(parse_language("klingon"), None);
+    }
+}
@@ -0,0 +1,138 @@
+//! 按语言格式化数字和字节数
+//!
+//! 小数点、千位分组符号和字节单位都来自语言文件中的 `format.*` 条目，
+//! 译者可以按语言习惯修改，例如使用逗号作小数点的语言把 `format.decimal_separator` 译为 `,`。
+//! 语言文件缺少的条目使用英文的格式。
+
+/// 字节单位的翻译键，从小到大每级相差 1024 倍
+const BYTE_UNIT_KEYS: [&str; 5] = [
+    "format.bytes",
+    "format.kilobytes",
+    "format.megabytes",
+    "format.gigabytes",
+    "format.terabytes",
+];
+
+/// 某个语言格式化数字时使用的符号和单位
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct NumberFormat {
+    decimal_separator: String,
+    group_separator: String,
+    byte_units: [String; 5],
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            decimal_separator: ".".to_string(),
+            group_separator: ",".to_string(),
+            byte_units: ["B", "KB", "MB", "GB", "TB"].map(str::to_string),
+        }
+    }
+}
+
+impl NumberFormat {
+    /// 通过 `lookup` 读取 `format.*` 条目，返回 None 的条目使用英文的格式
+    pub(crate) fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Self {
+        let mut format = Self::default();
+        if let Some(separator) = lookup("format.decimal_separator") {
+            format.decimal_separator = separator;
+        }
+        if let Some(separator) = lookup("format.group_separator") {
+            format.group_separator = separator;
+        }
+        for (unit, key) in format.byte_units.iter_mut().zip(BYTE_UNIT_KEYS) {
+            if let Some(translated) = lookup(key) {
+                *unit = translated;
+            }
+        }
+        format
+    }
+
+    /// 保留 `precision` 位小数格式化浮点数
+    pub(crate) fn format_float(&self, value: f64, precision: usize) -> String {
+        let formatted = format!("{value:.precision$}");
+        if self.decimal_separator == "." {
+            formatted
+        } else {
+            formatted.replace('.', &self.decimal_separator)
+        }
+    }
+
+    /// 每三位插入一个分组符号格式化整数，例如 `1,234,567`
+    pub(crate) fn format_number(&self, value: i64) -> String {
+        let digits = value.unsigned_abs().to_string();
+        let mut formatted = String::with_capacity(digits.len() * 2);
+        if value < 0 {
+            formatted.push('-');
+        }
+        for (index, digit) in digits.chars().enumerate() {
+            if index > 0 && (digits.len() - index) % 3 == 0 {
+                formatted.push_str(&self.group_separator);
+            }
+            formatted.push(digit);
+        }
+        formatted
+    }
+
+    /// 格式化为带单位的字节数，例如 `1.5 MB`，不足 1024 字节时不带小数
+    pub(crate) fn format_bytes(&self, bytes: u64) -> String {
+        if bytes < 1024 {
+            return format!("{bytes} {}", self.byte_units[0]);
+        }
+        let mut value = bytes as f64;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < self.byte_units.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+        format!("{} {}", self.format_float(value, 1), self.byte_units[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_format_number() {
+        let format = NumberFormat::default();
+        assert_eq!(format.format_number(0), "0");
+        assert_eq!(format.format_number(999), "999");
+        assert_eq!(format.format_number(1000), "1,000");
+        assert_eq!(format.format_number(1234567), "1,234,567");
+        assert_eq!(format.format_number(-1234), "-1,234");
+        assert_eq!(format.format_number(i64::MIN), "-9,223,372,036,854,775,808");
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        let format = NumberFormat::default();
+        assert_eq!(format.format_bytes(0), "0 B");
+        assert_eq!(format.format_bytes(1023), "1023 B");
+        assert_eq!(format.format_bytes(1024), "1.0 KB");
+        assert_eq!(format.format_bytes(1536), "1.5 KB");
+        assert_eq!(format.format_bytes(3 * 1024 * 1024 / 2), "1.5 MB");
+        assert_eq!(format.format_bytes(5 * 1024 * 1024 * 1024), "5.0 GB");
+        assert_eq!(format.format_bytes(u64::MAX), "16777216.0 TB");
+    }
+
+    #[test]
+    fn test_separators_and_units_come_from_translations() {
+        let translations = HashMap::from([
+            ("format.decimal_separator", ","),
+            ("format.group_separator", "\u{a0}"),
+            ("format.kilobytes", "Ko"),
+            ("format.megabytes", "Mo"),
+        ]);
+        let format = NumberFormat::from_lookup(|key| translations.get(key).map(|s| s.to_string()));
+        assert_eq!(format.format_float(1.25, 2), "1,25");
+        assert_eq!(format.format_number(1234567), "1\u{a0}234\u{a0}567");
+        assert_eq!(format.format_bytes(1536), "1,5 Ko");
+        assert_eq!(format.format_bytes(3 * 1024 * 1024 / 2), "1,5 Mo");
+        // 没有翻译的单位使用英文
+        assert_eq!(format.format_bytes(512), "512 B");
+        assert_eq!(format.format_bytes(5 * 1024 * 1024 * 1024), "5,0 GB");
+    }
+}
@@ -0,0 +1,117 @@
+//! 复数形式
+//!
+//! 语言文件中的复数条目写成以 `#` 加 CLDR 复数类别为键的对象：
+//!
+//! ```json
+//! "git.files_changed": { "#one": "{count} file changed", "#other": "{count} files changed" }
+//! ```
+//!
+//! 对象的键全部是带 `#` 的类别名称时才视为复数条目，否则视为嵌套的键分组，
+//! 所以 `{"count": {"one": "…"}}` 仍然是键 `count.one`。
+//! 加载时每个形式展开为一个独立的条目（如 `git.files_changed#one`），
+//! 翻译表仍然是键到字符串的映射，覆盖率统计、PO 导出等工具无需区分复数条目。
+
+use crate::Language;
+
+/// 复数条目展开后，键与复数类别之间的分隔符
+pub(crate) const PLURAL_SEPARATOR: char = '#';
+
+/// CLDR 复数类别
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    /// 语言文件中使用的类别名称
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+
+    /// 按 CLDR 复数规则返回 `count` 在 `lang` 中所属的类别
+    ///
+    /// 只处理整数：英文区分 `one` 和 `other`，中文、日文和韩文没有复数变化，
+    /// 所有数量都属于 `other`。
+    pub fn for_count(lang: Language, count: i64) -> Self {
+        match lang {
+            Language::English if count.unsigned_abs() == 1 => PluralCategory::One,
+            Language::English
+            | Language::SimplifiedChinese
+            | Language::TraditionalChinese
+            | Language::Japanese
+            | Language::Korean => PluralCategory::Other,
+        }
+    }
+}
+
+/// `name` 是否是语言文件中使用的复数类别名称
+fn is_plural_category(name: &str) -> bool {
+    matches!(name, "zero" | "one" | "two" | "few" | "many" | "other")
+}
+
+/// 复数条目中的键（如 `#one`）对应的类别名称，不是复数形式的键返回 None
+pub(crate) fn plural_form_category(name: &str) -> Option<&str> {
+    name.strip_prefix(PLURAL_SEPARATOR)
+        .filter(|category| is_plural_category(category))
+}
+
+/// 复数条目中某个形式在翻译表中的键
+pub(crate) fn plural_key(key: &str, category: &str) -> String {
+    format!("{key}{PLURAL_SEPARATOR}{category}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plural_form_category() {
+        assert_eq!(plural_form_category("#one"), Some("one"));
+        assert_eq!(plural_form_category("#other"), Some("other"));
+        assert_eq!(plural_form_category("one"), None);
+        assert_eq!(plural_form_category("#unknown"), None);
+    }
+
+    #[test]
+    fn test_plural_categories() {
+        assert_eq!(
+            PluralCategory::for_count(Language::English, 1),
+            PluralCategory::One
+        );
+        assert_eq!(
+            PluralCategory::for_count(Language::English, -1),
+            PluralCategory::One
+        );
+        for count in [0, 2, 11, 1000] {
+            assert_eq!(
+                PluralCategory::for_count(Language::English, count),
+                PluralCategory::Other
+            );
+        }
+        for lang in [
+            Language::SimplifiedChinese,
+            Language::TraditionalChinese,
+            Language::Japanese,
+            Language::Korean,
+        ] {
+            for count in [0, 1, 2, 5] {
+                assert_eq!(
+                    PluralCategory::for_count(lang, count),
+                    PluralCategory::Other
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,229 @@
+//! gettext `.po` 格式的导入导出
+//!
+//! 每个条目用 `msgctxt` 保存翻译键，`msgid` 保存英文原文，`msgstr` 保存译文，
+//! 这样 Weblate、Crowdin、POEdit 等工具既能看到原文，导入时又能准确对应回键。
+
+use crate::{Language, Translations};
+use std::fmt;
+
+/// 解析 `.po` 文件失败
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoParseError {
+    /// 出错的行号，从 1 开始
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for PoParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for PoParseError {}
+
+/// 将翻译表序列化为 `.po` 文本，条目按键排序
+///
+/// 英文中没有的键以键本身作为 `msgid`。
+pub(crate) fn to_po(lang: Language, english: &Translations, translations: &Translations) -> String {
+    let mut keys = translations.keys().collect::<Vec<_>>();
+    keys.sort();
+
+    let mut po = String::new();
+    po.push_str("msgid \"\"\nmsgstr \"\"\n");
+    po.push_str(&format!("\"Language: {}\\n\"\n", lang.as_str()));
+    po.push_str("\"Content-Type: text/plain; charset=UTF-8\\n\"\n");
+    for key in keys {
+        let source = english.get(key).unwrap_or(key);
+        po.push('\n');
+        po.push_str(&format!("msgctxt \"{}\"\n", escape(key)));
+        po.push_str(&format!("msgid \"{}\"\n", escape(source)));
+        po.push_str(&format!("msgstr \"{}\"\n", escape(&translations[key])));
+    }
+    po
+}
+
+/// 解析 `.po` 文本，返回键到译文的映射
+///
+/// 没有 `msgctxt` 的条目以 `msgid` 作为键；`msgstr` 为空的条目视为尚未翻译，不会导入。
+pub(crate) fn parse_po(po: &str) -> Result<Translations, PoParseError> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Field {
+        Context,
+        Id,
+        Str,
+    }
+
+    #[derive(Default)]
+    struct Entry {
+        context: Option<String>,
+        id: Option<String>,
+        translation: Option<String>,
+    }
+
+    fn finish(entry: Entry, translations: &mut Translations) {
+        let Some(translation) = entry
+            .translation
+            .filter(|translation| !translation.is_empty())
+        else {
+            return;
+        };
+        let Some(key) = entry.context.or(entry.id).filter(|key| !key.is_empty()) else {
+            return;
+        };
+        translations.insert(key, translation);
+    }
+
+    let mut translations = Translations::new();
+    let mut entry = Entry::default();
+    let mut field = None;
+
+    for (index, line) in po.lines().enumerate() {
+        let error = |message: &str| PoParseError {
+            line: index + 1,
+            message: message.to_string(),
+        };
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if line.starts_with('"') {
+            let value = unquote(line).ok_or_else(|| error("invalid string"))?;
+            let target = match field {
+                Some(Field::Context) => entry.context.as_mut(),
+                Some(Field::Id) => entry.id.as_mut(),
+                Some(Field::Str) => entry.translation.as_mut(),
+                None => None,
+            };
+            target
+                .ok_or_else(|| error("string continuation outside of an entry"))?
+                .push_str(&value);
+            continue;
+        }
+
+        let (keyword, rest) = line
+            .split_once(char::is_whitespace)
+            .ok_or_else(|| error("expected a keyword followed by a string"))?;
+        let value = unquote(rest.trim()).ok_or_else(|| error("invalid string"))?;
+        let next_field = match keyword {
+            "msgctxt" => Field::Context,
+            "msgid" => Field::Id,
+            "msgstr" => Field::Str,
+            _ => return Err(error(&format!("unsupported keyword `{keyword}`"))),
+        };
+
+        // `msgctxt` 或 `msgid` 出现在 `msgstr` 之后说明开始了新的条目
+        if field == Some(Field::Str) && next_field != Field::Str {
+            finish(std::mem::take(&mut entry), &mut translations);
+        }
+        match next_field {
+            Field::Context => entry.context = Some(value),
+            Field::Id => entry.id = Some(value),
+            Field::Str => entry.translation = Some(value),
+        }
+        field = Some(next_field);
+    }
+    finish(entry, &mut translations);
+
+    Ok(translations)
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// 去掉首尾引号并还原转义，格式不正确时返回 `None`
+fn unquote(text: &str) -> Option<String> {
+    let inner = text.strip_prefix('"')?.strip_suffix('"')?;
+    let mut unescaped = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                'n' => unescaped.push('\n'),
+                'r' => unescaped.push('\r'),
+                't' => unescaped.push('\t'),
+                c @ ('\\' | '"') => unescaped.push(c),
+                _ => return None,
+            },
+            '"' => return None,
+            c => unescaped.push(c),
+        }
+    }
+    Some(unescaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn translations(entries: &[(&str, &str)]) -> Translations {
+        entries
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let english = translations(&[
+            ("menu.save", "Save"),
+            ("welcome.message", "Welcome, {0}!"),
+            ("tooltip.quote", "Say \"hi\"\nthen \\ leave"),
+        ]);
+        let japanese = translations(&[
+            ("menu.save", "保存"),
+            ("welcome.message", "ようこそ、{0}！"),
+            ("tooltip.quote", "「hi」と言う\n\tそして \\ 去る"),
+            ("menu.japanese_only", "日本語のみ"),
+        ]);
+
+        let po = to_po(Language::Japanese, &english, &japanese);
+        assert!(po.contains(r#"msgid "Say \"hi\"\nthen \\ leave""#));
+        assert!(po.contains("msgctxt \"menu.japanese_only\"\nmsgid \"menu.japanese_only\"\n"));
+        assert_eq!(parse_po(&po).unwrap(), japanese);
+    }
+
+    #[test]
+    fn test_parse_continuation_lines_and_untranslated_entries() {
+        let po = r#"
+# translator comment
+msgid ""
+msgstr ""
+"Language: ko\n"
+
+msgctxt "menu.save"
+msgid "Save"
+msgstr ""
+"저"
+"장"
+
+msgid "menu.untranslated"
+msgstr ""
+"#;
+        assert_eq!(
+            parse_po(po).unwrap(),
+            translations(&[("menu.save", "저장")])
+        );
+    }
+
+    #[test]
+    fn test_parse_errors_report_line() {
+        let error = parse_po("msgid \"a\"\nmsgstr \"unterminated\n").unwrap_err();
+        assert_eq!(error.line, 2);
+        assert!(parse_po("msgfoo \"a\"").is_err());
+        assert!(parse_po("\"orphan\"").is_err());
+    }
+}
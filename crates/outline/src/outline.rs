@@ -13,7 +13,7 @@ use gpui::{
     ParentElement, Point, Render, Styled, StyledText, Task, TextStyle, WeakEntity, Window, div,
     rems,
 };
-use language::{Outline, OutlineItem};
+use language::{CharKind, Outline, OutlineItem};
 use ordered_float::OrderedFloat;
 use picker::{Picker, PickerDelegate};
 use settings::Settings;
@@ -40,6 +40,42 @@ pub fn toggle(
     _: &zed_actions::outline::ToggleOutline,
     window: &mut Window,
     cx: &mut App,
+) {
+    toggle_with_query(editor, None, window, cx);
+}
+
+pub fn toggle_filtered(
+    editor: Entity<Editor>,
+    action: &zed_actions::outline::ToggleFiltered,
+    window: &mut Window,
+    cx: &mut App,
+) {
+    let query = action
+        .query
+        .clone()
+        .unwrap_or_else(|| word_under_cursor(&editor, cx));
+    toggle_with_query(editor, Some(query), window, cx);
+}
+
+fn word_under_cursor(editor: &Entity<Editor>, cx: &mut App) -> String {
+    editor.update(cx, |editor, cx| {
+        let buffer = editor.buffer().read(cx).snapshot(cx);
+        let cursor_offset = editor
+            .selections
+            .newest::<MultiBufferOffset>(&editor.display_snapshot(cx))
+            .head();
+        match buffer.surrounding_word(cursor_offset, None) {
+            (range, Some(CharKind::Word)) => buffer.text_for_range(range).collect(),
+            _ => String::new(),
+        }
+    })
+}
+
+fn toggle_with_query(
+    editor: Entity<Editor>,
+    query: Option<String>,
+    window: &mut Window,
+    cx: &mut App,
 ) {
     let outline = editor
         .read(cx)
@@ -52,7 +88,13 @@ pub fn toggle(
     if let Some((workspace, outline)) = workspace.zip(outline) {
         workspace.update(cx, |workspace, cx| {
             workspace.toggle_modal(window, cx, |window, cx| {
-                OutlineView::new(outline, editor, window, cx)
+                let outline_view = OutlineView::new(outline, editor, window, cx);
+                if let Some(query) = query.filter(|query| !query.is_empty()) {
+                    outline_view.picker.update(cx, |picker, cx| {
+                        picker.set_query(&query, window, cx);
+                    });
+                }
+                outline_view
             });
         })
     }
@@ -110,6 +152,14 @@ impl OutlineView {
                     }
                 })
                 .detach();
+            let handle = cx.entity().downgrade();
+            editor
+                .register_action(move |action, window, cx| {
+                    if let Some(editor) = handle.upgrade() {
+                        toggle_filtered(editor, action, window, cx);
+                    }
+                })
+                .detach();
         }
     }
 
@@ -528,6 +578,63 @@ mod tests {
         assert_single_caret_at_row(&editor, expected_first_highlighted_row, cx);
     }
 
+    #[gpui::test]
+    async fn test_toggle_filtered_prefills_word_under_cursor(cx: &mut TestAppContext) {
+        init_test(cx);
+        let fs = FakeFs::new(cx.executor());
+        fs.insert_tree(
+            path!("/dir"),
+            json!({
+                "a.rs": indoc!{"
+                    struct SingleLine;
+                    struct MultiLine {
+                        field_1: i32,
+                    }
+                "}
+            }),
+        )
+        .await;
+
+        let project = Project::test(fs, [path!("/dir").as_ref()], cx).await;
+        project.read_with(cx, |project, _| {
+            project.languages().add(language::rust_lang())
+        });
+
+        let (workspace, cx) =
+            cx.add_window_view(|window, cx| Workspace::test_new(project.clone(), window, cx));
+        let worktree_id = workspace.update(cx, |workspace, cx| {
+            workspace.project().update(cx, |project, cx| {
+                project.worktrees(cx).next().unwrap().read(cx).id()
+            })
+        });
+        let editor = workspace
+            .update_in(cx, |workspace, window, cx| {
+                workspace.open_path((worktree_id, rel_path("a.rs")), None, true, window, cx)
+            })
+            .await
+            .unwrap()
+            .downcast::<Editor>()
+            .unwrap();
+        editor.update_in(cx, |editor, window, cx| {
+            editor.change_selections(SelectionEffects::no_scroll(), window, cx, |s| {
+                s.select_ranges([rope::Point::new(1, 10)..rope::Point::new(1, 10)])
+            });
+        });
+
+        cx.dispatch_action(zed_actions::outline::ToggleFiltered::default());
+        cx.run_until_parked();
+        let outline_view = workspace.update(cx, |workspace, cx| {
+            workspace
+                .active_modal::<OutlineView>(cx)
+                .unwrap()
+                .read(cx)
+                .picker
+                .clone()
+        });
+        assert_eq!(query(&outline_view, cx), "MultiLine");
+        assert_eq!(outline_names(&outline_view, cx), vec!["struct MultiLine"]);
+    }
+
     fn open_outline_view(
         workspace: &Entity<Workspace>,
         cx: &mut VisualTestContext,
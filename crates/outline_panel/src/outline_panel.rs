@@ -4988,9 +4988,10 @@ impl Panel for OutlinePanel {
             .then_some(IconName::ListTree)
     }
 
-    fn icon_tooltip(&self, _window: &Window, _: &App) -> Option<&'static str> {
-        Some(i18n::t_static("panel.outline_panel"))
-    }    fn toggle_action(&self) -> Box<dyn Action> {
+    fn icon_tooltip(&self, _window: &Window, _: &App) -> Option<SharedString> {
+        Some(i18n::t("panel.outline_panel").into())
+    }
+    fn toggle_action(&self) -> Box<dyn Action> {
         Box::new(ToggleFocus)
     }
 
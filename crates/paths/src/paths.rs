@@ -304,6 +304,15 @@ pub fn themes_dir() -> &'static PathBuf {
     THEMES_DIR.get_or_init(|| config_dir().join("themes"))
 }
 
+/// Returns the path to the locales directory.
+///
+/// This is where translation files, such as `zh-CN.json`, that override the bundled ones are
+/// stored.
+pub fn locales_dir() -> &'static PathBuf {
+    static LOCALES_DIR: OnceLock<PathBuf> = OnceLock::new();
+    LOCALES_DIR.get_or_init(|| config_dir().join("locales"))
+}
+
 /// Returns the path to the snippets directory.
 pub fn snippets_dir() -> &'static PathBuf {
     static SNIPPETS_DIR: OnceLock<PathBuf> = OnceLock::new();
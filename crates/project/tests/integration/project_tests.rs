@@ -11241,6 +11241,48 @@ async fn test_undo_encoding_change(cx: &mut gpui::TestAppContext) {
     });
 }
 
+#[gpui::test]
+async fn test_reopen_with_gbk_encoding_fixes_mojibake(cx: &mut gpui::TestAppContext) {
+    init_test(cx);
+
+    let fs = FakeFs::new(cx.executor());
+
+    // "中文编码" encoded as GBK, which is not valid UTF-8
+    let gbk_bytes: Vec<u8> = vec![0xD6, 0xD0, 0xCE, 0xC4, 0xB1, 0xE0, 0xC2, 0xEB];
+    fs.insert_tree(path!("/dir"), json!({})).await;
+    fs.insert_file(path!("/dir/gbk.txt"), gbk_bytes).await;
+
+    let project = Project::test(fs.clone(), [path!("/dir").as_ref()], cx).await;
+
+    let buffer = project
+        .update(cx, |p, cx| p.open_local_buffer(path!("/dir/gbk.txt"), cx))
+        .await
+        .unwrap();
+
+    // Decoding with the wrong encoding produces mojibake
+    let reload_receiver = buffer.update(cx, |buffer, cx| {
+        buffer.reload_with_encoding(encoding_rs::SHIFT_JIS, cx)
+    });
+    cx.executor().run_until_parked();
+    let _ = reload_receiver.await;
+    buffer.read_with(cx, |buffer, _| {
+        assert_eq!(buffer.encoding(), encoding_rs::SHIFT_JIS);
+        assert_ne!(buffer.text(), "中文编码");
+    });
+
+    let reload_receiver = buffer.update(cx, |buffer, cx| {
+        buffer.reload_with_encoding(encoding_rs::GBK, cx)
+    });
+    cx.executor().run_until_parked();
+    let _ = reload_receiver.await;
+
+    buffer.read_with(cx, |buffer, _| {
+        assert_eq!(buffer.encoding(), encoding_rs::GBK);
+        assert_eq!(buffer.text(), "中文编码");
+        assert!(!buffer.is_dirty());
+    });
+}
+
 pub fn init_test(cx: &mut gpui::TestAppContext) {
     zlog::init_test();
 
@@ -6824,11 +6824,10 @@ impl Panel for ProjectPanel {
             .then_some(IconName::FileTree)
     }
 
-    fn icon_tooltip(&self, _window: &Window, _cx: &App) -> Option<&'static str> {
-
-        Some(i18n::t_static("panel.project_panel"))
-
-    }    fn toggle_action(&self) -> Box<dyn Action> {
+    fn icon_tooltip(&self, _window: &Window, _cx: &App) -> Option<SharedString> {
+        Some(i18n::t("panel.project_panel").into())
+    }
+    fn toggle_action(&self) -> Box<dyn Action> {
         Box::new(ToggleFocus)
     }
 
@@ -12,7 +12,7 @@ use std::{cmp::Reverse, sync::Arc};
 use theme::{ActiveTheme, ThemeSettings};
 use util::ResultExt;
 use workspace::{
-    Workspace,
+    SymbolKindFilter, Workspace,
     ui::{LabelLike, ListItem, ListItemSpacing, prelude::*},
 };
 
@@ -21,12 +21,12 @@ pub fn init(cx: &mut App) {
         |workspace: &mut Workspace, _window, _: &mut Context<Workspace>| {
             workspace.register_action(
                 |workspace, _: &workspace::ToggleProjectSymbols, window, cx| {
-                    let project = workspace.project().clone();
-                    let handle = cx.entity().downgrade();
-                    workspace.toggle_modal(window, cx, move |window, cx| {
-                        let delegate = ProjectSymbolsDelegate::new(handle, project);
-                        Picker::uniform_list(delegate, window, cx).width(rems(34.))
-                    })
+                    toggle(workspace, None, window, cx);
+                },
+            );
+            workspace.register_action(
+                |workspace, action: &workspace::ToggleProjectSymbolsFiltered, window, cx| {
+                    toggle(workspace, Some(action.kind), window, cx);
                 },
             );
         },
@@ -34,6 +34,50 @@ pub fn init(cx: &mut App) {
     .detach();
 }
 
+fn toggle(
+    workspace: &mut Workspace,
+    kind_filter: Option<SymbolKindFilter>,
+    window: &mut Window,
+    cx: &mut Context<Workspace>,
+) {
+    let project = workspace.project().clone();
+    let handle = cx.entity().downgrade();
+    workspace.toggle_modal(window, cx, move |window, cx| {
+        let mut delegate = ProjectSymbolsDelegate::new(handle, project);
+        delegate.kind_filter = kind_filter;
+        Picker::uniform_list(delegate, window, cx).width(rems(34.))
+    })
+}
+
+/// Whether a symbol of the given LSP kind belongs to the filtered category.
+fn kind_matches(filter: SymbolKindFilter, kind: lsp::SymbolKind) -> bool {
+    match filter {
+        SymbolKindFilter::Function => matches!(
+            kind,
+            lsp::SymbolKind::FUNCTION | lsp::SymbolKind::METHOD | lsp::SymbolKind::CONSTRUCTOR
+        ),
+        SymbolKindFilter::Class => matches!(
+            kind,
+            lsp::SymbolKind::CLASS
+                | lsp::SymbolKind::STRUCT
+                | lsp::SymbolKind::INTERFACE
+                | lsp::SymbolKind::ENUM
+        ),
+        SymbolKindFilter::Variable => matches!(
+            kind,
+            lsp::SymbolKind::VARIABLE | lsp::SymbolKind::FIELD | lsp::SymbolKind::PROPERTY
+        ),
+        SymbolKindFilter::Constant => matches!(
+            kind,
+            lsp::SymbolKind::CONSTANT | lsp::SymbolKind::ENUM_MEMBER
+        ),
+        SymbolKindFilter::Module => matches!(
+            kind,
+            lsp::SymbolKind::MODULE | lsp::SymbolKind::NAMESPACE | lsp::SymbolKind::PACKAGE
+        ),
+    }
+}
+
 pub type ProjectSymbols = Entity<Picker<ProjectSymbolsDelegate>>;
 
 pub struct ProjectSymbolsDelegate {
@@ -45,6 +89,8 @@ pub struct ProjectSymbolsDelegate {
     external_match_candidates: Vec<StringMatchCandidate>,
     show_worktree_root_name: bool,
     matches: Vec<StringMatch>,
+    /// When set, only symbols of this kind are listed.
+    kind_filter: Option<SymbolKindFilter>,
 }
 
 impl ProjectSymbolsDelegate {
@@ -58,6 +104,7 @@ impl ProjectSymbolsDelegate {
             external_match_candidates: Default::default(),
             matches: Default::default(),
             show_worktree_root_name: false,
+            kind_filter: None,
         }
     }
 
@@ -192,9 +239,13 @@ impl PickerDelegate for ProjectSymbolsDelegate {
         let symbols = self
             .project
             .update(cx, |project, cx| project.symbols(&query, cx));
+        let kind_filter = self.kind_filter;
         cx.spawn_in(window, async move |this, cx| {
             let symbols = symbols.await.log_err();
-            if let Some(symbols) = symbols {
+            if let Some(mut symbols) = symbols {
+                if let Some(kind_filter) = kind_filter {
+                    symbols.retain(|symbol| kind_matches(kind_filter, symbol.kind));
+                }
                 this.update_in(cx, |this, window, cx| {
                     let delegate = &mut this.delegate;
                     let project = delegate.project.read(cx);
@@ -310,7 +361,7 @@ impl PickerDelegate for ProjectSymbolsDelegate {
 mod tests {
     use super::*;
     use futures::StreamExt;
-    use gpui::{TestAppContext, VisualContext};
+    use gpui::{TestAppContext, VisualContext, VisualTestContext};
     use language::{FakeLspAdapter, Language, LanguageConfig, LanguageMatcher};
     use lsp::OneOf;
     use project::FakeFs;
@@ -471,6 +522,111 @@ mod tests {
         });
     }
 
+    #[gpui::test]
+    async fn test_project_symbols_filtered_by_kind(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let fs = FakeFs::new(cx.executor());
+        fs.insert_tree(path!("/dir"), json!({ "test.rs": "" }))
+            .await;
+
+        let project = Project::test(fs.clone(), [path!("/dir").as_ref()], cx).await;
+
+        let language_registry = project.read_with(cx, |project, _| project.languages().clone());
+        language_registry.add(Arc::new(Language::new(
+            LanguageConfig {
+                name: "Rust".into(),
+                matcher: LanguageMatcher {
+                    path_suffixes: vec!["rs".to_string()],
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            None,
+        )));
+        let mut fake_servers = language_registry.register_fake_lsp(
+            "Rust",
+            FakeLspAdapter {
+                capabilities: lsp::ServerCapabilities {
+                    workspace_symbol_provider: Some(OneOf::Left(true)),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
+
+        let _buffer = project
+            .update(cx, |project, cx| {
+                project.open_local_buffer_with_lsp(path!("/dir/test.rs"), cx)
+            })
+            .await
+            .unwrap();
+
+        let fake_symbols = vec![
+            symbol_of_kind(
+                "parse_item",
+                path!("/dir/test.rs"),
+                lsp::SymbolKind::FUNCTION,
+            ),
+            symbol_of_kind("Parser", path!("/dir/test.rs"), lsp::SymbolKind::STRUCT),
+            symbol_of_kind("parse", path!("/dir/test.rs"), lsp::SymbolKind::METHOD),
+            symbol_of_kind(
+                "PARSE_LIMIT",
+                path!("/dir/test.rs"),
+                lsp::SymbolKind::CONSTANT,
+            ),
+        ];
+        let fake_server = fake_servers.next().await.unwrap();
+        fake_server.set_request_handler::<lsp::WorkspaceSymbolRequest, _, _>(move |_, _| {
+            let fake_symbols = fake_symbols.clone();
+            async move { Ok(Some(lsp::WorkspaceSymbolResponse::Flat(fake_symbols))) }
+        });
+
+        let (workspace, cx) =
+            cx.add_window_view(|window, cx| Workspace::test_new(project.clone(), window, cx));
+        let symbols_of_kind = |kind_filter: Option<SymbolKindFilter>,
+                               cx: &mut VisualTestContext| {
+            let symbols = cx.new_window_entity(|window, cx| {
+                let mut delegate =
+                    ProjectSymbolsDelegate::new(workspace.downgrade(), project.clone());
+                delegate.kind_filter = kind_filter;
+                Picker::uniform_list(delegate, window, cx)
+            });
+            symbols.update_in(cx, |p, window, cx| {
+                p.update_matches("pars".to_string(), window, cx);
+            });
+            cx.run_until_parked();
+            symbols.read_with(cx, |symbols, _| {
+                let mut names = symbols
+                    .delegate
+                    .matches
+                    .iter()
+                    .map(|mat| mat.string.clone())
+                    .collect::<Vec<_>>();
+                names.sort();
+                names
+            })
+        };
+
+        assert_eq!(
+            symbols_of_kind(None, cx),
+            ["PARSE_LIMIT", "Parser", "parse", "parse_item"]
+        );
+        assert_eq!(
+            symbols_of_kind(Some(SymbolKindFilter::Function), cx),
+            ["parse", "parse_item"]
+        );
+        assert_eq!(
+            symbols_of_kind(Some(SymbolKindFilter::Class), cx),
+            ["Parser"]
+        );
+        assert_eq!(
+            symbols_of_kind(Some(SymbolKindFilter::Constant), cx),
+            ["PARSE_LIMIT"]
+        );
+        assert!(symbols_of_kind(Some(SymbolKindFilter::Module), cx).is_empty());
+    }
+
     fn init_test(cx: &mut TestAppContext) {
         cx.update(|cx| {
             let store = SettingsStore::test(cx);
@@ -482,10 +638,18 @@ mod tests {
     }
 
     fn symbol(name: &str, path: impl AsRef<Path>) -> lsp::SymbolInformation {
+        symbol_of_kind(name, path, lsp::SymbolKind::FUNCTION)
+    }
+
+    fn symbol_of_kind(
+        name: &str,
+        path: impl AsRef<Path>,
+        kind: lsp::SymbolKind,
+    ) -> lsp::SymbolInformation {
         #[allow(deprecated)]
         lsp::SymbolInformation {
             name: name.to_string(),
-            kind: lsp::SymbolKind::FUNCTION,
+            kind,
             tags: None,
             deprecated: None,
             container_name: None,
@@ -14,6 +14,7 @@ pub use remote_connection::{RemoteConnectionModal, connect};
 pub use remote_connections::open_remote_project;
 
 use disconnected_overlay::DisconnectedOverlay;
+use fs::Fs;
 use fuzzy::{StringMatch, StringMatchCandidate};
 use gpui::{
     Action, AnyElement, App, Context, DismissEvent, Entity, EventEmitter, FocusHandle, Focusable,
@@ -35,7 +36,7 @@ use workspace::{
     WORKSPACE_DB, Workspace, WorkspaceId, notifications::DetachAndPromptErr,
     with_active_or_new_workspace,
 };
-use zed_actions::{OpenDevContainer, OpenRecent, OpenRemote};
+use zed_actions::{OpenDevContainer, OpenRecent, OpenRemote, RecentProjectsFilter};
 
 #[derive(Clone, Debug)]
 pub struct RecentProjectEntry {
@@ -101,6 +102,46 @@ pub async fn delete_recent_project(workspace_id: WorkspaceId) {
     let _ = WORKSPACE_DB.delete_workspace_by_id(workspace_id).await;
 }
 
+/// Keeps only the recent workspaces that match `filter`.
+///
+/// A local workspace counts as a folder when any of its paths is a directory.
+/// Remote workspaces are always treated as folders.
+async fn filter_workspaces(
+    workspaces: Vec<(WorkspaceId, SerializedWorkspaceLocation, PathList)>,
+    filter: RecentProjectsFilter,
+    fs: &dyn Fs,
+) -> Vec<(WorkspaceId, SerializedWorkspaceLocation, PathList)> {
+    if filter == RecentProjectsFilter::All {
+        return workspaces;
+    }
+
+    let mut filtered = Vec::with_capacity(workspaces.len());
+    for (workspace_id, location, paths) in workspaces {
+        let is_folder = match location {
+            SerializedWorkspaceLocation::Local => {
+                let mut is_folder = false;
+                for path in paths.paths() {
+                    if fs.is_dir(path).await {
+                        is_folder = true;
+                        break;
+                    }
+                }
+                is_folder
+            }
+            SerializedWorkspaceLocation::Remote(_) => true,
+        };
+        let keep = match filter {
+            RecentProjectsFilter::All => true,
+            RecentProjectsFilter::Files => !is_folder,
+            RecentProjectsFilter::Folders => is_folder,
+        };
+        if keep {
+            filtered.push((workspace_id, location, paths));
+        }
+    }
+    filtered
+}
+
 pub fn init(cx: &mut App) {
     #[cfg(target_os = "windows")]
     cx.on_action(|open_wsl: &zed_actions::wsl_actions::OpenFolderInWsl, cx| {
@@ -198,10 +239,18 @@ pub fn init(cx: &mut App) {
 
     cx.on_action(|open_recent: &OpenRecent, cx| {
         let create_new_window = open_recent.create_new_window;
+        let filter = open_recent.filter;
         with_active_or_new_workspace(cx, move |workspace, window, cx| {
             let Some(recent_projects) = workspace.active_modal::<RecentProjects>(cx) else {
                 let focus_handle = workspace.focus_handle(cx);
-                RecentProjects::open(workspace, create_new_window, window, focus_handle, cx);
+                RecentProjects::open(
+                    workspace,
+                    create_new_window,
+                    filter,
+                    window,
+                    focus_handle,
+                    cx,
+                );
                 return;
             };
 
@@ -355,6 +404,16 @@ impl RecentProjects {
                 .await
                 .log_err()
                 .unwrap_or_default();
+            let (filter, fs) = this
+                .update(cx, |this, cx| {
+                    let delegate = &this.picker.read(cx).delegate;
+                    (delegate.filter, delegate.fs(cx))
+                })
+                .ok()?;
+            let workspaces = match fs {
+                Some(fs) => filter_workspaces(workspaces, filter, fs.as_ref()).await,
+                None => workspaces,
+            };
             this.update_in(cx, move |this, window, cx| {
                 this.picker.update(cx, move |picker, cx| {
                     picker.delegate.set_workspaces(workspaces);
@@ -374,13 +433,15 @@ impl RecentProjects {
     pub fn open(
         workspace: &mut Workspace,
         create_new_window: bool,
+        filter: RecentProjectsFilter,
         window: &mut Window,
         focus_handle: FocusHandle,
         cx: &mut Context<Workspace>,
     ) {
         let weak = cx.entity().downgrade();
         workspace.toggle_modal(window, cx, |window, cx| {
-            let delegate = RecentProjectsDelegate::new(weak, create_new_window, true, focus_handle);
+            let delegate =
+                RecentProjectsDelegate::new(weak, create_new_window, true, filter, focus_handle);
 
             Self::new(delegate, 34., window, cx)
         })
@@ -394,8 +455,13 @@ impl RecentProjects {
         cx: &mut App,
     ) -> Entity<Self> {
         cx.new(|cx| {
-            let delegate =
-                RecentProjectsDelegate::new(workspace, create_new_window, true, focus_handle);
+            let delegate = RecentProjectsDelegate::new(
+                workspace,
+                create_new_window,
+                true,
+                RecentProjectsFilter::All,
+                focus_handle,
+            );
             let list = Self::new(delegate, 34., window, cx);
             list.picker.focus_handle(cx).focus(window, cx);
             list
@@ -432,6 +498,7 @@ pub struct RecentProjectsDelegate {
     matches: Vec<StringMatch>,
     render_paths: bool,
     create_new_window: bool,
+    filter: RecentProjectsFilter,
     // Flag to reset index when there is a new query vs not reset index when user delete an item
     reset_selected_match_index: bool,
     has_any_non_local_projects: bool,
@@ -443,6 +510,7 @@ impl RecentProjectsDelegate {
         workspace: WeakEntity<Workspace>,
         create_new_window: bool,
         render_paths: bool,
+        filter: RecentProjectsFilter,
         focus_handle: FocusHandle,
     ) -> Self {
         Self {
@@ -451,6 +519,7 @@ impl RecentProjectsDelegate {
             selected_match_index: 0,
             matches: Default::default(),
             create_new_window,
+            filter,
             render_paths,
             reset_selected_match_index: true,
             has_any_non_local_projects: false,
@@ -458,6 +527,11 @@ impl RecentProjectsDelegate {
         }
     }
 
+    fn fs(&self, cx: &App) -> Option<Arc<dyn Fs>> {
+        let workspace = self.workspace.upgrade()?;
+        Some(workspace.read(cx).app_state().fs.clone())
+    }
+
     pub fn set_workspaces(
         &mut self,
         workspaces: Vec<(WorkspaceId, SerializedWorkspaceLocation, PathList)>,
@@ -890,6 +964,13 @@ impl RecentProjectsDelegate {
                     .recent_workspaces_on_disk()
                     .await
                     .unwrap_or_default();
+                let (filter, fs) = this.update(cx, |picker, cx| {
+                    (picker.delegate.filter, picker.delegate.fs(cx))
+                })?;
+                let workspaces = match fs {
+                    Some(fs) => filter_workspaces(workspaces, filter, fs.as_ref()).await,
+                    None => workspaces,
+                };
                 this.update_in(cx, move |picker, window, cx| {
                     picker.delegate.set_workspaces(workspaces);
                     picker
@@ -1063,6 +1144,47 @@ mod tests {
             .unwrap();
     }
 
+    #[gpui::test]
+    async fn test_filter_recent_workspaces(cx: &mut TestAppContext) {
+        let app_state = init_test(cx);
+        app_state
+            .fs
+            .as_fake()
+            .insert_tree(
+                path!("/root"),
+                json!({
+                    "notes.txt": "",
+                    "project": {
+                        "main.rs": ""
+                    }
+                }),
+            )
+            .await;
+
+        let file = (
+            WorkspaceId::default(),
+            SerializedWorkspaceLocation::Local,
+            PathList::new(&[path!("/root/notes.txt")]),
+        );
+        let folder = (
+            WorkspaceId::default(),
+            SerializedWorkspaceLocation::Local,
+            PathList::new(&[path!("/root/project")]),
+        );
+        let workspaces = vec![file.clone(), folder.clone()];
+        let fs = app_state.fs.as_ref();
+
+        let files = filter_workspaces(workspaces.clone(), RecentProjectsFilter::Files, fs).await;
+        assert_eq!(files, vec![file.clone()]);
+
+        let folders =
+            filter_workspaces(workspaces.clone(), RecentProjectsFilter::Folders, fs).await;
+        assert_eq!(folders, vec![folder.clone()]);
+
+        let all = filter_workspaces(workspaces, RecentProjectsFilter::All, fs).await;
+        assert_eq!(all, vec![file, folder]);
+    }
+
     fn open_recent_projects(
         workspace: &WindowHandle<Workspace>,
         cx: &mut TestAppContext,
@@ -1071,6 +1193,7 @@ mod tests {
             (*workspace).into(),
             OpenRecent {
                 create_new_window: false,
+                ..Default::default()
             },
         );
         workspace
@@ -179,6 +179,7 @@ impl VsCodeSettings {
             base_keymap: Some(BaseKeymapContent::VSCode),
             calls: None,
             collaboration_panel: None,
+            custom_panel: None,
             debugger: None,
             diagnostics: None,
             disable_ai: None,
@@ -216,6 +217,7 @@ impl VsCodeSettings {
             terminal: self.terminal_settings_content(),
             theme: Box::new(self.theme_settings_content()),
             title_bar: None,
+            ui_language: None,
             vim: None,
             vim_mode: None,
             workspace: self.workspace_settings_content(),
@@ -969,6 +971,8 @@ impl VsCodeSettings {
             on_last_window_closed: None,
             pane_split_direction_horizontal: None,
             pane_split_direction_vertical: None,
+            related_file_patterns: None,
+            presentation_mode_font_scale: None,
             resize_all_panels_in_dock: None,
             restore_on_file_reopen: self.read_bool("workbench.editor.restoreViewState"),
             restore_on_startup: None,
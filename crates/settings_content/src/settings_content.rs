@@ -28,7 +28,7 @@ pub use terminal::*;
 pub use theme::*;
 pub use workspace::*;
 
-use collections::{HashMap, IndexMap};
+use collections::{BTreeMap, HashMap, IndexMap};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use settings_macros::{MergeFrom, with_fallible_options};
@@ -128,6 +128,9 @@ pub struct SettingsContent {
     /// Configuration for the collab panel visual settings.
     pub collaboration_panel: Option<PanelSettingsContent>,
 
+    /// Configuration for the custom panel.
+    pub custom_panel: Option<CustomPanelSettingsContent>,
+
     pub debugger: Option<DebuggerSettingsContent>,
 
     /// Configuration for Diagnostics-related features.
@@ -189,6 +192,11 @@ pub struct SettingsContent {
 
     pub title_bar: Option<TitleBarSettingsContent>,
 
+    /// The language of Zed's user interface, such as `"en"` or `"zh-CN"`.
+    ///
+    /// Default: the language of the operating system, or English if it is not supported
+    pub ui_language: Option<String>,
+
     /// Whether or not to enable Vim mode.
     ///
     /// Default: false
@@ -456,6 +464,11 @@ pub struct DebuggerSettingsContent {
     ///
     /// Default: Bottom
     pub dock: Option<DockPosition>,
+    /// Whether running a task tagged "run" starts it under the debugger instead
+    /// of in the terminal. Tasks that no debug adapter can debug run normally.
+    ///
+    /// Default: false
+    pub auto_attach_on_run: Option<bool>,
 }
 
 /// The granularity of one 'step' in the stepping requests `next`, `stepIn`, `stepOut`, and `stepBack`.
@@ -631,6 +644,45 @@ pub struct NotificationPanelSettingsContent {
     pub default_width: Option<f32>,
 }
 
+#[with_fallible_options]
+#[derive(Clone, Default, Serialize, Deserialize, JsonSchema, MergeFrom, Debug, PartialEq)]
+pub struct CustomPanelSettingsContent {
+    /// Whether moving the selection with the keyboard immediately activates
+    /// the newly selected item, instead of waiting for enter.
+    ///
+    /// Default: false
+    pub focus_follows_selection: Option<bool>,
+    /// Where to dock the panel.
+    ///
+    /// Default: left
+    pub dock: Option<DockPosition>,
+    /// Default width of the panel in pixels, used until the panel is resized
+    /// in a workspace.
+    ///
+    /// Default: 280
+    #[serde(serialize_with = "crate::serialize_optional_f32_with_two_decimal_places")]
+    pub default_width: Option<f32>,
+    /// The icon shown for the panel in the status bar, as an icon name such as
+    /// "star" or "file_tree". Unknown names fall back to "star".
+    ///
+    /// Default: "star"
+    pub icon: Option<String>,
+    /// The translation key of the title shown in the panel's header, so
+    /// deployments can point the panel at their own strings.
+    ///
+    /// Default: "custom_panel.title"
+    pub title_key: Option<String>,
+    /// The translation key of the panel's greeting entry.
+    ///
+    /// Default: "custom_panel.hello"
+    pub hello_key: Option<String>,
+    /// Named filters that can be re-applied from the panel's saved filters
+    /// menu, mapping each name to its filter text.
+    ///
+    /// Default: {}
+    pub saved_filters: Option<BTreeMap<String, String>>,
+}
+
 #[with_fallible_options]
 #[derive(Clone, Default, Serialize, Deserialize, JsonSchema, MergeFrom, Debug, PartialEq)]
 pub struct PanelSettingsContent {
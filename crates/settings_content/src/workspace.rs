@@ -116,6 +116,16 @@ pub struct WorkspaceSettingsContent {
     /// What draws window decorations/titlebar, the client application (Zed) or display server
     /// Default: client
     pub window_decorations: Option<WindowDecorations>,
+    /// Groups of file name patterns that "Go to Related File" jumps between.
+    /// `{name}` stands for the part of the file name shared by the group, e.g.
+    /// `["{name}.rs", "{name}_test.rs"]` relates `foo.rs` and `foo_test.rs`.
+    ///
+    /// Default: source/header and source/test patterns for common languages
+    pub related_file_patterns: Option<Vec<Vec<String>>>,
+    /// The factor by which presentation mode scales the buffer and UI fonts.
+    ///
+    /// Default: 1.5
+    pub presentation_mode_font_scale: Option<f32>,
 }
 
 #[with_fallible_options]
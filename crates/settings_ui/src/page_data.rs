@@ -5402,7 +5402,7 @@ fn panels_page() -> SettingsPage {
 }
 
 fn debugger_page() -> SettingsPage {
-    fn general_section() -> [SettingsPageItem; 6] {
+    fn general_section() -> [SettingsPageItem; 7] {
         [
             SettingsPageItem::SectionHeader("General"),
             SettingsPageItem::SettingItem(SettingItem {
@@ -5506,6 +5506,28 @@ fn debugger_page() -> SettingsPage {
                 metadata: None,
                 files: USER,
             }),
+            SettingsPageItem::SettingItem(SettingItem {
+                title: "Auto-Attach Debugger on Run",
+                description: "Whether running a task tagged \"run\" starts it under the debugger.",
+                field: Box::new(SettingField {
+                    json_path: Some("debugger.auto_attach_on_run"),
+                    pick: |settings_content| {
+                        settings_content
+                            .debugger
+                            .as_ref()?
+                            .auto_attach_on_run
+                            .as_ref()
+                    },
+                    write: |settings_content, value| {
+                        settings_content
+                            .debugger
+                            .get_or_insert_default()
+                            .auto_attach_on_run = value;
+                    },
+                }),
+                metadata: None,
+                files: USER,
+            }),
         ]
     }
 
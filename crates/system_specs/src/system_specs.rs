@@ -27,6 +27,7 @@ pub struct SystemSpecs {
     commit_sha: Option<String>,
     bundle_type: Option<String>,
     gpu_specs: Option<String>,
+    language: &'static str,
 }
 
 impl SystemSpecs {
@@ -47,6 +48,8 @@ impl SystemSpecs {
         };
         let bundle_type = bundle_type();
 
+        let language = i18n::get_language().as_str();
+
         let gpu_specs = window.gpu_specs().map(|specs| {
             format!(
                 "{} || {} || {}",
@@ -66,6 +69,7 @@ impl SystemSpecs {
                 architecture,
                 commit_sha,
                 gpu_specs,
+                language,
             }
         })
     }
@@ -98,6 +102,7 @@ impl SystemSpecs {
             commit_sha,
             bundle_type,
             gpu_specs: try_determine_available_gpus(),
+            language: i18n::get_language().as_str(),
         }
     }
 }
@@ -128,6 +133,7 @@ impl Display for SystemSpecs {
             os_information,
             format!("Memory: {}", human_bytes(self.memory as f64)),
             format!("Architecture: {}", self.architecture),
+            format!("Language: {}", self.language),
         ]
         .into_iter()
         .chain(
@@ -293,3 +299,31 @@ fn bundle_type() -> Option<String> {
         .map(|bundle_type| bundle_type.to_string())
         .or_else(|| env::var("ZED_BUNDLE_TYPE").ok())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_system_specs_display() {
+        let specs = SystemSpecs {
+            app_version: "1.2.3".to_string(),
+            release_channel: "Stable",
+            os_name: "macOS".to_string(),
+            os_version: "15.0".to_string(),
+            memory: 16 * 1024 * 1024 * 1024,
+            architecture: "aarch64",
+            commit_sha: None,
+            bundle_type: None,
+            gpu_specs: Some("Apple M1".to_string()),
+            language: i18n::Language::Japanese.as_str(),
+        };
+        let specs = specs.to_string();
+
+        assert!(specs.contains("Zed: v1.2.3 (Stable)"));
+        assert!(specs.contains("OS: macOS 15.0"));
+        assert!(specs.contains("Architecture: aarch64"));
+        assert!(specs.contains("Language: ja"));
+        assert!(specs.contains("GPU: Apple M1"));
+    }
+}
@@ -932,6 +932,77 @@ mod tests {
         );
     }
 
+    #[gpui::test]
+    async fn test_spawn_by_tag(cx: &mut TestAppContext) {
+        init_test(cx);
+        let fs = FakeFs::new(cx.executor());
+        fs.insert_tree(
+            path!("/dir"),
+            json!({
+                ".zed": {
+                    "tasks.json": r#"[
+                        {
+                            "label": "run tests",
+                            "command": "cargo",
+                            "args": ["test"],
+                            "tags": ["test"]
+                        },
+                        {
+                            "label": "run app",
+                            "command": "cargo",
+                            "args": ["run"],
+                            "tags": ["run"]
+                        },
+                    ]"#,
+                },
+                "a.ts": "a"
+            }),
+        )
+        .await;
+
+        let project = Project::test(fs, [path!("/dir").as_ref()], cx).await;
+        let (workspace, cx) =
+            cx.add_window_view(|window, cx| Workspace::test_new(project.clone(), window, cx));
+
+        cx.dispatch_action(Spawn::ByTag {
+            task_tag: "test".to_string(),
+            reveal_target: None,
+        });
+        cx.run_until_parked();
+        let last_scheduled = project.update(cx, |project, cx| {
+            project
+                .task_store()
+                .read(cx)
+                .task_inventory()
+                .unwrap()
+                .read(cx)
+                .last_scheduled_task(None)
+                .map(|(_, task)| task.resolved_label)
+        });
+        assert_eq!(last_scheduled.as_deref(), Some("run tests"));
+        assert!(
+            workspace.read_with(cx, |workspace, cx| workspace
+                .active_modal::<TasksModal>(cx)
+                .is_none()),
+            "Tagged task should be spawned without opening the modal"
+        );
+
+        cx.dispatch_action(Spawn::ByTag {
+            task_tag: "build".to_string(),
+            reveal_target: None,
+        });
+        cx.run_until_parked();
+        let tasks_picker = workspace.update(cx, |workspace, cx| {
+            workspace
+                .active_modal::<TasksModal>(cx)
+                .expect("modal should be open when no task has the tag")
+                .read(cx)
+                .picker
+                .clone()
+        });
+        assert_eq!(query(&tasks_picker, cx), "build");
+    }
+
     #[gpui::test]
     async fn test_basic_context_for_simple_files(cx: &mut TestAppContext) {
         init_test(cx);
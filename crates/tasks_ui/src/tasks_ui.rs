@@ -125,14 +125,7 @@ fn spawn_task_or_modal(
             let overrides = reveal_target.map(|reveal_target| TaskOverrides {
                 reveal_target: Some(reveal_target),
             });
-            let tag = task_tag.clone();
-            spawn_tasks_filtered(
-                move |(_, task)| task.tags.contains(&tag),
-                overrides,
-                window,
-                cx,
-            )
-            .detach_and_log_err(cx)
+            spawn_tasks_with_tag(task_tag.clone(), overrides, window, cx).detach_and_log_err(cx)
         }
         Spawn::ViaModal { reveal_target } => {
             toggle_modal(workspace, *reveal_target, window, cx).detach()
@@ -145,6 +138,16 @@ pub fn toggle_modal(
     reveal_target: Option<RevealTarget>,
     window: &mut Window,
     cx: &mut Context<Workspace>,
+) -> Task<()> {
+    toggle_modal_with_query(workspace, reveal_target, None, window, cx)
+}
+
+fn toggle_modal_with_query(
+    workspace: &mut Workspace,
+    reveal_target: Option<RevealTarget>,
+    query: Option<String>,
+    window: &mut Window,
+    cx: &mut Context<Workspace>,
 ) -> Task<()> {
     let task_store = workspace.project().read(cx).task_store().clone();
     let workspace_handle = workspace.weak_handle();
@@ -169,7 +172,13 @@ pub fn toggle_modal(
                             window,
                             cx,
                         )
-                    })
+                    });
+                    if let Some(query) = query
+                        && let Some(modal) = workspace.active_modal::<TasksModal>(cx)
+                    {
+                        let picker = modal.read(cx).picker.clone();
+                        picker.update(cx, |picker, cx| picker.set_query(&query, window, cx));
+                    }
                 })
                 .ok();
         })
@@ -178,9 +187,41 @@ pub fn toggle_modal(
     }
 }
 
+/// Spawns every task tagged with `tag`.
+///
+/// When no such task exists, opens the tasks modal with the tag as its query instead.
+pub fn spawn_tasks_with_tag(
+    tag: String,
+    overrides: Option<TaskOverrides>,
+    window: &mut Window,
+    cx: &mut Context<Workspace>,
+) -> Task<anyhow::Result<()>> {
+    let predicate_tag = tag.clone();
+    spawn_tasks_filtered_or_query(
+        move |(_, task)| task.tags.contains(&predicate_tag),
+        overrides,
+        Some(tag),
+        window,
+        cx,
+    )
+}
+
 pub fn spawn_tasks_filtered<F>(
+    predicate: F,
+    overrides: Option<TaskOverrides>,
+    window: &mut Window,
+    cx: &mut Context<Workspace>,
+) -> Task<anyhow::Result<()>>
+where
+    F: FnMut((&TaskSourceKind, &TaskTemplate)) -> bool + 'static,
+{
+    spawn_tasks_filtered_or_query(predicate, overrides, None, window, cx)
+}
+
+fn spawn_tasks_filtered_or_query<F>(
     mut predicate: F,
     overrides: Option<TaskOverrides>,
+    fallback_query: Option<String>,
     window: &mut Window,
     cx: &mut Context<Workspace>,
 ) -> Task<anyhow::Result<()>>
@@ -250,18 +291,25 @@ where
             })?
             .is_some();
         if !did_spawn {
-            workspace
-                .update_in(cx, |workspace, window, cx| {
-                    spawn_task_or_modal(
-                        workspace,
-                        &Spawn::ViaModal {
-                            reveal_target: overrides.and_then(|overrides| overrides.reveal_target),
-                        },
-                        window,
-                        cx,
-                    );
-                })
-                .ok();
+            let reveal_target = overrides.and_then(|overrides| overrides.reveal_target);
+            if let Some(query) = fallback_query {
+                workspace
+                    .update_in(cx, |workspace, window, cx| {
+                        toggle_modal_with_query(workspace, reveal_target, Some(query), window, cx)
+                    })?
+                    .await;
+            } else {
+                workspace
+                    .update_in(cx, |workspace, window, cx| {
+                        spawn_task_or_modal(
+                            workspace,
+                            &Spawn::ViaModal { reveal_target },
+                            window,
+                            cx,
+                        );
+                    })
+                    .ok();
+            }
         }
 
         Ok(())
@@ -27,6 +27,7 @@ use gpui::{
     InteractiveElement, IntoElement, MouseButton, ParentElement, Render,
     StatefulInteractiveElement, Styled, Subscription, WeakEntity, Window, actions, div,
 };
+use i18n::t_static;
 use onboarding_banner::OnboardingBanner;
 use project::{Project, git_store::GitStoreEvent, trusted_worktrees::TrustedWorktrees};
 use project_dropdown::ProjectDropdown;
@@ -41,7 +42,6 @@ use ui::{
     PopoverMenuHandle, TintColor, Tooltip, prelude::*,
 };
 use util::ResultExt;
-use i18n::t_static;
 use workspace::{SwitchProject, ToggleWorktreeSecurity, Workspace, notifications::NotifyResultExt};
 use zed_actions::OpenRemote;
 
@@ -687,6 +687,7 @@ impl TitleBar {
                         "Recent Projects",
                         &zed_actions::OpenRecent {
                             create_new_window: false,
+                            ..Default::default()
                         },
                         cx,
                     )
@@ -0,0 +1,201 @@
+use fuzzy::{StringMatch, StringMatchCandidate, match_strings};
+use gpui::{
+    App, Context, DismissEvent, Entity, EventEmitter, FocusHandle, Focusable, Render, WeakEntity,
+    Window,
+};
+use i18n::Language;
+use picker::{Picker, PickerDelegate};
+use std::sync::Arc;
+use ui::{HighlightedLabel, ListItem, ListItemSpacing, prelude::*, v_flex};
+use util::ResultExt;
+use workspace::{ModalView, Workspace};
+use zed_actions::ui_language_selector::SelectLanguage;
+
+pub fn init(cx: &mut App) {
+    cx.observe_new(UiLanguageSelector::register).detach();
+}
+
+/// A picker listing the languages Zed's user interface is translated into.
+pub struct UiLanguageSelector {
+    picker: Entity<Picker<UiLanguageSelectorDelegate>>,
+}
+
+impl UiLanguageSelector {
+    fn register(
+        workspace: &mut Workspace,
+        _window: Option<&mut Window>,
+        _: &mut Context<Workspace>,
+    ) {
+        workspace.register_action(|workspace, _: &SelectLanguage, window, cx| {
+            Self::toggle(workspace, window, cx);
+        });
+    }
+
+    pub fn toggle(workspace: &mut Workspace, window: &mut Window, cx: &mut Context<Workspace>) {
+        workspace.toggle_modal(window, cx, |window, cx| UiLanguageSelector::new(window, cx));
+    }
+
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let delegate = UiLanguageSelectorDelegate::new(cx.entity().downgrade());
+        let picker = cx.new(|cx| Picker::uniform_list(delegate, window, cx));
+        Self { picker }
+    }
+}
+
+impl Render for UiLanguageSelector {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .key_context("UiLanguageSelector")
+            .w(rems(34.))
+            .child(self.picker.clone())
+    }
+}
+
+impl Focusable for UiLanguageSelector {
+    fn focus_handle(&self, cx: &App) -> FocusHandle {
+        self.picker.focus_handle(cx)
+    }
+}
+
+impl EventEmitter<DismissEvent> for UiLanguageSelector {}
+impl ModalView for UiLanguageSelector {}
+
+pub struct UiLanguageSelectorDelegate {
+    selector: WeakEntity<UiLanguageSelector>,
+    /// The language in use when the picker was opened, marked with a check.
+    current_language: Language,
+    match_candidates: Arc<Vec<StringMatchCandidate>>,
+    matches: Vec<StringMatch>,
+    selected_index: usize,
+}
+
+impl UiLanguageSelectorDelegate {
+    fn new(selector: WeakEntity<UiLanguageSelector>) -> Self {
+        let current_language = i18n::get_language();
+        let match_candidates = Language::all()
+            .iter()
+            .enumerate()
+            .map(|(id, language)| StringMatchCandidate::new(id, language.native_name()))
+            .collect::<Vec<_>>();
+        // Start on the active language, so confirming right away keeps it.
+        let selected_index = Language::all()
+            .iter()
+            .position(|language| *language == current_language)
+            .unwrap_or_default();
+        Self {
+            selector,
+            current_language,
+            match_candidates: Arc::new(match_candidates),
+            matches: Vec::new(),
+            selected_index,
+        }
+    }
+}
+
+impl PickerDelegate for UiLanguageSelectorDelegate {
+    type ListItem = ListItem;
+
+    fn placeholder_text(&self, _window: &mut Window, _cx: &mut App) -> Arc<str> {
+        i18n::t("language.select_placeholder").into()
+    }
+
+    fn match_count(&self) -> usize {
+        self.matches.len()
+    }
+
+    fn selected_index(&self) -> usize {
+        self.selected_index
+    }
+
+    fn set_selected_index(
+        &mut self,
+        ix: usize,
+        _window: &mut Window,
+        _: &mut Context<Picker<Self>>,
+    ) {
+        self.selected_index = ix;
+    }
+
+    fn update_matches(
+        &mut self,
+        query: String,
+        window: &mut Window,
+        cx: &mut Context<Picker<Self>>,
+    ) -> Task<()> {
+        let background = cx.background_executor().clone();
+        let candidates = self.match_candidates.clone();
+
+        cx.spawn_in(window, async move |this, cx| {
+            let matches = if query.is_empty() {
+                candidates
+                    .iter()
+                    .map(|candidate| StringMatch {
+                        candidate_id: candidate.id,
+                        string: candidate.string.clone(),
+                        positions: Vec::new(),
+                        score: 0.0,
+                    })
+                    .collect()
+            } else {
+                match_strings(
+                    &candidates,
+                    &query,
+                    false,
+                    true,
+                    100,
+                    &Default::default(),
+                    background,
+                )
+                .await
+            };
+
+            this.update(cx, |this, cx| {
+                let delegate = &mut this.delegate;
+                delegate.matches = matches;
+                delegate.selected_index = delegate
+                    .selected_index
+                    .min(delegate.matches.len().saturating_sub(1));
+                cx.notify();
+            })
+            .log_err();
+        })
+    }
+
+    fn confirm(&mut self, _: bool, window: &mut Window, cx: &mut Context<Picker<Self>>) {
+        if let Some(mat) = self.matches.get(self.selected_index) {
+            i18n::persist_language(Language::all()[mat.candidate_id], cx);
+        }
+        self.dismissed(window, cx);
+    }
+
+    fn dismissed(&mut self, _: &mut Window, cx: &mut Context<Picker<Self>>) {
+        self.selector
+            .update(cx, |_, cx| cx.emit(DismissEvent))
+            .log_err();
+    }
+
+    fn render_match(
+        &self,
+        ix: usize,
+        selected: bool,
+        _: &mut Window,
+        _: &mut Context<Picker<Self>>,
+    ) -> Option<Self::ListItem> {
+        let mat = self.matches.get(ix)?;
+        let is_current = Language::all()[mat.candidate_id] == self.current_language;
+
+        Some(
+            ListItem::new(ix)
+                .inset(true)
+                .spacing(ListItemSpacing::Sparse)
+                .toggle_state(selected)
+                .child(HighlightedLabel::new(
+                    mat.string.clone(),
+                    mat.positions.clone(),
+                ))
+                .when(is_current, |item| {
+                    item.end_slot(Icon::new(IconName::Check).size(IconSize::Small))
+                }),
+        )
+    }
+}
@@ -1388,6 +1388,8 @@ mod element {
                 .inactive_opacity
                 .map(|val| val.0.clamp(0.0, 1.0))
                 .and_then(|val| (val <= 1.).then_some(val));
+            let overlay_opacity =
+                crate::presentation_mode::inactive_pane_opacity(overlay_opacity, cx);
 
             let mut overlay_background = cx.theme().colors().editor_background;
             if let Some(opacity) = overlay_opacity {
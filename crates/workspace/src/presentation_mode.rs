@@ -0,0 +1,140 @@
+//! Presentation mode scales up the buffer and UI fonts and hides visual noise,
+//! such as the minimap and breadcrumbs, for screen sharing and teaching.
+//!
+//! Unlike centered layout, which only changes where the editor is laid out,
+//! presentation mode is about legibility. It is not persisted: toggling it off
+//! restores the font sizes that were in effect when it was turned on.
+
+use gpui::{App, Global, Pixels};
+use settings::Settings as _;
+use theme::ThemeSettings;
+
+use crate::WorkspaceSettings;
+
+/// The opacity of inactive panes while presenting, unless the user already
+/// dims them further.
+const INACTIVE_PANE_OPACITY: f32 = 0.6;
+
+/// Whether presentation mode is on, and the font sizes to restore when it is
+/// turned off.
+#[derive(Default)]
+pub struct PresentationMode {
+    saved_font_sizes: Option<SavedFontSizes>,
+}
+
+impl Global for PresentationMode {}
+
+/// Runtime font size adjustments in effect before presentation mode was turned
+/// on, `None` meaning the size came from the settings.
+struct SavedFontSizes {
+    buffer: Option<Pixels>,
+    ui: Option<Pixels>,
+}
+
+/// Whether presentation mode is on.
+pub fn presentation_mode_active(cx: &App) -> bool {
+    cx.try_global::<PresentationMode>()
+        .is_some_and(|mode| mode.saved_font_sizes.is_some())
+}
+
+/// Turns presentation mode on or off.
+pub fn toggle_presentation_mode(cx: &mut App) {
+    let saved_font_sizes = cx
+        .default_global::<PresentationMode>()
+        .saved_font_sizes
+        .take();
+    match saved_font_sizes {
+        Some(saved) => {
+            match saved.buffer {
+                Some(size) => theme::adjust_buffer_font_size(cx, |_| size),
+                None => theme::reset_buffer_font_size(cx),
+            }
+            match saved.ui {
+                Some(size) => theme::adjust_ui_font_size(cx, |_| size),
+                None => theme::reset_ui_font_size(cx),
+            }
+        }
+        None => {
+            let theme_settings = ThemeSettings::get_global(cx);
+            let buffer = theme_settings.buffer_font_size(cx);
+            let ui = theme_settings.ui_font_size(cx);
+            let default_buffer = theme::clamp_font_size(theme_settings.buffer_font_size_settings());
+            let default_ui = theme::clamp_font_size(theme_settings.ui_font_size_settings());
+            let saved = SavedFontSizes {
+                buffer: (buffer != default_buffer).then_some(buffer),
+                ui: (ui != default_ui).then_some(ui),
+            };
+
+            let scale = WorkspaceSettings::get_global(cx).presentation_mode_font_scale;
+            theme::adjust_buffer_font_size(cx, |size| size * scale);
+            theme::adjust_ui_font_size(cx, |size| size * scale);
+            cx.default_global::<PresentationMode>().saved_font_sizes = Some(saved);
+        }
+    }
+    // Notify observers, such as editors hiding their minimap and breadcrumbs.
+    cx.update_global::<PresentationMode, _>(|_, _| {});
+    cx.refresh_windows();
+}
+
+/// The opacity of the overlay dimming inactive panes, taking presentation mode
+/// into account.
+pub(crate) fn inactive_pane_opacity(settings_opacity: Option<f32>, cx: &App) -> Option<f32> {
+    if presentation_mode_active(cx) {
+        Some(settings_opacity.map_or(INACTIVE_PANE_OPACITY, |opacity| {
+            opacity.min(INACTIVE_PANE_OPACITY)
+        }))
+    } else {
+        settings_opacity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gpui::{TestAppContext, px};
+    use settings::SettingsStore;
+
+    fn font_sizes(cx: &TestAppContext) -> (Pixels, Pixels) {
+        cx.read(|cx| {
+            let theme_settings = ThemeSettings::get_global(cx);
+            (
+                theme_settings.buffer_font_size(cx),
+                theme_settings.ui_font_size(cx),
+            )
+        })
+    }
+
+    #[gpui::test]
+    fn test_presentation_mode_scales_and_restores(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            let settings_store = SettingsStore::test(cx);
+            cx.set_global(settings_store);
+            theme::init(theme::LoadThemes::JustBase, cx);
+        });
+
+        // A runtime adjustment made before presenting is restored afterwards.
+        cx.update(|cx| theme::adjust_ui_font_size(cx, |size| size + px(2.)));
+        let (buffer, ui) = font_sizes(cx);
+        let scale = cx.read(|cx| WorkspaceSettings::get_global(cx).presentation_mode_font_scale);
+        assert!(scale > 1.);
+
+        cx.update(toggle_presentation_mode);
+        assert!(cx.read(presentation_mode_active));
+        assert_eq!(
+            font_sizes(cx),
+            (
+                theme::clamp_font_size(buffer * scale),
+                theme::clamp_font_size(ui * scale)
+            )
+        );
+        cx.read(|cx| {
+            assert_eq!(inactive_pane_opacity(None, cx), Some(INACTIVE_PANE_OPACITY));
+            assert_eq!(inactive_pane_opacity(Some(0.2), cx), Some(0.2));
+        });
+
+        cx.update(toggle_presentation_mode);
+        assert!(!cx.read(presentation_mode_active));
+        assert_eq!(font_sizes(cx), (buffer, ui));
+        cx.read(|cx| assert_eq!(inactive_pane_opacity(None, cx), None));
+    }
+}
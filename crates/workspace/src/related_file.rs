@@ -0,0 +1,86 @@
+//! Resolution of "related" files, such as a header and its source file or a
+//! module and its tests, from the `related_file_patterns` setting.
+
+/// The placeholder in a related file pattern that stands for the part of the
+/// file name shared by all files in a group.
+const NAME_PLACEHOLDER: &str = "{name}";
+
+/// Returns the file names related to `file_name`, most specific match first.
+///
+/// Each group lists patterns such as `{name}.rs` and `{name}_test.rs`; a file
+/// matching one pattern in a group is related to the files produced by
+/// substituting its `{name}` into the group's other patterns. When several
+/// patterns match, those that leave the shortest `{name}` are preferred, so
+/// `foo_test.rs` resolves to `foo.rs` before `foo_test_test.rs`.
+pub fn related_file_names(file_name: &str, groups: &[Vec<String>]) -> Vec<String> {
+    let mut matches = Vec::new();
+    for group in groups {
+        for (ix, pattern) in group.iter().enumerate() {
+            if let Some(name) = match_pattern(pattern, file_name) {
+                matches.push((name, group, ix));
+            }
+        }
+    }
+    matches.sort_by_key(|(name, _, _)| name.len());
+
+    let mut related = Vec::new();
+    for (name, group, matched_ix) in matches {
+        for (ix, pattern) in group.iter().enumerate() {
+            if ix == matched_ix || !pattern.contains(NAME_PLACEHOLDER) {
+                continue;
+            }
+            let candidate = pattern.replacen(NAME_PLACEHOLDER, name, 1);
+            if candidate != file_name && !related.contains(&candidate) {
+                related.push(candidate);
+            }
+        }
+    }
+    related
+}
+
+/// Returns the `{name}` part of `file_name` if it matches `pattern`.
+fn match_pattern<'a>(pattern: &str, file_name: &'a str) -> Option<&'a str> {
+    let (prefix, suffix) = pattern.split_once(NAME_PLACEHOLDER)?;
+    let name = file_name.strip_prefix(prefix)?.strip_suffix(suffix)?;
+    (!name.is_empty()).then_some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn groups(groups: &[&[&str]]) -> Vec<Vec<String>> {
+        groups
+            .iter()
+            .map(|group| group.iter().map(|pattern| pattern.to_string()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_related_file_names() {
+        let groups = groups(&[
+            &["{name}.rs", "{name}_test.rs"],
+            &["{name}.h", "{name}.c", "{name}.cpp"],
+            &["{name}.py", "test_{name}.py"],
+        ]);
+
+        assert_eq!(related_file_names("foo.rs", &groups), ["foo_test.rs"]);
+        assert_eq!(
+            related_file_names("foo_test.rs", &groups),
+            ["foo.rs", "foo_test_test.rs"]
+        );
+        assert_eq!(
+            related_file_names("widget.h", &groups),
+            ["widget.c", "widget.cpp"]
+        );
+        assert_eq!(
+            related_file_names("widget.cpp", &groups),
+            ["widget.h", "widget.c"]
+        );
+        assert_eq!(
+            related_file_names("test_app.py", &groups),
+            ["app.py", "test_test_app.py"]
+        );
+        assert!(related_file_names("README.md", &groups).is_empty());
+    }
+}
@@ -8,6 +8,8 @@ pub mod pane;
 pub mod pane_group;
 mod path_list;
 mod persistence;
+mod presentation_mode;
+pub mod related_file;
 pub mod searchable;
 mod security_modal;
 pub mod shared_screen;
@@ -74,6 +76,7 @@ pub use persistence::{
     model::{ItemId, SerializedWorkspaceLocation},
 };
 use postage::stream::Stream;
+pub use presentation_mode::{PresentationMode, presentation_mode_active, toggle_presentation_mode};
 use project::{
     DirectoryLister, Project, ProjectEntryId, ProjectPath, ResolvedPath, Worktree, WorktreeId,
     WorktreeSettings,
@@ -237,6 +240,8 @@ actions!(
         FollowNextCollaborator,
         /// Moves the focused panel to the next position.
         MoveFocusedPanelToNextPosition,
+        /// Moves the active item into a new window that shares the current project.
+        MoveItemToNewWindow,
         /// Creates a new file.
         NewFile,
         /// Creates a new file in a vertical split.
@@ -251,12 +256,18 @@ actions!(
         Open,
         /// Opens multiple files.
         OpenFiles,
+        /// Reveals the active file, or the project root when no file is open, in the system file manager.
+        OpenContainingFolder,
         /// Opens the current location in terminal.
         OpenInTerminal,
         /// Opens the component preview.
         OpenComponentPreview,
+        /// Opens the file related to the active one, such as its header, source or tests.
+        OpenRelatedFile,
         /// Reloads the active item.
         ReloadActiveItem,
+        /// Renames the active file on disk.
+        RenameFile,
         /// Resets the active dock to its default size.
         ResetActiveDockSize,
         /// Resets all open docks to their default sizes.
@@ -265,6 +276,8 @@ actions!(
         Reload,
         /// Saves the current file with a new name.
         SaveAs,
+        /// Writes a copy of the current file to a new path, keeping the buffer on the original file.
+        SaveCopyAs,
         /// Saves without formatting.
         SaveWithoutFormat,
         /// Shuts down all debug adapters.
@@ -279,6 +292,8 @@ actions!(
         ToggleEditPrediction,
         /// Toggles the left dock.
         ToggleLeftDock,
+        /// Toggles presentation mode, which enlarges fonts and hides visual noise.
+        TogglePresentationMode,
         /// Toggles the right dock.
         ToggleRightDock,
         /// Toggles zoom on the active pane.
@@ -399,6 +414,30 @@ actions!(
     ]
 );
 
+/// A category of symbols that the project symbols search can be restricted to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolKindFilter {
+    /// Functions, methods, and constructors.
+    Function,
+    /// Classes, structs, interfaces, and enums.
+    Class,
+    /// Variables, fields, and properties.
+    Variable,
+    /// Constants and enum members.
+    Constant,
+    /// Modules, namespaces, and packages.
+    Module,
+}
+
+/// Toggles the project symbols search, showing only symbols of the given kind.
+#[derive(PartialEq, Clone, Debug, Deserialize, JsonSchema, Action)]
+#[action(namespace = project_symbols, name = "ToggleFiltered")]
+#[serde(deny_unknown_fields)]
+pub struct ToggleProjectSymbolsFiltered {
+    pub kind: SymbolKindFilter,
+}
+
 /// Toggles the file finder interface.
 #[derive(Default, PartialEq, Eq, Clone, Deserialize, JsonSchema, Action)]
 #[action(namespace = file_finder, name = "Toggle")]
@@ -3255,6 +3294,207 @@ impl Workspace {
         })
     }
 
+    pub fn open_containing_folder(&mut self, cx: &mut Context<Self>) {
+        let project = self.project.read(cx);
+        let path = self
+            .active_project_path(cx)
+            .and_then(|project_path| project.absolute_path(&project_path, cx))
+            .or_else(|| {
+                project
+                    .visible_worktrees(cx)
+                    .next()
+                    .map(|worktree| worktree.read(cx).abs_path().to_path_buf())
+            });
+        if let Some(path) = path {
+            self.project
+                .update(cx, |project, cx| project.reveal_path(&path, cx));
+        }
+    }
+
+    /// Opens the first existing file related to the active one by the
+    /// `related_file_patterns` setting, looking next to the active file.
+    ///
+    /// Shows a toast when no related file exists.
+    pub fn open_related_file(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Task<Result<()>> {
+        let Some(project_path) = self.active_project_path(cx) else {
+            return Task::ready(Ok(()));
+        };
+        let Some(file_name) = project_path.path.file_name() else {
+            return Task::ready(Ok(()));
+        };
+        let directory = project_path.path.parent().unwrap_or(RelPath::empty());
+        let patterns = &WorkspaceSettings::get_global(cx).related_file_patterns;
+        let project = self.project.read(cx);
+        let related_path = related_file::related_file_names(file_name, patterns)
+            .into_iter()
+            .filter_map(|name| {
+                let path = directory.join(RelPath::unix(&name).ok()?);
+                Some(ProjectPath {
+                    worktree_id: project_path.worktree_id,
+                    path,
+                })
+            })
+            .find(|path| {
+                project
+                    .entry_for_path(path, cx)
+                    .is_some_and(|entry| entry.is_file())
+            });
+
+        let Some(related_path) = related_path else {
+            struct NoRelatedFile;
+
+            self.show_toast(
+                Toast::new(
+                    NotificationId::unique::<NoRelatedFile>(),
+                    format!("No related file found for {file_name}"),
+                )
+                .autohide(),
+                cx,
+            );
+            return Task::ready(Ok(()));
+        };
+        let open = self.open_path(related_path, None, true, window, cx);
+        cx.spawn(async move |_, _| {
+            open.await?;
+            Ok(())
+        })
+    }
+
+    pub fn rename_active_file(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Task<Result<()>> {
+        let project = self.project.clone();
+        let Some(project_path) = self.active_project_path(cx) else {
+            return Task::ready(Ok(()));
+        };
+        let Some(entry_id) = project
+            .read(cx)
+            .entry_for_path(&project_path, cx)
+            .map(|entry| entry.id)
+        else {
+            return Task::ready(Ok(()));
+        };
+
+        let suggested_name = project_path.path.file_name().map(|name| name.to_string());
+        let new_path = self.prompt_for_new_path(
+            DirectoryLister::Project(project.clone()),
+            suggested_name,
+            window,
+            cx,
+        );
+        cx.spawn_in(window, async move |_, cx| {
+            // A cancelled prompt or an empty selection both leave the file untouched.
+            let Some(new_abs_path) = new_path.await?.and_then(|paths| paths.into_iter().next())
+            else {
+                return Ok(());
+            };
+            let new_project_path = project
+                .read_with(cx, |project, cx| {
+                    project.find_project_path(&new_abs_path, cx)
+                })
+                .with_context(|| format!("{} is outside of the project", new_abs_path.display()))?;
+            if new_project_path == project_path {
+                return Ok(());
+            }
+            let target_exists = project.read_with(cx, |project, cx| {
+                project.entry_for_path(&new_project_path, cx).is_some()
+            });
+            if target_exists {
+                anyhow::bail!("{} already exists", new_abs_path.display());
+            }
+
+            project
+                .update(cx, |project, cx| {
+                    project.rename_entry(entry_id, new_project_path, cx)
+                })
+                .await?;
+            Ok(())
+        })
+    }
+
+    /// Writes the active buffer's contents to a newly chosen path, leaving the
+    /// buffer associated with its original file.
+    pub fn save_copy_of_active_item(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Task<Result<()>> {
+        let Some(buffer) = self
+            .active_project_path(cx)
+            .and_then(|project_path| self.project.read(cx).get_open_buffer(&project_path, cx))
+        else {
+            return Task::ready(Ok(()));
+        };
+        let buffer = buffer.read(cx);
+        let text = buffer.as_rope().clone();
+        let line_ending = buffer.line_ending();
+        let suggested_name = buffer.file().map(|file| file.file_name(cx).to_string());
+
+        let fs = self.app_state.fs.clone();
+        let new_path = self.prompt_for_new_path(
+            DirectoryLister::Project(self.project.clone()),
+            suggested_name,
+            window,
+            cx,
+        );
+        cx.spawn_in(window, async move |_, _| {
+            let Some(abs_path) = new_path.await?.and_then(|paths| paths.into_iter().next()) else {
+                return Ok(());
+            };
+            fs.save(&abs_path, &text, line_ending).await
+        })
+    }
+
+    /// Moves the active item into a new window for the same project.
+    ///
+    /// Does nothing when the item is the only one in the workspace, since the
+    /// window would be left empty, or when the item cannot be cloned into
+    /// another window.
+    pub fn move_active_item_to_new_window(
+        &mut self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Task<Result<Option<WindowHandle<Workspace>>>> {
+        let source_pane = self.active_pane.clone();
+        let Some(item) = source_pane.read(cx).active_item() else {
+            return Task::ready(Ok(None));
+        };
+        if self.panes.len() == 1 && source_pane.read(cx).items_len() == 1 {
+            return Task::ready(Ok(None));
+        }
+
+        let project = self.project.clone();
+        let app_state = self.app_state.clone();
+        let options = (app_state.build_window_options)(None, cx);
+        let new_window = match cx.open_window(options, move |window, cx| {
+            cx.new(|cx| Workspace::new(None, project, app_state, window, cx))
+        }) {
+            Ok(new_window) => new_window,
+            Err(error) => return Task::ready(Err(error)),
+        };
+        let clone = new_window.update(cx, |_, window, cx| item.clone_on_split(None, window, cx));
+
+        cx.spawn_in(window, async move |_, cx| {
+            let Some(clone) = clone?.await else {
+                new_window.update(cx, |_, window, _| window.remove_window())?;
+                return Ok(None);
+            };
+            new_window.update(cx, |workspace, window, cx| {
+                workspace.add_item_to_active_pane(clone, None, true, window, cx)
+            })?;
+            source_pane.update_in(cx, |pane, window, cx| {
+                pane.remove_item(item.item_id(), false, true, window, cx)
+            })?;
+            Ok(Some(new_window))
+        })
+    }
+
     pub fn close_inactive_items_and_panes(
         &mut self,
         action: &CloseInactiveTabsAndPanes,
@@ -6192,6 +6432,36 @@ impl Workspace {
                     .save_active_item(SaveIntent::SaveAs, window, cx)
                     .detach_and_prompt_err("Failed to save", window, cx, |_, _, _| None);
             }))
+            .on_action(cx.listener(|workspace, _: &SaveCopyAs, window, cx| {
+                workspace
+                    .save_copy_of_active_item(window, cx)
+                    .detach_and_prompt_err("Failed to save a copy", window, cx, |_, _, _| None);
+            }))
+            .on_action(
+                cx.listener(|workspace, _: &MoveItemToNewWindow, window, cx| {
+                    workspace
+                        .move_active_item_to_new_window(window, cx)
+                        .detach_and_log_err(cx);
+                }),
+            )
+            .on_action(cx.listener(|workspace, _: &OpenContainingFolder, _, cx| {
+                workspace.open_containing_folder(cx);
+            }))
+            .on_action(|_: &TogglePresentationMode, _, cx| {
+                toggle_presentation_mode(cx);
+            })
+            .on_action(cx.listener(|workspace, _: &OpenRelatedFile, window, cx| {
+                workspace
+                    .open_related_file(window, cx)
+                    .detach_and_prompt_err("Failed to open related file", window, cx, |_, _, _| {
+                        None
+                    });
+            }))
+            .on_action(cx.listener(|workspace, _: &RenameFile, window, cx| {
+                workspace
+                    .rename_active_file(window, cx)
+                    .detach_and_prompt_err("Failed to rename", window, cx, |_, _, _| None);
+            }))
             .on_action(
                 cx.listener(|workspace, _: &ActivatePreviousPane, window, cx| {
                     workspace.activate_previous_pane(window, cx)
@@ -9387,6 +9657,56 @@ mod tests {
         item3.read_with(cx, |item, _| assert_eq!(item.tab_detail.get(), Some(3)));
     }
 
+    #[gpui::test]
+    async fn test_cycling_through_windows(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let fs = FakeFs::new(cx.executor());
+        let mut windows = Vec::new();
+        for _ in 0..3 {
+            let project = Project::test(fs.clone(), [], cx).await;
+            windows.push(cx.add_window(|window, cx| Workspace::test_new(project, window, cx)));
+        }
+        let active_window_id =
+            |cx: &mut TestAppContext| cx.update(|cx| cx.active_window().map(|w| w.window_id()));
+
+        windows[0]
+            .update(cx, |_, window, _| window.activate_window())
+            .unwrap();
+        assert_eq!(active_window_id(cx), Some(windows[0].window_id()));
+
+        let mut active = 0;
+        for expected in [1, 2, 0] {
+            cx.dispatch_action(windows[active].into(), ActivateNextWindow);
+            assert_eq!(active_window_id(cx), Some(windows[expected].window_id()));
+            active = expected;
+        }
+        for expected in [2, 1, 0] {
+            cx.dispatch_action(windows[active].into(), ActivatePreviousWindow);
+            assert_eq!(active_window_id(cx), Some(windows[expected].window_id()));
+            active = expected;
+        }
+    }
+
+    #[gpui::test]
+    async fn test_cycling_through_a_single_window(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let fs = FakeFs::new(cx.executor());
+        let project = Project::test(fs, [], cx).await;
+        let window = cx.add_window(|window, cx| Workspace::test_new(project, window, cx));
+        window
+            .update(cx, |_, window, _| window.activate_window())
+            .unwrap();
+
+        cx.dispatch_action(window.into(), ActivateNextWindow);
+        cx.dispatch_action(window.into(), ActivatePreviousWindow);
+        assert_eq!(
+            cx.update(|cx| cx.active_window().map(|w| w.window_id())),
+            Some(window.window_id())
+        );
+    }
+
     #[gpui::test]
     async fn test_tracking_active_path(cx: &mut TestAppContext) {
         init_test(cx);
@@ -9524,6 +9844,58 @@ mod tests {
         assert!(!task.await.unwrap());
     }
 
+    #[gpui::test]
+    async fn test_move_item_to_new_window(cx: &mut TestAppContext) {
+        init_test(cx);
+
+        let fs = FakeFs::new(cx.executor());
+        let project = Project::test(fs, [], cx).await;
+        let (workspace, cx) =
+            cx.add_window_view(|window, cx| Workspace::test_new(project.clone(), window, cx));
+
+        let item1 = cx.new(|cx| TestItem::new(cx).with_label("one"));
+        workspace.update_in(cx, |workspace, window, cx| {
+            workspace.add_item_to_active_pane(Box::new(item1.clone()), None, true, window, cx)
+        });
+
+        // Moving the only item would leave the window empty, so it stays put.
+        let new_window = workspace
+            .update_in(cx, |workspace, window, cx| {
+                workspace.move_active_item_to_new_window(window, cx)
+            })
+            .await
+            .unwrap();
+        assert!(new_window.is_none());
+
+        let item2 = cx.new(|cx| TestItem::new(cx).with_label("two"));
+        workspace.update_in(cx, |workspace, window, cx| {
+            workspace.add_item_to_active_pane(Box::new(item2.clone()), None, true, window, cx)
+        });
+        let new_window = workspace
+            .update_in(cx, |workspace, window, cx| {
+                workspace.move_active_item_to_new_window(window, cx)
+            })
+            .await
+            .unwrap()
+            .unwrap();
+        cx.run_until_parked();
+
+        workspace.read_with(cx, |workspace, cx| {
+            let pane = workspace.active_pane().read(cx);
+            assert_eq!(pane.items_len(), 1);
+            assert_eq!(pane.active_item().unwrap().item_id(), item1.entity_id());
+        });
+        new_window
+            .read_with(cx, |new_workspace, cx| {
+                assert_eq!(new_workspace.project(), &project);
+                let pane = new_workspace.active_pane().read(cx);
+                assert_eq!(pane.items_len(), 1);
+                let moved = pane.active_item().unwrap().downcast::<TestItem>().unwrap();
+                assert_eq!(moved.read(cx).label, "two");
+            })
+            .unwrap();
+    }
+
     #[gpui::test]
     async fn test_close_window_with_serializable_items(cx: &mut TestAppContext) {
         init_test(cx);
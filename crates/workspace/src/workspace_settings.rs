@@ -34,6 +34,8 @@ pub struct WorkspaceSettings {
     pub use_system_window_tabs: bool,
     pub zoomed_padding: bool,
     pub window_decorations: settings::WindowDecorations,
+    pub related_file_patterns: Vec<Vec<String>>,
+    pub presentation_mode_font_scale: f32,
 }
 
 #[derive(Copy, Clone, PartialEq, Debug, Default)]
@@ -111,6 +113,8 @@ impl Settings for WorkspaceSettings {
             use_system_window_tabs: workspace.use_system_window_tabs.unwrap(),
             zoomed_padding: workspace.zoomed_padding.unwrap(),
             window_decorations: workspace.window_decorations.unwrap(),
+            related_file_patterns: workspace.related_file_patterns.clone().unwrap(),
+            presentation_mode_font_scale: workspace.presentation_mode_font_scale.unwrap(),
         }
     }
 }
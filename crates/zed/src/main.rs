@@ -432,13 +432,13 @@ fn main() {
         if let Some(app_commit_sha) = app_commit_sha {
             AppCommitSha::set_global(app_commit_sha, cx);
         }
-        
+
         // 初始化 settings
         settings::init(cx);
-        
+
         // 初始化 i18n 国际化系统
         i18n::init(cx);
-        
+
         zlog_settings::init(cx);
         handle_settings_file_changes(
             user_settings_file_rx,
@@ -683,6 +683,7 @@ fn main() {
         line_ending_selector::init(cx);
         toolchain_selector::init(cx);
         theme_selector::init(cx);
+        ui_language_selector::init(cx);
         settings_profile_selector::init(cx);
         language_tools::init(cx);
         call::init(app_state.client.clone(), app_state.user_store.clone(), cx);
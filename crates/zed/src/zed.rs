@@ -24,7 +24,7 @@ use client::zed_urls;
 use collections::VecDeque;
 use custom_panel::CustomPanel;
 use debugger_ui::debugger_panel::DebugPanel;
-use editor::{Editor, MultiBuffer};
+use editor::{Editor, EditorEvent, MultiBuffer};
 use extension_host::ExtensionStore;
 use feature_flags::{FeatureFlagAppExt as _, PanicFeatureFlag};
 use fs::Fs;
@@ -188,6 +188,9 @@ pub fn init(cx: &mut App) {
     .on_action(|_: &workspace::RevealLogInFileManager, cx| {
         cx.reveal_path(paths::log_file().as_path());
     })
+    .on_action(|_: &zed_actions::OpenExtensionsFolder, cx| {
+        open_extensions_folder(<dyn Fs>::global(cx), cx).detach_and_log_err(cx);
+    })
     .on_action(|_: &zed_actions::OpenLicenses, cx| {
         with_active_or_new_workspace(cx, |workspace, window, cx| {
             open_bundled_file(
@@ -371,6 +374,24 @@ pub fn initialize_workspace(
     })
     .detach();
 
+    // Checked menu items reflect the active editor, so rebuild the menus when its toggles change.
+    // Switching to another editor is handled by the workspace's `ActiveItemChanged` below.
+    cx.observe_new(|_: &mut Editor, _, cx| {
+        cx.subscribe_self(|_, event: &EditorEvent, cx| {
+            if matches!(
+                event,
+                EditorEvent::InlineValuesToggled | EditorEvent::IndentGuidesToggled
+            ) {
+                cx.defer(refresh_app_menus);
+            }
+        })
+        .detach();
+    })
+    .detach();
+
+    // Menu labels are translated when the menus are built, so rebuild them in the new language.
+    i18n::subscribe(cx, |_, cx| refresh_app_menus(cx)).detach();
+
     cx.observe_new(move |workspace: &mut Workspace, window, cx| {
         let Some(window) = window else {
             return;
@@ -385,6 +406,7 @@ pub fn initialize_workspace(
                 workspace::Event::PaneAdded(pane) => {
                     initialize_pane(workspace, pane, window, cx);
                 }
+                workspace::Event::ActiveItemChanged => cx.defer(refresh_app_menus),
                 workspace::Event::OpenBundledFile {
                     text,
                     title,
@@ -1417,6 +1439,18 @@ fn quit(_: &Quit, cx: &mut App) {
     .detach_and_log_err(cx);
 }
 
+fn open_extensions_folder(fs: Arc<dyn Fs>, cx: &mut App) -> Task<anyhow::Result<()>> {
+    let extensions_dir = paths::extensions_dir();
+    cx.spawn(async move |cx| {
+        // Nothing is installed yet on a fresh setup, so the directory may be missing.
+        fs.create_dir(extensions_dir)
+            .await
+            .with_context(|| format!("creating extensions directory {extensions_dir:?}"))?;
+        cx.update(|cx| cx.reveal_path(extensions_dir));
+        anyhow::Ok(())
+    })
+}
+
 fn open_log_file(workspace: &mut Workspace, window: &mut Window, cx: &mut Context<Workspace>) {
     const MAX_LINES: usize = 1000;
     let app_state = workspace.app_state();
@@ -1875,8 +1909,7 @@ fn reload_keymaps(cx: &mut App, mut user_key_bindings: Vec<KeyBinding>) {
     }
     cx.bind_keys(user_key_bindings);
 
-    let menus = app_menus(cx);
-    cx.set_menus(menus);
+    refresh_app_menus(cx);
     // On Windows, this is set in the `update_jump_list` method of the `HistoryManager`.
     #[cfg(not(target_os = "windows"))]
     cx.set_dock_menu(vec![gpui::MenuItem::action(
@@ -1887,6 +1920,12 @@ fn reload_keymaps(cx: &mut App, mut user_key_bindings: Vec<KeyBinding>) {
     keymap_editor::KeymapEventChannel::trigger_keymap_changed(cx);
 }
 
+/// Rebuilds the application menus so that checked items and labels reflect the current state.
+pub fn refresh_app_menus(cx: &mut App) {
+    let menus = app_menus(cx);
+    cx.set_menus(menus);
+}
+
 pub fn load_default_keymap(cx: &mut App) {
     let base_keymap = *BaseKeymap::get_global(cx);
     if base_keymap == BaseKeymap::None {
@@ -3573,6 +3612,268 @@ mod tests {
             .unwrap();
     }
 
+    #[gpui::test]
+    async fn test_ui_font_size_is_independent_of_buffer_font_size(cx: &mut TestAppContext) {
+        let app_state = init_test(cx);
+        let project = Project::test(app_state.fs.clone(), [], cx).await;
+        let window = cx.add_window(|window, cx| Workspace::test_new(project, window, cx));
+
+        let (initial_ui_font_size, initial_buffer_font_size) = cx.update(|cx| {
+            let settings = ThemeSettings::get_global(cx);
+            (settings.ui_font_size(cx), settings.buffer_font_size(cx))
+        });
+
+        cx.dispatch_action(
+            window.into(),
+            zed_actions::IncreaseUiFontSize { persist: false },
+        );
+        cx.update(|cx| {
+            let settings = ThemeSettings::get_global(cx);
+            assert_eq!(settings.ui_font_size(cx), initial_ui_font_size + px(1.0));
+            assert_eq!(settings.buffer_font_size(cx), initial_buffer_font_size);
+        });
+
+        cx.dispatch_action(
+            window.into(),
+            zed_actions::IncreaseBufferFontSize { persist: false },
+        );
+        cx.update(|cx| {
+            let settings = ThemeSettings::get_global(cx);
+            assert_eq!(settings.ui_font_size(cx), initial_ui_font_size + px(1.0));
+            assert_eq!(
+                settings.buffer_font_size(cx),
+                initial_buffer_font_size + px(1.0)
+            );
+        });
+
+        cx.dispatch_action(
+            window.into(),
+            zed_actions::ResetUiFontSize { persist: false },
+        );
+        cx.update(|cx| {
+            let settings = ThemeSettings::get_global(cx);
+            assert_eq!(settings.ui_font_size(cx), initial_ui_font_size);
+            assert_eq!(
+                settings.buffer_font_size(cx),
+                initial_buffer_font_size + px(1.0)
+            );
+        });
+    }
+
+    #[gpui::test]
+    async fn test_open_containing_folder(cx: &mut TestAppContext) {
+        let app_state = init_test(cx);
+        app_state
+            .fs
+            .as_fake()
+            .insert_tree(path!("/root"), json!({ "dir": { "a.txt": "a" } }))
+            .await;
+
+        let project = Project::test(app_state.fs.clone(), [path!("/root").as_ref()], cx).await;
+        let window = cx.add_window(|window, cx| Workspace::test_new(project, window, cx));
+
+        // Without an active file, the project root is revealed.
+        cx.dispatch_action(window.into(), workspace::OpenContainingFolder);
+        assert_eq!(cx.revealed_path(), Some(PathBuf::from(path!("/root"))));
+
+        window
+            .update(cx, |workspace, window, cx| {
+                workspace.open_paths(
+                    vec![PathBuf::from(path!("/root/dir/a.txt"))],
+                    OpenOptions {
+                        visible: Some(OpenVisible::All),
+                        ..Default::default()
+                    },
+                    None,
+                    window,
+                    cx,
+                )
+            })
+            .unwrap()
+            .await;
+        cx.dispatch_action(window.into(), workspace::OpenContainingFolder);
+        assert_eq!(
+            cx.revealed_path(),
+            Some(PathBuf::from(path!("/root/dir/a.txt")))
+        );
+    }
+
+    #[gpui::test]
+    async fn test_open_extensions_folder(cx: &mut TestAppContext) {
+        let app_state = init_test(cx);
+        let extensions_dir = paths::extensions_dir();
+        assert!(!app_state.fs.is_dir(extensions_dir).await);
+
+        cx.update(|cx| open_extensions_folder(app_state.fs.clone(), cx))
+            .await
+            .unwrap();
+        assert!(app_state.fs.is_dir(extensions_dir).await);
+        assert_eq!(cx.revealed_path(), Some(extensions_dir.clone()));
+    }
+
+    #[gpui::test]
+    async fn test_rename_active_file(cx: &mut TestAppContext) {
+        let app_state = init_test(cx);
+        app_state
+            .fs
+            .as_fake()
+            .insert_tree(path!("/root"), json!({ "a.txt": "a", "b.txt": "b" }))
+            .await;
+
+        let project = Project::test(app_state.fs.clone(), [path!("/root").as_ref()], cx).await;
+        let window = cx.add_window(|window, cx| Workspace::test_new(project, window, cx));
+        let workspace = window.root(cx).unwrap();
+
+        window
+            .update(cx, |workspace, window, cx| {
+                workspace.open_paths(
+                    vec![PathBuf::from(path!("/root/a.txt"))],
+                    OpenOptions {
+                        visible: Some(OpenVisible::All),
+                        ..Default::default()
+                    },
+                    None,
+                    window,
+                    cx,
+                )
+            })
+            .unwrap()
+            .await;
+        let editor = cx.read(|cx| {
+            let pane = workspace.read(cx).active_pane().read(cx);
+            let item = pane.active_item().unwrap();
+            item.downcast::<Editor>().unwrap()
+        });
+
+        // Renaming onto an existing file is rejected and leaves both files untouched.
+        let rename_task = window
+            .update(cx, |workspace, window, cx| {
+                workspace.rename_active_file(window, cx)
+            })
+            .unwrap();
+        cx.background_executor.run_until_parked();
+        cx.simulate_new_path_selection(|parent_dir| Some(parent_dir.join("b.txt")));
+        assert!(rename_task.await.is_err());
+        assert!(app_state.fs.is_file(Path::new(path!("/root/a.txt"))).await);
+
+        // Cancelling the prompt is a no-op.
+        let rename_task = window
+            .update(cx, |workspace, window, cx| {
+                workspace.rename_active_file(window, cx)
+            })
+            .unwrap();
+        cx.background_executor.run_until_parked();
+        cx.simulate_new_path_selection(|_| None);
+        rename_task.await.unwrap();
+        assert!(app_state.fs.is_file(Path::new(path!("/root/a.txt"))).await);
+
+        let rename_task = window
+            .update(cx, |workspace, window, cx| {
+                workspace.rename_active_file(window, cx)
+            })
+            .unwrap();
+        cx.background_executor.run_until_parked();
+        cx.simulate_new_path_selection(|parent_dir| {
+            assert_eq!(parent_dir, Path::new(path!("/root")));
+            Some(parent_dir.join("renamed.txt"))
+        });
+        rename_task.await.unwrap();
+        cx.run_until_parked();
+
+        assert!(!app_state.fs.is_file(Path::new(path!("/root/a.txt"))).await);
+        assert!(
+            app_state
+                .fs
+                .is_file(Path::new(path!("/root/renamed.txt")))
+                .await
+        );
+        cx.read(|cx| {
+            assert_eq!(editor.read(cx).title(cx), "renamed.txt");
+            assert_eq!(
+                editor.project_path(cx).unwrap().path.as_ref(),
+                rel_path("renamed.txt")
+            );
+        });
+    }
+
+    #[gpui::test]
+    async fn test_save_copy_of_active_item(cx: &mut TestAppContext) {
+        let app_state = init_test(cx);
+        app_state
+            .fs
+            .as_fake()
+            .insert_tree(path!("/root"), json!({ "a.txt": "a" }))
+            .await;
+
+        let project = Project::test(app_state.fs.clone(), [path!("/root").as_ref()], cx).await;
+        let window = cx.add_window(|window, cx| Workspace::test_new(project, window, cx));
+        let workspace = window.root(cx).unwrap();
+
+        window
+            .update(cx, |workspace, window, cx| {
+                workspace.open_paths(
+                    vec![PathBuf::from(path!("/root/a.txt"))],
+                    OpenOptions {
+                        visible: Some(OpenVisible::All),
+                        ..Default::default()
+                    },
+                    None,
+                    window,
+                    cx,
+                )
+            })
+            .unwrap()
+            .await;
+        let editor = cx.read(|cx| {
+            let pane = workspace.read(cx).active_pane().read(cx);
+            let item = pane.active_item().unwrap();
+            item.downcast::<Editor>().unwrap()
+        });
+        window
+            .update(cx, |_, window, cx| {
+                editor.update(cx, |editor, cx| editor.insert("unsaved ", window, cx));
+            })
+            .unwrap();
+
+        let save_task = window
+            .update(cx, |workspace, window, cx| {
+                workspace.save_copy_of_active_item(window, cx)
+            })
+            .unwrap();
+        cx.background_executor.run_until_parked();
+        cx.simulate_new_path_selection(|parent_dir| {
+            assert_eq!(parent_dir, Path::new(path!("/root")));
+            Some(parent_dir.join("copy.txt"))
+        });
+        save_task.await.unwrap();
+        cx.run_until_parked();
+
+        assert_eq!(
+            app_state
+                .fs
+                .load(Path::new(path!("/root/copy.txt")))
+                .await
+                .unwrap(),
+            "unsaved a"
+        );
+        assert_eq!(
+            app_state
+                .fs
+                .load(Path::new(path!("/root/a.txt")))
+                .await
+                .unwrap(),
+            "a"
+        );
+        cx.read(|cx| {
+            assert_eq!(editor.read(cx).title(cx), "a.txt");
+            assert!(editor.read(cx).is_dirty(cx));
+            assert_eq!(
+                editor.project_path(cx).unwrap().path.as_ref(),
+                rel_path("a.txt")
+            );
+        });
+    }
+
     #[gpui::test]
     async fn test_open_and_save_new_file(cx: &mut TestAppContext) {
         let app_state = init_test(cx);
@@ -3900,6 +4201,360 @@ mod tests {
         buffer.assert_released();
     }
 
+    #[gpui::test]
+    async fn test_go_to_last_position_in_previous_file(cx: &mut TestAppContext) {
+        let app_state = init_test(cx);
+        app_state
+            .fs
+            .as_fake()
+            .insert_tree(
+                path!("/root"),
+                json!({
+                    "a": {
+                        "file1": "contents 1\n".repeat(20),
+                        "file2": "contents 2\n".repeat(20),
+                    },
+                }),
+            )
+            .await;
+
+        let project = Project::test(app_state.fs.clone(), [path!("/root").as_ref()], cx).await;
+        let workspace =
+            cx.add_window(|window, cx| Workspace::test_new(project.clone(), window, cx));
+        let entries = cx.update(|cx| workspace.root(cx).unwrap().file_project_paths(cx));
+        let file1 = entries[0].clone();
+        let file2 = entries[1].clone();
+
+        for (file, row) in [(file1.clone(), 10), (file2.clone(), 5)] {
+            let editor = workspace
+                .update(cx, |w, window, cx| {
+                    w.open_path(file, None, true, window, cx)
+                })
+                .unwrap()
+                .await
+                .unwrap()
+                .downcast::<Editor>()
+                .unwrap();
+            workspace
+                .update(cx, |_, window, cx| {
+                    editor.update(cx, |editor, cx| {
+                        editor.change_selections(Default::default(), window, cx, |s| {
+                            s.select_display_ranges([DisplayPoint::new(DisplayRow(row), 3)
+                                ..DisplayPoint::new(DisplayRow(row), 3)])
+                        });
+                    });
+                })
+                .unwrap();
+        }
+
+        let active_location = |cx: &mut TestAppContext| {
+            workspace
+                .update(cx, |workspace, _, cx| {
+                    let item = workspace.active_item(cx).unwrap();
+                    let editor = item.downcast::<Editor>().unwrap();
+                    let cursor = editor.update(cx, |editor, cx| {
+                        editor
+                            .selections
+                            .display_ranges(&editor.display_snapshot(cx))[0]
+                            .start
+                    });
+                    (item.project_path(cx).unwrap(), cursor)
+                })
+                .unwrap()
+        };
+
+        cx.run_until_parked();
+        cx.dispatch_action(workspace.into(), pane::AlternateFile);
+        assert_eq!(
+            active_location(cx),
+            (file1, DisplayPoint::new(DisplayRow(10), 3))
+        );
+
+        cx.run_until_parked();
+        cx.dispatch_action(workspace.into(), pane::AlternateFile);
+        assert_eq!(
+            active_location(cx),
+            (file2, DisplayPoint::new(DisplayRow(5), 3))
+        );
+    }
+
+    #[gpui::test]
+    async fn test_scroll_cursor_to_center(cx: &mut TestAppContext) {
+        let app_state = init_test(cx);
+        app_state
+            .fs
+            .as_fake()
+            .insert_tree(
+                path!("/root"),
+                json!({ "a": { "file1": "contents\n".repeat(200) } }),
+            )
+            .await;
+
+        let project = Project::test(app_state.fs.clone(), [path!("/root").as_ref()], cx).await;
+        let workspace =
+            cx.add_window(|window, cx| Workspace::test_new(project.clone(), window, cx));
+        let file = cx.update(|cx| workspace.root(cx).unwrap().file_project_paths(cx))[0].clone();
+        let editor = workspace
+            .update(cx, |w, window, cx| {
+                w.open_path(file, None, true, window, cx)
+            })
+            .unwrap()
+            .await
+            .unwrap()
+            .downcast::<Editor>()
+            .unwrap();
+        cx.run_until_parked();
+
+        workspace
+            .update(cx, |_, window, cx| {
+                editor.update(cx, |editor, cx| {
+                    editor.change_selections(Default::default(), window, cx, |s| {
+                        s.select_display_ranges([DisplayPoint::new(DisplayRow(100), 0)
+                            ..DisplayPoint::new(DisplayRow(100), 0)])
+                    });
+                });
+            })
+            .unwrap();
+        cx.run_until_parked();
+        let top_before = editor.update(cx, |editor, cx| editor.scroll_position(cx).y);
+
+        cx.dispatch_action(workspace.into(), editor::actions::ScrollCursorCenter);
+        cx.run_until_parked();
+
+        let (top_after, visible_rows) = editor.update(cx, |editor, cx| {
+            (
+                editor.scroll_position(cx).y,
+                editor.visible_line_count().unwrap() as u32,
+            )
+        });
+        assert_ne!(top_after, top_before);
+        assert_eq!(top_after, (100 - visible_rows / 2) as f64);
+    }
+
+    #[gpui::test]
+    async fn test_toggle_inline_values_menu_item(cx: &mut TestAppContext) {
+        let app_state = init_test(cx);
+        app_state
+            .fs
+            .as_fake()
+            .insert_tree(path!("/root"), json!({ "a": { "file1": "contents\n" } }))
+            .await;
+
+        let project = Project::test(app_state.fs.clone(), [path!("/root").as_ref()], cx).await;
+        let workspace =
+            cx.add_window(|window, cx| Workspace::test_new(project.clone(), window, cx));
+        let file = cx.update(|cx| workspace.root(cx).unwrap().file_project_paths(cx))[0].clone();
+        let editor = workspace
+            .update(cx, |w, window, cx| {
+                w.open_path(file, None, true, window, cx)
+            })
+            .unwrap()
+            .await
+            .unwrap()
+            .downcast::<Editor>()
+            .unwrap();
+        cx.run_until_parked();
+
+        let menu_item_checked = |cx: &mut TestAppContext| {
+            cx.update(|cx| {
+                app_menus(cx)
+                    .into_iter()
+                    .flat_map(|menu| menu.items)
+                    .find_map(|item| match item {
+                        gpui::MenuItem::Action { name, checked, .. }
+                            if name.as_ref() == i18n::t("menu.toggle_inline_values") =>
+                        {
+                            Some(checked)
+                        }
+                        _ => None,
+                    })
+                    .unwrap()
+            })
+        };
+
+        let enabled = editor.read_with(cx, |editor, _| editor.inline_values_enabled());
+        assert_eq!(menu_item_checked(cx), enabled);
+
+        workspace
+            .update(cx, |_, window, cx| {
+                editor.update(cx, |editor, cx| {
+                    editor.toggle_inline_values(&editor::actions::ToggleInlineValues, window, cx)
+                })
+            })
+            .unwrap();
+        cx.run_until_parked();
+        assert_eq!(
+            editor.read_with(cx, |editor, _| editor.inline_values_enabled()),
+            !enabled
+        );
+        assert_eq!(menu_item_checked(cx), !enabled);
+
+        // Without a debug session there are no inline values to toggle.
+        let available_actions = cx
+            .update(|cx| workspace.update(cx, |_, window, cx| window.available_actions(cx)))
+            .unwrap();
+        assert!(
+            !available_actions
+                .iter()
+                .any(|action| action.partial_eq(&editor::actions::ToggleInlineValues))
+        );
+    }
+
+    /// Flattens a menu tree into its labels and action names, depth first.
+    fn menu_entries(menus: &[gpui::Menu]) -> Vec<(String, Option<&'static str>)> {
+        let mut entries = Vec::new();
+        for menu in menus {
+            entries.push((menu.name.to_string(), None));
+            for item in &menu.items {
+                match item {
+                    gpui::MenuItem::Separator => entries.push(("-".to_string(), None)),
+                    gpui::MenuItem::Submenu(submenu) => {
+                        entries.extend(menu_entries(std::slice::from_ref(submenu)))
+                    }
+                    gpui::MenuItem::SystemMenu(os_menu) => {
+                        entries.push((os_menu.name.to_string(), None))
+                    }
+                    gpui::MenuItem::Action { name, action, .. } => {
+                        entries.push((name.to_string(), Some(action.name())))
+                    }
+                }
+            }
+        }
+        entries
+    }
+
+    #[gpui::test]
+    async fn test_app_menus_are_resolved_from_menu_structure(cx: &mut TestAppContext) {
+        init_test(cx);
+        cx.update(|cx| {
+            let keyed = menu_entries(&app_menus::menu_structure(cx));
+            let localized = menu_entries(&app_menus(cx));
+
+            // Every label in the structure is a translation key with an English fallback.
+            let english = i18n::bundled_translations(i18n::Language::English);
+            for (key, _) in keyed.iter().filter(|(key, _)| key != "-") {
+                assert!(
+                    key.starts_with("menu."),
+                    "{key} is not a menu translation key"
+                );
+                assert!(english.contains_key(key), "{key} is missing from en.json");
+            }
+
+            let expected = keyed
+                .into_iter()
+                .map(|(key, action)| match key.as_str() {
+                    "-" => (key, action),
+                    _ => (i18n::t(&key), action),
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(localized, expected);
+        });
+    }
+
+    #[gpui::test]
+    async fn test_app_menus_are_rebuilt_when_language_changes(cx: &mut TestAppContext) {
+        init_test(cx);
+        i18n::init_test();
+        let menu_names = |cx: &mut TestAppContext| {
+            cx.update(|cx| {
+                cx.get_menus()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|menu| menu.name.to_string())
+                    .collect::<Vec<_>>()
+            })
+        };
+
+        let previous = i18n::get_language();
+        i18n::set_language(i18n::Language::SimplifiedChinese);
+        cx.run_until_parked();
+        let chinese = i18n::bundled_translations(i18n::Language::SimplifiedChinese);
+        let names = menu_names(cx);
+        assert!(names.contains(&chinese["menu.file"]), "{names:?}");
+        assert!(names.contains(&chinese["menu.edit"]), "{names:?}");
+
+        i18n::set_language(i18n::Language::English);
+        cx.run_until_parked();
+        let names = menu_names(cx);
+        assert!(names.contains(&"File".to_string()), "{names:?}");
+        i18n::set_language(previous);
+    }
+
+    #[gpui::test]
+    async fn test_developer_tools_menu_is_dev_only(cx: &mut TestAppContext) {
+        init_test(cx);
+        let has_developer_tools = |channel: ReleaseChannel, cx: &mut TestAppContext| {
+            cx.update(|cx| {
+                release_channel::init_test(Version::new(0, 0, 0), channel, cx);
+                let developer_tools = i18n::t("menu.developer_tools");
+                app_menus(cx)
+                    .into_iter()
+                    .flat_map(|menu| menu.items)
+                    .any(|item| match item {
+                        gpui::MenuItem::Submenu(menu) => menu.name.as_ref() == developer_tools,
+                        _ => false,
+                    })
+            })
+        };
+
+        assert!(has_developer_tools(ReleaseChannel::Dev, cx));
+        assert!(!has_developer_tools(ReleaseChannel::Nightly, cx));
+        assert!(!has_developer_tools(ReleaseChannel::Stable, cx));
+    }
+
+    #[gpui::test]
+    async fn test_toggle_indent_guides_menu_item(cx: &mut TestAppContext) {
+        let app_state = init_test(cx);
+        app_state
+            .fs
+            .as_fake()
+            .insert_tree(path!("/root"), json!({ "a": { "file1": "contents\n" } }))
+            .await;
+
+        let project = Project::test(app_state.fs.clone(), [path!("/root").as_ref()], cx).await;
+        let workspace =
+            cx.add_window(|window, cx| Workspace::test_new(project.clone(), window, cx));
+        let file = cx.update(|cx| workspace.root(cx).unwrap().file_project_paths(cx))[0].clone();
+        let editor = workspace
+            .update(cx, |w, window, cx| {
+                w.open_path(file, None, true, window, cx)
+            })
+            .unwrap()
+            .await
+            .unwrap()
+            .downcast::<Editor>()
+            .unwrap();
+        cx.run_until_parked();
+
+        let menu_item_checked = |cx: &mut TestAppContext| {
+            cx.update(|cx| {
+                app_menus(cx)
+                    .into_iter()
+                    .flat_map(|menu| menu.items)
+                    .find_map(|item| match item {
+                        gpui::MenuItem::Action { name, checked, .. }
+                            if name.as_ref() == i18n::t("menu.toggle_indent_guides") =>
+                        {
+                            Some(checked)
+                        }
+                        _ => None,
+                    })
+                    .unwrap()
+            })
+        };
+
+        let enabled = editor.read_with(cx, |editor, cx| editor.indent_guides_enabled(cx));
+        assert_eq!(menu_item_checked(cx), enabled);
+
+        cx.dispatch_action(workspace.into(), editor::actions::ToggleIndentGuides);
+        cx.run_until_parked();
+        assert_eq!(
+            editor.read_with(cx, |editor, cx| editor.indent_guides_enabled(cx)),
+            !enabled
+        );
+        assert_eq!(menu_item_checked(cx), !enabled);
+    }
+
     #[gpui::test]
     async fn test_navigation(cx: &mut TestAppContext) {
         let app_state = init_test(cx);
@@ -4948,6 +5603,32 @@ mod tests {
         assert!(has_default_theme);
     }
 
+    #[gpui::test]
+    fn test_custom_panel_has_default_key_binding(cx: &mut App) {
+        settings::init(cx);
+
+        let expected = if cfg!(target_os = "macos") {
+            "cmd-alt-shift-p"
+        } else {
+            "ctrl-alt-shift-p"
+        };
+        let expected = gpui::Keystroke::parse(expected).unwrap();
+
+        let bindings = KeymapFile::load_asset(DEFAULT_KEYMAP_PATH, None, cx).unwrap();
+        let keystrokes = bindings
+            .iter()
+            .filter(|binding| binding.action().partial_eq(&custom_panel::ToggleFocus))
+            .map(|binding| {
+                binding
+                    .keystrokes()
+                    .iter()
+                    .map(|keystroke| keystroke.inner().clone())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(keystrokes, vec![vec![expected]]);
+    }
+
     #[gpui::test]
     async fn test_bundled_files_editor(cx: &mut TestAppContext) {
         let app_state = init_test(cx);
@@ -1,333 +1,648 @@
 use collab_ui::collab_panel;
-use gpui::{App, Menu, MenuItem, OsAction};
-use i18n::t;
+use editor::Editor;
+use gpui::{App, Entity, Menu, MenuItem, OsAction, SharedString};
 use release_channel::ReleaseChannel;
 use terminal_view::terminal_panel;
+use workspace::Workspace;
 use zed_actions::{ToggleFocus as ToggleDebugPanel, dev};
 
 pub fn app_menus(cx: &mut App) -> Vec<Menu> {
+    let mut menus = menu_structure(cx);
+    localize_menus(&mut menus);
+    menus
+}
+
+/// The application menus, labelled with translation keys instead of display text.
+///
+/// This is the single source of truth for which keys the menus use; [`app_menus`] resolves
+/// all of them in one pass with [`i18n::t_all`]. Labels that carry a numeric parameter are
+/// the exception: build those with [`i18n::t_named`] and [`i18n::format_number`] so the number
+/// is formatted for the current language. [`localize_menus`] passes text that isn't a
+/// translation key through as is.
+pub(crate) fn menu_structure(cx: &mut App) -> Vec<Menu> {
     use zed_actions::Quit;
 
-    let mut view_items = vec![
+    let view_items = vec![
         MenuItem::action(
-            t("menu.zoom_in"),
+            "menu.zoom_in",
             zed_actions::IncreaseBufferFontSize { persist: false },
         ),
         MenuItem::action(
-            t("menu.zoom_out"),
+            "menu.zoom_out",
             zed_actions::DecreaseBufferFontSize { persist: false },
         ),
         MenuItem::action(
-            t("menu.reset_zoom"),
+            "menu.reset_zoom",
             zed_actions::ResetBufferFontSize { persist: false },
         ),
         MenuItem::action(
-            t("menu.reset_all_zoom"),
+            "menu.reset_all_zoom",
             zed_actions::ResetAllZoom { persist: false },
         ),
         MenuItem::separator(),
-        MenuItem::action(t("menu.toggle_left_dock"), workspace::ToggleLeftDock),
-        MenuItem::action(t("menu.toggle_right_dock"), workspace::ToggleRightDock),
-        MenuItem::action(t("menu.toggle_bottom_dock"), workspace::ToggleBottomDock),
-        MenuItem::action(t("menu.toggle_all_docks"), workspace::ToggleAllDocks),
         MenuItem::submenu(Menu {
-            name: t("menu.editor_layout").into(),
+            name: "menu.ui_font_size".into(),
             items: vec![
-                MenuItem::action(t("menu.split_up"), workspace::SplitUp::default()),
-                MenuItem::action(t("menu.split_down"), workspace::SplitDown::default()),
-                MenuItem::action(t("menu.split_left"), workspace::SplitLeft::default()),
-                MenuItem::action(t("menu.split_right"), workspace::SplitRight::default()),
+                MenuItem::action(
+                    "menu.increase_ui_font_size",
+                    zed_actions::IncreaseUiFontSize { persist: false },
+                ),
+                MenuItem::action(
+                    "menu.decrease_ui_font_size",
+                    zed_actions::DecreaseUiFontSize { persist: false },
+                ),
+                MenuItem::action(
+                    "menu.reset_ui_font_size",
+                    zed_actions::ResetUiFontSize { persist: false },
+                ),
             ],
         }),
         MenuItem::separator(),
-        MenuItem::action(t("menu.project_panel"), zed_actions::project_panel::ToggleFocus),
-        MenuItem::action(t("menu.outline_panel"), outline_panel::ToggleFocus),
-        MenuItem::action(t("menu.collab_panel"), collab_panel::ToggleFocus),
-        MenuItem::action(t("menu.terminal_panel"), terminal_panel::ToggleFocus),
-        MenuItem::action(t("menu.debugger_panel"), ToggleDebugPanel),
+        MenuItem::action("menu.toggle_left_dock", workspace::ToggleLeftDock),
+        MenuItem::action("menu.toggle_right_dock", workspace::ToggleRightDock),
+        MenuItem::action("menu.toggle_bottom_dock", workspace::ToggleBottomDock),
+        MenuItem::action("menu.toggle_all_docks", workspace::ToggleAllDocks),
+        MenuItem::submenu(Menu {
+            name: "menu.editor_layout".into(),
+            items: vec![
+                MenuItem::action("menu.split_up", workspace::SplitUp::default()),
+                MenuItem::action("menu.split_down", workspace::SplitDown::default()),
+                MenuItem::action("menu.split_left", workspace::SplitLeft::default()),
+                MenuItem::action("menu.split_right", workspace::SplitRight::default()),
+            ],
+        }),
         MenuItem::separator(),
-        MenuItem::action(t("menu.diagnostics"), diagnostics::Deploy),
+        MenuItem::action(
+            "menu.scroll_cursor_to_center",
+            editor::actions::ScrollCursorCenter,
+        ),
+        MenuItem::action(
+            "menu.scroll_cursor_to_top",
+            editor::actions::ScrollCursorTop,
+        ),
+        MenuItem::action(
+            "menu.scroll_cursor_to_bottom",
+            editor::actions::ScrollCursorBottom,
+        ),
+        MenuItem::separator(),
+        MenuItem::action(
+            "menu.toggle_indent_guides",
+            editor::actions::ToggleIndentGuides,
+        )
+        .checked(indent_guides_enabled(cx)),
+        MenuItem::action("menu.presentation_mode", workspace::TogglePresentationMode)
+            .checked(workspace::presentation_mode_active(cx)),
+        MenuItem::separator(),
+        MenuItem::action(
+            "menu.project_panel",
+            zed_actions::project_panel::ToggleFocus,
+        ),
+        MenuItem::action("menu.outline_panel", outline_panel::ToggleFocus),
+        MenuItem::action("menu.collab_panel", collab_panel::ToggleFocus),
+        MenuItem::action("menu.terminal_panel", terminal_panel::ToggleFocus),
+        MenuItem::action("menu.debugger_panel", ToggleDebugPanel),
+        MenuItem::separator(),
+        MenuItem::action("menu.diagnostics", diagnostics::Deploy),
         MenuItem::separator(),
     ];
 
+    let mut help_items = vec![
+        MenuItem::action(
+            "menu.view_release_notes_locally",
+            auto_update_ui::ViewReleaseNotesLocally,
+        ),
+        MenuItem::action("menu.view_telemetry", zed_actions::OpenTelemetryLog),
+        MenuItem::action("menu.view_dependency_licenses", zed_actions::OpenLicenses),
+        MenuItem::action(
+            "menu.open_extensions_folder",
+            zed_actions::OpenExtensionsFolder,
+        ),
+        MenuItem::action("menu.show_welcome", onboarding::ShowWelcome),
+        MenuItem::separator(),
+        MenuItem::action("menu.file_bug_report", zed_actions::feedback::FileBugReport),
+        MenuItem::action(
+            "menu.copy_system_specs",
+            system_specs::CopySystemSpecsIntoClipboard,
+        ),
+        MenuItem::action(
+            "menu.request_feature",
+            zed_actions::feedback::RequestFeature,
+        ),
+        MenuItem::action("menu.email_us", zed_actions::feedback::EmailZed),
+        MenuItem::separator(),
+        MenuItem::action(
+            "menu.documentation",
+            super::OpenBrowser {
+                url: "https://zed.dev/docs".into(),
+            },
+        ),
+        MenuItem::action("menu.zed_repository", feedback::OpenZedRepo),
+        MenuItem::action(
+            "menu.zed_twitter",
+            super::OpenBrowser {
+                url: "https://twitter.com/zeddotdev".into(),
+            },
+        ),
+        MenuItem::action(
+            "menu.join_the_team",
+            super::OpenBrowser {
+                url: "https://zed.dev/jobs".into(),
+            },
+        ),
+    ];
+
+    // Tools for debugging Zed itself and its translations are only offered on the Dev channel.
     if ReleaseChannel::try_global(cx) == Some(ReleaseChannel::Dev) {
-        view_items.push(MenuItem::action(
-            t("menu.toggle_gpui_inspector"),
-            dev::ToggleInspector,
-        ));
-        view_items.push(MenuItem::separator());
+        help_items.push(MenuItem::separator());
+        help_items.push(MenuItem::submenu(developer_tools_menu()));
     }
 
     vec![
         Menu {
-            name: t("menu.zed").into(),
+            name: "menu.zed".into(),
             items: vec![
-                MenuItem::action(t("menu.about_zed"), zed_actions::About),
-                MenuItem::action(t("menu.check_for_updates"), auto_update::Check),
+                MenuItem::action("menu.about_zed", zed_actions::About),
+                MenuItem::action("menu.check_for_updates", auto_update::Check),
                 MenuItem::separator(),
                 MenuItem::submenu(Menu {
-                    name: t("menu.settings").into(),
+                    name: "menu.settings".into(),
                     items: vec![
-                        MenuItem::action(t("menu.open_settings"), zed_actions::OpenSettings),
-                        MenuItem::action(t("menu.open_settings_file"), super::OpenSettingsFile),
-                        MenuItem::action(t("menu.open_project_settings"), zed_actions::OpenProjectSettings),
+                        MenuItem::action("menu.open_settings", zed_actions::OpenSettings),
+                        MenuItem::action("menu.open_settings_file", super::OpenSettingsFile),
+                        MenuItem::action(
+                            "menu.open_project_settings",
+                            zed_actions::OpenProjectSettings,
+                        ),
                         MenuItem::action(
-                            t("menu.open_project_settings_file"),
+                            "menu.open_project_settings_file",
                             super::OpenProjectSettingsFile,
                         ),
-                        MenuItem::action(t("menu.open_default_settings"), super::OpenDefaultSettings),
+                        MenuItem::action("menu.open_default_settings", super::OpenDefaultSettings),
                         MenuItem::separator(),
-                        MenuItem::action(t("menu.open_keymap"), zed_actions::OpenKeymap),
-                        MenuItem::action(t("menu.open_keymap_file"), zed_actions::OpenKeymapFile),
+                        MenuItem::action("menu.open_keymap", zed_actions::OpenKeymap),
+                        MenuItem::action("menu.open_keymap_file", zed_actions::OpenKeymapFile),
                         MenuItem::action(
-                            t("menu.open_default_key_bindings"),
+                            "menu.open_default_key_bindings",
                             zed_actions::OpenDefaultKeymap,
                         ),
                         MenuItem::separator(),
                         MenuItem::action(
-                            t("menu.select_theme"),
+                            "menu.select_theme",
                             zed_actions::theme_selector::Toggle::default(),
                         ),
                         MenuItem::action(
-                            t("menu.select_icon_theme"),
+                            "menu.select_icon_theme",
                             zed_actions::icon_theme_selector::Toggle::default(),
                         ),
+                        MenuItem::action(
+                            "menu.select_language",
+                            zed_actions::ui_language_selector::SelectLanguage,
+                        ),
                     ],
                 }),
                 MenuItem::separator(),
                 #[cfg(target_os = "macos")]
-                MenuItem::os_submenu(t("menu.services"), gpui::SystemMenuType::Services),
+                MenuItem::os_submenu("menu.services", gpui::SystemMenuType::Services),
                 MenuItem::separator(),
-                MenuItem::action(t("menu.extensions"), zed_actions::Extensions::default()),
+                MenuItem::action("menu.extensions", zed_actions::Extensions::default()),
                 #[cfg(not(target_os = "windows"))]
-                MenuItem::action(t("menu.install_cli"), install_cli::InstallCliBinary),
+                MenuItem::action("menu.install_cli", install_cli::InstallCliBinary),
                 MenuItem::separator(),
                 #[cfg(target_os = "macos")]
-                MenuItem::action(t("menu.hide_zed"), super::Hide),
+                MenuItem::action("menu.hide_zed", super::Hide),
                 #[cfg(target_os = "macos")]
-                MenuItem::action(t("menu.hide_others"), super::HideOthers),
+                MenuItem::action("menu.hide_others", super::HideOthers),
                 #[cfg(target_os = "macos")]
-                MenuItem::action(t("menu.show_all"), super::ShowAll),
+                MenuItem::action("menu.show_all", super::ShowAll),
                 MenuItem::separator(),
-                MenuItem::action(t("menu.quit_zed"), Quit),
+                MenuItem::action("menu.quit_zed", Quit),
             ],
         },
         Menu {
-            name: t("menu.file").into(),
+            name: "menu.file".into(),
             items: vec![
-                MenuItem::action(t("menu.new"), workspace::NewFile),
-                MenuItem::action(t("menu.new_window"), workspace::NewWindow),
+                MenuItem::action("menu.new", workspace::NewFile),
+                MenuItem::action("menu.new_window", workspace::NewWindow),
                 MenuItem::separator(),
                 #[cfg(not(target_os = "macos"))]
-                MenuItem::action(t("menu.open_file"), workspace::OpenFiles),
+                MenuItem::action("menu.open_file", workspace::OpenFiles),
                 MenuItem::action(
                     if cfg!(not(target_os = "macos")) {
-                        t("menu.open_folder")
+                        "menu.open_folder"
                     } else {
-                        t("menu.open")
+                        "menu.open"
                     },
                     workspace::Open,
                 ),
                 MenuItem::action(
-                    t("menu.open_recent"),
+                    "menu.open_recent",
+                    zed_actions::OpenRecent {
+                        create_new_window: false,
+                        filter: zed_actions::RecentProjectsFilter::All,
+                    },
+                ),
+                MenuItem::action(
+                    "menu.open_recent_file",
+                    zed_actions::OpenRecent {
+                        create_new_window: false,
+                        filter: zed_actions::RecentProjectsFilter::Files,
+                    },
+                ),
+                MenuItem::action(
+                    "menu.open_recent_folder",
                     zed_actions::OpenRecent {
                         create_new_window: false,
+                        filter: zed_actions::RecentProjectsFilter::Folders,
                     },
                 ),
                 MenuItem::action(
-                    t("menu.open_remote"),
+                    "menu.open_remote",
                     zed_actions::OpenRemote {
                         create_new_window: false,
                         from_existing_connection: false,
                     },
                 ),
                 MenuItem::separator(),
-                MenuItem::action(t("menu.add_folder_to_project"), workspace::AddFolderToProject),
+                MenuItem::action("menu.add_folder_to_project", workspace::AddFolderToProject),
+                MenuItem::action(
+                    "menu.open_containing_folder",
+                    workspace::OpenContainingFolder,
+                ),
                 MenuItem::separator(),
-                MenuItem::action(t("menu.save"), workspace::Save { save_intent: None }),
-                MenuItem::action(t("menu.save_as"), workspace::SaveAs),
-                MenuItem::action(t("menu.save_all"), workspace::SaveAll { save_intent: None }),
+                MenuItem::action("menu.save", workspace::Save { save_intent: None }),
+                MenuItem::action("menu.save_as", workspace::SaveAs),
+                MenuItem::action("menu.save_copy_as", workspace::SaveCopyAs),
+                MenuItem::action("menu.save_all", workspace::SaveAll { save_intent: None }),
+                MenuItem::action("menu.rename_file", workspace::RenameFile),
+                MenuItem::submenu(encoding_menu()),
                 MenuItem::separator(),
                 MenuItem::action(
-                    t("menu.close_editor"),
+                    "menu.close_editor",
                     workspace::CloseActiveItem {
                         save_intent: None,
                         close_pinned: true,
                     },
                 ),
-                MenuItem::action(t("menu.close_project"), workspace::CloseProject),
-                MenuItem::action(t("menu.close_window"), workspace::CloseWindow),
+                MenuItem::action("menu.close_project", workspace::CloseProject),
+                MenuItem::action("menu.close_window", workspace::CloseWindow),
             ],
         },
         Menu {
-            name: t("menu.edit").into(),
+            name: "menu.edit".into(),
             items: vec![
-                MenuItem::os_action(t("menu.undo"), editor::actions::Undo, OsAction::Undo),
-                MenuItem::os_action(t("menu.redo"), editor::actions::Redo, OsAction::Redo),
+                MenuItem::os_action("menu.undo", editor::actions::Undo, OsAction::Undo),
+                MenuItem::os_action("menu.redo", editor::actions::Redo, OsAction::Redo),
                 MenuItem::separator(),
-                MenuItem::os_action(t("menu.cut"), editor::actions::Cut, OsAction::Cut),
-                MenuItem::os_action(t("menu.copy"), editor::actions::Copy, OsAction::Copy),
-                MenuItem::action(t("menu.copy_and_trim"), editor::actions::CopyAndTrim),
-                MenuItem::os_action(t("menu.paste"), editor::actions::Paste, OsAction::Paste),
+                MenuItem::os_action("menu.cut", editor::actions::Cut, OsAction::Cut),
+                MenuItem::os_action("menu.copy", editor::actions::Copy, OsAction::Copy),
+                MenuItem::action("menu.copy_and_trim", editor::actions::CopyAndTrim),
+                MenuItem::os_action("menu.paste", editor::actions::Paste, OsAction::Paste),
                 MenuItem::separator(),
-                MenuItem::action(t("menu.find"), search::buffer_search::Deploy::find()),
-                MenuItem::action(t("menu.find_in_project"), workspace::DeploySearch::find()),
+                MenuItem::action("menu.find", search::buffer_search::Deploy::find()),
+                MenuItem::action("menu.find_in_project", workspace::DeploySearch::find()),
                 MenuItem::separator(),
                 MenuItem::action(
-                    t("menu.toggle_line_comment"),
+                    "menu.toggle_line_comment",
                     editor::actions::ToggleComments::default(),
                 ),
             ],
         },
         Menu {
-            name: t("menu.selection").into(),
+            name: "menu.selection".into(),
             items: vec![
                 MenuItem::os_action(
-                    t("menu.select_all"),
+                    "menu.select_all",
                     editor::actions::SelectAll,
                     OsAction::SelectAll,
                 ),
-                MenuItem::action(t("menu.expand_selection"), editor::actions::SelectLargerSyntaxNode),
-                MenuItem::action(t("menu.shrink_selection"), editor::actions::SelectSmallerSyntaxNode),
-                MenuItem::action(t("menu.select_next_sibling"), editor::actions::SelectNextSyntaxNode),
+                MenuItem::action("menu.invert_selection", editor::actions::InvertSelection),
+                MenuItem::action(
+                    "menu.surround_selection",
+                    editor::actions::SurroundSelections::default(),
+                ),
+                MenuItem::action(
+                    "menu.expand_selection",
+                    editor::actions::SelectLargerSyntaxNode,
+                ),
+                MenuItem::action(
+                    "menu.shrink_selection",
+                    editor::actions::SelectSmallerSyntaxNode,
+                ),
+                MenuItem::action(
+                    "menu.grow_selection_by_word",
+                    editor::actions::GrowSelectionByWord,
+                ),
+                MenuItem::action(
+                    "menu.shrink_selection_by_word",
+                    editor::actions::ShrinkSelectionByWord,
+                ),
+                MenuItem::action(
+                    "menu.select_next_sibling",
+                    editor::actions::SelectNextSyntaxNode,
+                ),
                 MenuItem::action(
-                    t("menu.select_previous_sibling"),
+                    "menu.select_previous_sibling",
                     editor::actions::SelectPreviousSyntaxNode,
                 ),
                 MenuItem::separator(),
                 MenuItem::action(
-                    t("menu.add_cursor_above"),
+                    "menu.add_cursor_above",
                     editor::actions::AddSelectionAbove {
                         skip_soft_wrap: true,
                     },
                 ),
                 MenuItem::action(
-                    t("menu.add_cursor_below"),
+                    "menu.add_cursor_below",
                     editor::actions::AddSelectionBelow {
                         skip_soft_wrap: true,
                     },
                 ),
                 MenuItem::action(
-                    t("menu.select_next_occurrence"),
+                    "menu.select_next_occurrence",
                     editor::actions::SelectNext {
                         replace_newest: false,
                     },
                 ),
                 MenuItem::action(
-                    t("menu.select_previous_occurrence"),
+                    "menu.select_previous_occurrence",
                     editor::actions::SelectPrevious {
                         replace_newest: false,
                     },
                 ),
-                MenuItem::action(t("menu.select_all_occurrences"), editor::actions::SelectAllMatches),
+                MenuItem::action(
+                    "menu.select_all_occurrences",
+                    editor::actions::SelectAllMatches,
+                ),
                 MenuItem::separator(),
-                MenuItem::action(t("menu.move_line_up"), editor::actions::MoveLineUp),
-                MenuItem::action(t("menu.move_line_down"), editor::actions::MoveLineDown),
-                MenuItem::action(t("menu.duplicate_selection"), editor::actions::DuplicateLineDown),
+                MenuItem::action("menu.move_line_up", editor::actions::MoveLineUp),
+                MenuItem::action("menu.move_line_down", editor::actions::MoveLineDown),
+                MenuItem::action(
+                    "menu.duplicate_selection",
+                    editor::actions::DuplicateLineDown,
+                ),
+                MenuItem::action(
+                    "menu.trim_trailing_whitespace",
+                    editor::actions::TrimTrailingWhitespace,
+                ),
             ],
         },
         Menu {
-            name: t("menu.view").into(),
+            name: "menu.view".into(),
             items: view_items,
         },
         Menu {
-            name: t("menu.go").into(),
+            name: "menu.go".into(),
             items: vec![
-                MenuItem::action(t("menu.back"), workspace::GoBack),
-                MenuItem::action(t("menu.forward"), workspace::GoForward),
+                MenuItem::action("menu.back", workspace::GoBack),
+                MenuItem::action("menu.forward", workspace::GoForward),
+                MenuItem::action(
+                    "menu.go_to_last_position_in_previous_file",
+                    workspace::pane::AlternateFile,
+                ),
                 MenuItem::separator(),
-                MenuItem::action(t("menu.command_palette"), zed_actions::command_palette::Toggle),
+                MenuItem::action("menu.command_palette", zed_actions::command_palette::Toggle),
                 MenuItem::separator(),
-                MenuItem::action(t("menu.go_to_file"), workspace::ToggleFileFinder::default()),
-                // MenuItem::action("Go to Symbol in Project", project_symbols::Toggle),
+                MenuItem::action("menu.go_to_file", workspace::ToggleFileFinder::default()),
+                MenuItem::action("menu.go_to_related_file", workspace::OpenRelatedFile),
                 MenuItem::action(
-                    t("menu.go_to_symbol_in_editor"),
+                    "menu.go_to_symbol_in_project",
+                    workspace::ToggleProjectSymbols,
+                ),
+                MenuItem::submenu(project_symbols_by_kind_menu()),
+                MenuItem::action(
+                    "menu.go_to_symbol_in_editor",
                     zed_actions::outline::ToggleOutline,
                 ),
-                MenuItem::action(t("menu.go_to_line_column"), editor::actions::ToggleGoToLine),
+                MenuItem::action(
+                    "menu.go_to_symbol_under_cursor",
+                    zed_actions::outline::ToggleFiltered::default(),
+                ),
+                MenuItem::action("menu.go_to_line_column", editor::actions::ToggleGoToLine),
+                MenuItem::action(
+                    "menu.go_to_next_method",
+                    editor::actions::GoToNextMethod::default(),
+                ),
+                MenuItem::action(
+                    "menu.go_to_previous_method",
+                    editor::actions::GoToPreviousMethod::default(),
+                ),
                 MenuItem::separator(),
-                MenuItem::action(t("menu.go_to_definition"), editor::actions::GoToDefinition),
-                MenuItem::action(t("menu.go_to_declaration"), editor::actions::GoToDeclaration),
-                MenuItem::action(t("menu.go_to_type_definition"), editor::actions::GoToTypeDefinition),
+                MenuItem::action("menu.go_to_definition", editor::actions::GoToDefinition),
+                MenuItem::action("menu.peek_definition", editor::actions::PeekDefinition),
+                MenuItem::action("menu.go_to_declaration", editor::actions::GoToDeclaration),
                 MenuItem::action(
-                    t("menu.find_all_references"),
+                    "menu.go_to_type_definition",
+                    editor::actions::GoToTypeDefinition,
+                ),
+                MenuItem::action(
+                    "menu.find_all_references",
                     editor::actions::FindAllReferences::default(),
                 ),
                 MenuItem::separator(),
-                MenuItem::action(t("menu.next_problem"), editor::actions::GoToDiagnostic::default()),
                 MenuItem::action(
-                    t("menu.previous_problem"),
+                    "menu.next_problem",
+                    editor::actions::GoToDiagnostic::default(),
+                ),
+                MenuItem::action(
+                    "menu.previous_problem",
                     editor::actions::GoToPreviousDiagnostic::default(),
                 ),
             ],
         },
         Menu {
-            name: t("menu.run").into(),
+            name: "menu.run".into(),
             items: vec![
+                MenuItem::action("menu.build_task", spawn_tagged_task("build")),
+                MenuItem::action("menu.run_task", spawn_tagged_task("run")),
+                MenuItem::action("menu.test_task", spawn_tagged_task("test")),
+                MenuItem::separator(),
                 MenuItem::action(
-                    t("menu.spawn_task"),
+                    "menu.spawn_task",
                     zed_actions::Spawn::ViaModal {
                         reveal_target: None,
                     },
                 ),
-                MenuItem::action(t("menu.start_debugger"), debugger_ui::Start),
+                MenuItem::action("menu.start_debugger", debugger_ui::Start),
+                MenuItem::action(
+                    "menu.auto_attach_debugger_on_run",
+                    debugger_ui::ToggleAutoAttachOnRun,
+                )
+                .checked(debugger_ui::auto_attach_on_run(cx)),
                 MenuItem::separator(),
-                MenuItem::action(t("menu.edit_tasks_json"), crate::zed::OpenProjectTasks),
-                MenuItem::action(t("menu.edit_debug_json"), zed_actions::OpenProjectDebugTasks),
+                MenuItem::action("menu.edit_tasks_json", crate::zed::OpenProjectTasks),
+                MenuItem::action("menu.edit_debug_json", zed_actions::OpenProjectDebugTasks),
                 MenuItem::separator(),
-                MenuItem::action(t("menu.continue"), debugger_ui::Continue),
-                MenuItem::action(t("menu.step_over"), debugger_ui::StepOver),
-                MenuItem::action(t("menu.step_into"), debugger_ui::StepInto),
-                MenuItem::action(t("menu.step_out"), debugger_ui::StepOut),
+                MenuItem::action("menu.continue", debugger_ui::Continue),
+                MenuItem::action("menu.step_over", debugger_ui::StepOver),
+                MenuItem::action("menu.step_into", debugger_ui::StepInto),
+                MenuItem::action("menu.step_out", debugger_ui::StepOut),
+                MenuItem::action(
+                    "menu.toggle_inline_values",
+                    editor::actions::ToggleInlineValues,
+                )
+                .checked(inline_values_enabled(cx)),
                 MenuItem::separator(),
-                MenuItem::action(t("menu.toggle_breakpoint"), editor::actions::ToggleBreakpoint),
-                MenuItem::action(t("menu.edit_breakpoint"), editor::actions::EditLogBreakpoint),
-                MenuItem::action(t("menu.clear_all_breakpoints"), debugger_ui::ClearAllBreakpoints),
+                MenuItem::action("menu.toggle_breakpoint", editor::actions::ToggleBreakpoint),
+                MenuItem::action("menu.edit_breakpoint", editor::actions::EditLogBreakpoint),
+                MenuItem::action(
+                    "menu.clear_all_breakpoints",
+                    debugger_ui::ClearAllBreakpoints,
+                ),
             ],
         },
         Menu {
-            name: t("menu.window").into(),
+            name: "menu.window".into(),
             items: vec![
-                MenuItem::action(t("menu.minimize"), super::Minimize),
-                MenuItem::action(t("menu.zoom"), super::Zoom),
+                MenuItem::action("menu.minimize", super::Minimize),
+                MenuItem::action("menu.zoom", super::Zoom),
                 MenuItem::separator(),
-            ],
-        },
-        Menu {
-            name: t("menu.help").into(),
-            items: vec![
+                MenuItem::action("menu.cycle_windows", workspace::ActivateNextWindow),
                 MenuItem::action(
-                    t("menu.view_release_notes_locally"),
-                    auto_update_ui::ViewReleaseNotesLocally,
+                    "menu.cycle_windows_reverse",
+                    workspace::ActivatePreviousWindow,
                 ),
-                MenuItem::action(t("menu.view_telemetry"), zed_actions::OpenTelemetryLog),
-                MenuItem::action(t("menu.view_dependency_licenses"), zed_actions::OpenLicenses),
-                MenuItem::action(t("menu.show_welcome"), onboarding::ShowWelcome),
-                MenuItem::separator(),
-                MenuItem::action(t("menu.file_bug_report"), zed_actions::feedback::FileBugReport),
-                MenuItem::action(t("menu.request_feature"), zed_actions::feedback::RequestFeature),
-                MenuItem::action(t("menu.email_us"), zed_actions::feedback::EmailZed),
-                MenuItem::separator(),
                 MenuItem::action(
-                    t("menu.documentation"),
-                    super::OpenBrowser {
-                        url: "https://zed.dev/docs".into(),
-                    },
-                ),
-                MenuItem::action(t("menu.zed_repository"), feedback::OpenZedRepo),
-                MenuItem::action(
-                    t("menu.zed_twitter"),
-                    super::OpenBrowser {
-                        url: "https://twitter.com/zeddotdev".into(),
-                    },
-                ),
-                MenuItem::action(
-                    t("menu.join_the_team"),
-                    super::OpenBrowser {
-                        url: "https://zed.dev/jobs".into(),
-                    },
+                    "menu.move_tab_to_new_window",
+                    workspace::MoveItemToNewWindow,
                 ),
+                MenuItem::separator(),
             ],
         },
+        Menu {
+            name: "menu.help".into(),
+            items: help_items,
+        },
     ]
 }
+
+/// The Help > Developer Tools submenu.
+fn developer_tools_menu() -> Menu {
+    Menu {
+        name: "menu.developer_tools".into(),
+        items: vec![
+            MenuItem::action("menu.toggle_gpui_inspector", dev::ToggleInspector),
+            MenuItem::separator(),
+            MenuItem::action(
+                "menu.open_translation_preview",
+                custom_panel::OpenTranslationPreview,
+            ),
+            MenuItem::action(
+                "menu.log_translation_diff",
+                custom_panel::LogTranslationDiff,
+            ),
+        ],
+    }
+}
+
+/// The File > Encoding submenu.
+///
+/// Encoding names are not translation keys; `localize_menus` leaves them as is.
+fn encoding_menu() -> Menu {
+    let encodings = encoding_selector::menu_encodings();
+    Menu {
+        name: "menu.encoding".into(),
+        items: vec![
+            MenuItem::submenu(Menu {
+                name: "menu.reopen_with_encoding".into(),
+                items: encodings
+                    .iter()
+                    .map(|encoding| {
+                        MenuItem::action(
+                            encoding.name(),
+                            encoding_selector::ReopenWithEncoding {
+                                encoding: encoding.name().to_string(),
+                            },
+                        )
+                    })
+                    .collect(),
+            }),
+            MenuItem::submenu(Menu {
+                name: "menu.save_with_encoding".into(),
+                items: encodings
+                    .iter()
+                    .map(|encoding| {
+                        MenuItem::action(
+                            encoding.name(),
+                            encoding_selector::SaveWithEncoding {
+                                encoding: encoding.name().to_string(),
+                            },
+                        )
+                    })
+                    .collect(),
+            }),
+        ],
+    }
+}
+
+/// The Go > Go to Symbol in Project by Kind submenu.
+fn project_symbols_by_kind_menu() -> Menu {
+    use workspace::SymbolKindFilter;
+    let item = |name: &'static str, kind| {
+        MenuItem::action(name, workspace::ToggleProjectSymbolsFiltered { kind })
+    };
+    Menu {
+        name: "menu.go_to_symbol_in_project_by_kind".into(),
+        items: vec![
+            item("menu.symbol_kind_functions", SymbolKindFilter::Function),
+            item("menu.symbol_kind_classes", SymbolKindFilter::Class),
+            item("menu.symbol_kind_variables", SymbolKindFilter::Variable),
+            item("menu.symbol_kind_constants", SymbolKindFilter::Constant),
+            item("menu.symbol_kind_modules", SymbolKindFilter::Module),
+        ],
+    }
+}
+
+/// Spawns the tasks tagged with `tag`, falling back to the tasks modal filtered by it.
+fn spawn_tagged_task(tag: &str) -> zed_actions::Spawn {
+    zed_actions::Spawn::ByTag {
+        task_tag: tag.to_string(),
+        reveal_target: None,
+    }
+}
+
+/// The editor that is the active item of the active workspace window.
+fn active_editor(cx: &App) -> Option<Entity<Editor>> {
+    cx.active_window()
+        .and_then(|window| window.downcast::<Workspace>())
+        .and_then(|workspace| workspace.read(cx).ok())
+        .and_then(|workspace| workspace.active_item_as::<Editor>(cx))
+}
+
+/// Whether the focused editor is currently rendering inline values.
+fn inline_values_enabled(cx: &App) -> bool {
+    active_editor(cx).is_some_and(|editor| editor.read(cx).inline_values_enabled())
+}
+
+/// Whether the focused editor is currently rendering indent guides.
+fn indent_guides_enabled(cx: &App) -> bool {
+    active_editor(cx).is_some_and(|editor| editor.read(cx).indent_guides_enabled(cx))
+}
+
+/// Replaces every translation key used as a label in `menus` with its translation.
+fn localize_menus(menus: &mut [Menu]) {
+    let mut keys = Vec::new();
+    for menu in menus.iter_mut() {
+        for_each_label(menu, &mut |label| keys.push(label.clone()));
+    }
+    let mut labels = i18n::t_all(keys.iter().map(|key| key.as_ref())).into_iter();
+    for menu in menus.iter_mut() {
+        for_each_label(menu, &mut |label| {
+            if let Some(translated) = labels.next() {
+                *label = translated.into();
+            }
+        });
+    }
+}
+
+/// Visits the label of `menu` and of every item in it, depth first.
+fn for_each_label(menu: &mut Menu, f: &mut impl FnMut(&mut SharedString)) {
+    f(&mut menu.name);
+    for item in &mut menu.items {
+        match item {
+            MenuItem::Separator => {}
+            MenuItem::Submenu(submenu) => for_each_label(submenu, f),
+            MenuItem::SystemMenu(os_menu) => f(&mut os_menu.name),
+            MenuItem::Action { name, .. } => f(name),
+        }
+    }
+}
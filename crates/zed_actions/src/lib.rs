@@ -68,6 +68,8 @@ actions!(
         OpenLicenses,
         /// Opens the telemetry log.
         OpenTelemetryLog,
+        /// Reveals the installed extensions directory in the system file manager.
+        OpenExtensionsFolder,
         /// Opens the performance profiler.
         OpenPerformanceProfiler,
         /// Opens the onboarding view.
@@ -308,7 +310,9 @@ pub mod project_panel {
     );
 }
 pub mod feedback {
-    use gpui::actions;
+    use gpui::{Action, actions};
+    use schemars::JsonSchema;
+    use serde::Deserialize;
 
     actions!(
         feedback,
@@ -321,6 +325,25 @@ pub mod feedback {
             RequestFeature
         ]
     );
+
+    /// The kind of report to open with [`OpenPrefilledIssue`].
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Deserialize, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    pub enum IssueKind {
+        #[default]
+        BugReport,
+        FeatureRequest,
+    }
+
+    /// Opens a bug report or feature request whose body is pre-filled with a
+    /// template in the current UI language and the system information.
+    #[derive(PartialEq, Clone, Default, Debug, Deserialize, JsonSchema, Action)]
+    #[action(namespace = feedback)]
+    #[serde(deny_unknown_fields)]
+    pub struct OpenPrefilledIssue {
+        #[serde(default)]
+        pub kind: IssueKind,
+    }
 }
 
 pub mod theme_selector {
@@ -353,6 +376,18 @@ pub mod icon_theme_selector {
     }
 }
 
+pub mod ui_language_selector {
+    use gpui::actions;
+
+    actions!(
+        ui_language_selector,
+        [
+            /// Opens a picker to change the language of Zed's user interface.
+            SelectLanguage
+        ]
+    );
+}
+
 pub mod search {
     use gpui::actions;
     actions!(
@@ -519,6 +554,21 @@ pub mod debugger {
 pub struct OpenRecent {
     #[serde(default)]
     pub create_new_window: bool,
+    #[serde(default)]
+    pub filter: RecentProjectsFilter,
+}
+
+/// Which kind of recent projects to list.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RecentProjectsFilter {
+    /// Lists every recent project.
+    #[default]
+    All,
+    /// Lists only projects that were opened from a single file or a set of files.
+    Files,
+    /// Lists only projects that contain at least one folder.
+    Folders,
 }
 
 /// Creates a project from a selected template.
@@ -610,7 +660,9 @@ pub struct Rerun {
 pub mod outline {
     use std::sync::OnceLock;
 
-    use gpui::{AnyView, App, Window, actions};
+    use gpui::{Action, AnyView, App, Window, actions};
+    use schemars::JsonSchema;
+    use serde::Deserialize;
 
     actions!(
         outline,
@@ -619,6 +671,17 @@ pub mod outline {
             ToggleOutline
         ]
     );
+
+    /// Opens the outline with its query prefilled, filtering the symbols right away.
+    #[derive(PartialEq, Clone, Default, Debug, Deserialize, JsonSchema, Action)]
+    #[action(namespace = outline)]
+    #[serde(deny_unknown_fields)]
+    pub struct ToggleFiltered {
+        /// The query to prefill. When omitted, the word under the cursor is used.
+        #[serde(default)]
+        pub query: Option<String>,
+    }
+
     /// A pointer to outline::toggle function, exposed here to sewer the breadcrumbs <-> outline dependency.
     pub static TOGGLE_OUTLINE: OnceLock<fn(AnyView, &mut Window, &mut App)> = OnceLock::new();
 }